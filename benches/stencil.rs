@@ -0,0 +1,57 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use gridd::{Grid, Offset, PadMode};
+
+const SIDE: usize = 1024;
+
+fn heightmap(col_count: usize, row_count: usize) -> Grid<f32> {
+    let mut grid = Grid::new(col_count, row_count, 0.0);
+
+    for row in 0..row_count {
+        for col in 0..col_count {
+            grid.set((col, row), ((col * 7 + row * 13) % 31) as f32);
+        }
+    }
+
+    grid
+}
+
+/// The per-neighbor lookup a stencil is usually written with by hand.
+fn naive_laplacian(grid: &Grid<f32>) -> Grid<f32> {
+    let mut dest = Grid::new(grid.col_count(), grid.row_count(), 0.0);
+    let neighbors = [Offset::NORTH, Offset::EAST, Offset::SOUTH, Offset::WEST];
+
+    for row in 0..grid.row_count() {
+        for col in 0..grid.col_count() {
+            let center = *grid.get((col, row)).unwrap();
+            let sum: f32 = neighbors
+                .iter()
+                .map(|&vec| *grid.rget((col, row), vec).unwrap_or(&0.0))
+                .sum();
+
+            dest.set((col, row), sum - 4.0 * center);
+        }
+    }
+
+    dest
+}
+
+fn bench_laplacian(c: &mut Criterion) {
+    let grid = heightmap(SIDE, SIDE);
+
+    let mut group = c.benchmark_group("laplacian_1024");
+    group.sample_size(20);
+
+    group.bench_function("naive", |b| b.iter(|| naive_laplacian(black_box(&grid))));
+    group.bench_function("stencil5", |b| {
+        b.iter(|| {
+            black_box(&grid).stencil5(PadMode::Constant(0.0), |c, n, e, s, w| {
+                n + e + s + w - 4.0 * c
+            })
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_laplacian);
+criterion_main!(benches);
@@ -0,0 +1,54 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use gridd::Grid;
+
+const SIDE: usize = 4096;
+
+fn labeled(col_count: usize, row_count: usize) -> Grid<u32> {
+    let mut grid = Grid::new(col_count, row_count, 0);
+
+    for row in 0..row_count {
+        for col in 0..col_count {
+            grid.set((col, row), (col + col_count * row) as u32);
+        }
+    }
+
+    grid
+}
+
+/// The row-by-row transposition used before tiling was introduced.
+fn naive_transpose(grid: &Grid<u32>) -> Grid<u32> {
+    let mut dest = Grid::new(grid.row_count(), grid.col_count(), 0);
+
+    for row in 0..grid.row_count() {
+        for col in 0..grid.col_count() {
+            if let Some(&val) = grid.get((col, row)) {
+                dest.set((row, col), val);
+            }
+        }
+    }
+
+    dest
+}
+
+fn bench_transpose(c: &mut Criterion) {
+    let grid = labeled(SIDE, SIDE);
+    let mut dest = Grid::new(SIDE, SIDE, 0);
+    let mut square = grid.clone();
+
+    let mut group = c.benchmark_group("transpose_4096");
+    group.sample_size(10);
+
+    group.bench_function("naive", |b| b.iter(|| naive_transpose(black_box(&grid))));
+    group.bench_function("transpose", |b| b.iter(|| black_box(&grid).transpose()));
+    group.bench_function("transpose_into", |b| {
+        b.iter(|| black_box(&grid).transpose_into(&mut dest))
+    });
+    group.bench_function("transpose_in_place", |b| {
+        b.iter(|| black_box(&mut square).transpose_in_place())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_transpose);
+criterion_main!(benches);
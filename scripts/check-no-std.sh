@@ -0,0 +1,21 @@
+#!/bin/sh
+# Check that gridd still builds without `std`, and passes its tests with
+# the `std` feature off.
+#
+# The embedded target is only checked when it is installed:
+#     rustup target add thumbv7m-none-eabi
+set -e
+cd "$(dirname "$0")/.."
+
+cargo build --no-default-features
+cargo build --no-default-features --features approx,bytemuck,serde
+
+# Tests link `std` regardless (see the `cfg_attr` in lib.rs), so this runs
+# them with the `std`-only items left out but is not itself a no_std check.
+cargo test --no-default-features
+
+if rustup target list --installed | grep -q '^thumbv7m-none-eabi$'; then
+    cargo build --no-default-features --features approx,bytemuck,serde --target thumbv7m-none-eabi
+else
+    echo "thumbv7m-none-eabi is not installed; skipping the embedded build"
+fi
@@ -0,0 +1,127 @@
+//! Approximate equality for grids of floating-point values, via the
+//! `approx` crate.
+//!
+//! Grids compare approximately equal when their dimensions match exactly
+//! and every pair of corresponding cells is within tolerance. When an
+//! assertion fails, `Grid::first_mismatch` finds the offending cell.
+
+use crate::Grid;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+//////////////////////////////////////////////////////////////////////////////
+// Approximate Equality
+//////////////////////////////////////////////////////////////////////////////
+
+impl<T> Grid<T> {
+    /// Determine if two grids have the same dimensions and every pair of
+    /// corresponding cells satisfies `eq`.
+    fn all_cells_match<F>(&self, other: &Self, mut eq: F) -> bool
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        (self.col_count, self.row_count) == (other.col_count, other.row_count)
+            && self.data.iter().zip(&other.data).all(|(a, b)| eq(a, b))
+    }
+}
+
+impl<T> AbsDiffEq for Grid<T>
+where
+    T: AbsDiffEq,
+    T::Epsilon: Clone,
+{
+    type Epsilon = T::Epsilon;
+
+    fn default_epsilon() -> T::Epsilon {
+        T::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+        self.all_cells_match(other, |a, b| a.abs_diff_eq(b, epsilon.clone()))
+    }
+}
+
+impl<T> RelativeEq for Grid<T>
+where
+    T: RelativeEq,
+    T::Epsilon: Clone,
+{
+    fn default_max_relative() -> T::Epsilon {
+        T::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+        self.all_cells_match(other, |a, b| {
+            a.relative_eq(b, epsilon.clone(), max_relative.clone())
+        })
+    }
+}
+
+impl<T> UlpsEq for Grid<T>
+where
+    T: UlpsEq,
+    T::Epsilon: Clone,
+{
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+        self.all_cells_match(other, |a, b| a.ulps_eq(b, epsilon.clone(), max_ulps))
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::{assert_relative_eq, assert_relative_ne};
+
+    /// A 3x2 grid of thirds.
+    fn thirds() -> Grid<f32> {
+        let mut grid = Grid::new(3, 2, 0.0);
+
+        for (index, cell) in grid.iter_mut().enumerate() {
+            *cell = index as f32 / 3.0;
+        }
+
+        grid
+    }
+
+    #[test]
+    fn test_within_epsilon() {
+        let grid = thirds();
+        let nudged = grid.map(|&cell| cell + 1e-7);
+
+        assert_ne!(grid, nudged);
+        assert_relative_eq!(grid, nudged, epsilon = 1e-6);
+        assert!(grid.abs_diff_eq(&nudged, 1e-6));
+        assert!(grid.ulps_eq(&nudged, 1e-6, 4));
+    }
+
+    #[test]
+    fn test_one_cell_out_of_tolerance() {
+        let grid = thirds();
+        let mut off = grid.clone();
+        off.set((1, 1), 5.0);
+
+        assert_relative_ne!(grid, off, epsilon = 1e-6);
+        assert!(!grid.abs_diff_eq(&off, 1.0));
+        assert_eq!(
+            Some((1, 1)),
+            grid.first_mismatch(&off, |a, b| a.abs_diff_eq(b, 1e-6))
+        );
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        let wide = Grid::new(4, 2, 1.0f64);
+        let tall = Grid::new(2, 4, 1.0f64);
+
+        assert!(!wide.abs_diff_eq(&tall, f64::INFINITY));
+        assert!(!wide.relative_eq(&tall, f64::INFINITY, f64::INFINITY));
+        assert!(!wide.ulps_eq(&tall, f64::INFINITY, u32::MAX));
+    }
+}
@@ -0,0 +1,346 @@
+//! `proptest` strategies for grids, behind the `proptest` feature.
+//!
+//! `Grid<T>` and `Offset` implement `Arbitrary`, so they can be generated
+//! with `any` or named directly in `proptest!` arguments. Use `grid` to
+//! choose the cell strategy and the range of each dimension.
+//!
+//! Failing grids shrink by dropping columns from the right and rows from
+//! the bottom, then by shrinking the remaining cells.
+//!
+//! # Examples
+//!
+//! ```
+//! use gridd::Grid;
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(grid: Grid<u8>)| {
+//!     prop_assert_eq!(grid.clone(), grid.transpose().transpose());
+//! });
+//! ```
+
+use crate::{Grid, Offset};
+use proptest::arbitrary::{any, any_with, Arbitrary, StrategyFor};
+use proptest::collection::SizeRange;
+use proptest::strategy::{Map, NewTree, Strategy, ValueTree};
+use proptest::test_runner::TestRunner;
+
+/// Create a strategy for grids whose cells are generated by `cells`, with
+/// column and row counts drawn from `cols` and `rows`.
+///
+/// # Examples
+///
+/// ```
+/// use gridd::arbitrary::grid;
+/// use proptest::prelude::*;
+///
+/// proptest!(|(walls in grid(any::<bool>(), 1..=8, 1..=8))| {
+///     prop_assert!(walls.col_count() >= 1 && walls.col_count() <= 8);
+/// });
+/// ```
+pub fn grid<S>(cells: S, cols: impl Into<SizeRange>, rows: impl Into<SizeRange>) -> GridStrategy<S>
+where
+    S: Strategy,
+{
+    GridStrategy {
+        cells,
+        cols: cols.into(),
+        rows: rows.into(),
+    }
+}
+
+/// A strategy for generating grids, created by `grid`.
+#[derive(Clone, Debug)]
+pub struct GridStrategy<S> {
+    cells: S,
+    cols: SizeRange,
+    rows: SizeRange,
+}
+
+impl<S> Strategy for GridStrategy<S>
+where
+    S: Strategy,
+{
+    type Tree = GridValueTree<S::Tree>;
+    type Value = Grid<S::Value>;
+
+    fn new_tree(&self, runner: &mut TestRunner) -> NewTree<Self> {
+        let (min_cols, max_cols) = self.cols.start_end_incl();
+        let (min_rows, max_rows) = self.rows.start_end_incl();
+        let col_count = (min_cols..=max_cols).new_tree(runner)?.current();
+        let row_count = (min_rows..=max_rows).new_tree(runner)?.current();
+
+        let cells = (0..col_count * row_count)
+            .map(|_| self.cells.new_tree(runner))
+            .collect::<Result<_, _>>()?;
+
+        Ok(GridValueTree {
+            cells,
+            full_cols: col_count,
+            cols: DimSearch::new(min_cols, col_count),
+            rows: DimSearch::new(min_rows, row_count),
+            shrink: Shrink::Cols,
+            prev_shrink: None,
+        })
+    }
+}
+
+/// The stage a `GridValueTree` is shrinking.
+#[derive(Clone, Copy, Debug)]
+enum Shrink {
+    Cols,
+    Rows,
+    /// The cell at this row-major position within the current dimensions.
+    Cell(usize),
+}
+
+/// A binary search for the smallest failing dimension, bounded below by
+/// the strategy's minimum.
+#[derive(Clone, Copy, Debug)]
+struct DimSearch {
+    lo: usize,
+    curr: usize,
+    hi: usize,
+}
+
+impl DimSearch {
+    fn new(lo: usize, start: usize) -> Self {
+        Self {
+            lo,
+            curr: start,
+            hi: start,
+        }
+    }
+
+    fn reposition(&mut self) -> bool {
+        let mid = self.lo + (self.hi - self.lo) / 2;
+
+        mid != std::mem::replace(&mut self.curr, mid)
+    }
+
+    fn simplify(&mut self) -> bool {
+        if self.hi <= self.lo {
+            return false;
+        }
+
+        self.hi = self.curr;
+        self.reposition()
+    }
+
+    fn complicate(&mut self) -> bool {
+        if self.hi <= self.lo {
+            return false;
+        }
+
+        self.lo = self.curr + 1;
+        self.reposition()
+    }
+}
+
+/// The value tree for `GridStrategy`.
+///
+/// Every cell of the originally generated grid is kept, and the current
+/// grid is its top-left corner.
+#[derive(Clone, Debug)]
+pub struct GridValueTree<T> {
+    cells: Vec<T>,
+    full_cols: usize,
+    cols: DimSearch,
+    rows: DimSearch,
+    shrink: Shrink,
+    prev_shrink: Option<Shrink>,
+}
+
+impl<T> ValueTree for GridValueTree<T>
+where
+    T: ValueTree,
+{
+    type Value = Grid<T::Value>;
+
+    fn current(&self) -> Self::Value {
+        let (col_count, row_count) = (self.cols.curr, self.rows.curr);
+        let data = (0..row_count)
+            .flat_map(|row| &self.cells[row * self.full_cols..][..col_count])
+            .map(ValueTree::current)
+            .collect();
+
+        Grid {
+            col_count,
+            row_count,
+            data,
+        }
+    }
+
+    fn simplify(&mut self) -> bool {
+        loop {
+            match self.shrink {
+                Shrink::Cols => {
+                    if self.cols.simplify() {
+                        self.prev_shrink = Some(Shrink::Cols);
+                        return true;
+                    }
+                    self.shrink = Shrink::Rows;
+                }
+                Shrink::Rows => {
+                    if self.rows.simplify() {
+                        self.prev_shrink = Some(Shrink::Rows);
+                        return true;
+                    }
+                    self.shrink = Shrink::Cell(0);
+                }
+                Shrink::Cell(pos) => {
+                    let col_count = self.cols.curr;
+                    if pos >= col_count * self.rows.curr {
+                        self.prev_shrink = None;
+                        return false;
+                    }
+
+                    let index = pos / col_count * self.full_cols + pos % col_count;
+                    if self.cells[index].simplify() {
+                        self.prev_shrink = Some(Shrink::Cell(index));
+                        return true;
+                    }
+                    self.shrink = Shrink::Cell(pos + 1);
+                }
+            }
+        }
+    }
+
+    fn complicate(&mut self) -> bool {
+        let complicated = match self.prev_shrink {
+            None => false,
+            Some(Shrink::Cols) => self.cols.complicate(),
+            Some(Shrink::Rows) => self.rows.complicate(),
+            Some(Shrink::Cell(index)) => self.cells[index].complicate(),
+        };
+
+        if !complicated {
+            self.prev_shrink = None;
+        }
+
+        complicated
+    }
+}
+
+impl<T> Arbitrary for Grid<T>
+where
+    T: Arbitrary,
+{
+    /// The column count range, the row count range, and the cells'
+    /// parameters.
+    type Parameters = (SizeRange, SizeRange, T::Parameters);
+    type Strategy = GridStrategy<T::Strategy>;
+
+    fn arbitrary_with((cols, rows, cell): Self::Parameters) -> Self::Strategy {
+        grid(any_with::<T>(cell), cols, rows)
+    }
+}
+
+impl Arbitrary for Offset {
+    type Parameters = ();
+    type Strategy = Map<StrategyFor<(i32, i32)>, fn((i32, i32)) -> Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<(i32, i32)>().prop_map(Offset::from as fn(_) -> _)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use proptest::test_runner::TestError;
+
+    /// Find the minimal failing input for `test`.
+    fn minimal_failure<S, F>(strategy: S, test: F) -> S::Value
+    where
+        S: Strategy,
+        F: Fn(S::Value) -> Result<(), TestCaseError>,
+    {
+        match TestRunner::default().run(&strategy, test) {
+            Err(TestError::Fail(_, value)) => value,
+            other => panic!("expected the property to fail, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_dimensions_in_range() {
+        let mut runner = TestRunner::default();
+        let strategy = grid(0..10u8, 2..=5, 3..7);
+
+        for _ in 0..100 {
+            let grid = strategy.new_tree(&mut runner).unwrap().current();
+
+            assert!((2..=5).contains(&grid.col_count()));
+            assert!((3..7).contains(&grid.row_count()));
+            assert!(grid.iter().all(|&cell| cell < 10));
+        }
+    }
+
+    #[test]
+    fn test_shrinks_dimensions() {
+        let wide = minimal_failure(grid(any::<u8>(), 1..=50, 1..=50), |grid| {
+            prop_assert!(grid.col_count() < 2);
+            Ok(())
+        });
+        assert_eq!(Grid::new(2, 1, 0), wide);
+
+        let big = minimal_failure(any::<Grid<u8>>(), |grid| {
+            prop_assert!(grid.col_count() * grid.row_count() < 6);
+            Ok(())
+        });
+        assert_eq!(6, big.col_count() * big.row_count());
+        assert!(big.iter().all(|&cell| cell == 0));
+    }
+
+    #[test]
+    fn test_shrinks_cells() {
+        let bright = minimal_failure(grid(any::<u8>(), 10..=50, 10..=50), |grid| {
+            prop_assert!(grid.iter().all(|&cell| cell < 200));
+            Ok(())
+        });
+
+        assert_eq!((10, 10), (bright.col_count(), bright.row_count()));
+        assert_eq!(1, bright.iter().filter(|&&cell| cell != 0).count());
+        assert_eq!(Some(200), bright.max_value());
+    }
+
+    #[test]
+    fn test_offsets_shrink_to_origin() {
+        let offset = minimal_failure(any::<Offset>(), |vec| {
+            prop_assert!(vec.col_offset < 7);
+            Ok(())
+        });
+
+        assert_eq!(Offset::from((7, 0)), offset);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_transpose_twice_is_identity(grid: Grid<u8>) {
+            prop_assert_eq!(grid.clone(), grid.transpose().transpose());
+        }
+
+        #[test]
+        fn prop_rotate_four_times_is_identity(grid: Grid<i16>) {
+            let rotated = grid.rotate_cw().rotate_cw().rotate_cw().rotate_cw();
+            prop_assert_eq!(&grid, &rotated);
+            prop_assert_eq!(grid.rotate_ccw(), grid.rotate_cw().rotate_cw().rotate_cw());
+        }
+
+        #[test]
+        fn prop_flips_are_involutions(grid: Grid<char>) {
+            prop_assert_eq!(grid.clone(), grid.flip_horizontal().flip_horizontal());
+            prop_assert_eq!(grid.clone(), grid.flip_vertical().flip_vertical());
+        }
+
+        #[test]
+        fn prop_offset_identities(vec: Offset) {
+            prop_assert_eq!(vec, vec + Offset::default());
+            prop_assert_eq!(Offset::default(), vec - vec);
+        }
+    }
+}
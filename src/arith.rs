@@ -0,0 +1,324 @@
+//! Element-wise arithmetic on grids.
+
+use crate::{Grid, GridError};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+//////////////////////////////////////////////////////////////////////////////
+// Fallible Element-Wise Operations
+//////////////////////////////////////////////////////////////////////////////
+
+impl<T> Grid<T> {
+    /// Combine two equally-sized grids cell by cell.
+    fn zip_with<F>(&self, other: &Self, mut f: F) -> Result<Self, GridError>
+    where
+        F: FnMut(&T, &T) -> T,
+    {
+        self.check_same_dims(other)?;
+
+        Ok(Grid {
+            col_count: self.col_count,
+            row_count: self.row_count,
+            data: self.data.iter().zip(&other.data).map(|(a, b)| f(a, b)).collect(),
+        })
+    }
+
+    /// Add two grids cell by cell.
+    ///
+    /// Unlike `+`, which panics, this returns a `DimensionMismatch` error
+    /// if the grids differ in size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let a = Grid::new(2, 2, 1);
+    ///
+    /// assert_eq!(Ok(Grid::new(2, 2, 2)), a.checked_add_grid(&a));
+    /// assert!(a.checked_add_grid(&Grid::new(2, 3, 1)).is_err());
+    /// ```
+    pub fn checked_add_grid(&self, other: &Self) -> Result<Self, GridError>
+    where
+        for<'a> &'a T: Add<Output = T>,
+    {
+        self.zip_with(other, |a, b| a + b)
+    }
+
+    /// Subtract another grid from this one cell by cell.
+    ///
+    /// Returns a `DimensionMismatch` error if the grids differ in size.
+    pub fn checked_sub_grid(&self, other: &Self) -> Result<Self, GridError>
+    where
+        for<'a> &'a T: Sub<Output = T>,
+    {
+        self.zip_with(other, |a, b| a - b)
+    }
+
+    /// Multiply two grids cell by cell.
+    ///
+    /// Returns a `DimensionMismatch` error if the grids differ in size.
+    pub fn checked_mul_grid(&self, other: &Self) -> Result<Self, GridError>
+    where
+        for<'a> &'a T: Mul<Output = T>,
+    {
+        self.zip_with(other, |a, b| a * b)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Operator Traits
+//////////////////////////////////////////////////////////////////////////////
+
+/// Implement an element-wise binary operator for borrowed and owned grids,
+/// along with its compound assignment form. Mismatched dimensions panic.
+macro_rules! elementwise_op {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, $checked:ident, $verb:expr) => {
+        impl<'a, 'b, T> $Op<&'b Grid<T>> for &'a Grid<T>
+        where
+            for<'x> &'x T: $Op<Output = T>,
+        {
+            type Output = Grid<T>;
+
+            fn $op(self, rhs: &'b Grid<T>) -> Grid<T> {
+                self.$checked(rhs)
+                    .unwrap_or_else(|err| panic!("cannot {} grids: {}", $verb, err))
+            }
+        }
+
+        impl<T> $Op for Grid<T>
+        where
+            T: $Op<Output = T>,
+        {
+            type Output = Grid<T>;
+
+            fn $op(self, rhs: Grid<T>) -> Grid<T> {
+                if let Err(err) = self.check_same_dims(&rhs) {
+                    panic!("cannot {} grids: {}", $verb, err);
+                }
+
+                Grid {
+                    col_count: self.col_count,
+                    row_count: self.row_count,
+                    data: self.data.into_iter().zip(rhs.data).map(|(a, b)| a.$op(b)).collect(),
+                }
+            }
+        }
+
+        impl<'a, T> $OpAssign<&'a Grid<T>> for Grid<T>
+        where
+            for<'x> T: $OpAssign<&'x T>,
+        {
+            fn $op_assign(&mut self, rhs: &'a Grid<T>) {
+                if let Err(err) = self.check_same_dims(rhs) {
+                    panic!("cannot {} grids: {}", $verb, err);
+                }
+
+                for (a, b) in self.data.iter_mut().zip(&rhs.data) {
+                    a.$op_assign(b);
+                }
+            }
+        }
+    };
+}
+
+elementwise_op!(Add, add, AddAssign, add_assign, checked_add_grid, "add");
+elementwise_op!(Sub, sub, SubAssign, sub_assign, checked_sub_grid, "subtract");
+elementwise_op!(Mul, mul, MulAssign, mul_assign, checked_mul_grid, "multiply");
+
+//////////////////////////////////////////////////////////////////////////////
+// Scalar Broadcasting
+//////////////////////////////////////////////////////////////////////////////
+
+/// Implement a binary operator between a grid and a single value applied
+/// to every cell, along with its compound assignment form.
+macro_rules! scalar_op {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident) => {
+        impl<'a, T> $Op<T> for &'a Grid<T>
+        where
+            for<'x> &'x T: $Op<Output = T>,
+        {
+            type Output = Grid<T>;
+
+            fn $op(self, rhs: T) -> Grid<T> {
+                self.map(|cell| cell.$op(&rhs))
+            }
+        }
+
+        impl<T> $Op<T> for Grid<T>
+        where
+            T: Clone + $Op<Output = T>,
+        {
+            type Output = Grid<T>;
+
+            fn $op(self, rhs: T) -> Grid<T> {
+                Grid {
+                    col_count: self.col_count,
+                    row_count: self.row_count,
+                    data: self.data.into_iter().map(|cell| cell.$op(rhs.clone())).collect(),
+                }
+            }
+        }
+
+        impl<T> $OpAssign<T> for Grid<T>
+        where
+            for<'x> T: $OpAssign<&'x T>,
+        {
+            fn $op_assign(&mut self, rhs: T) {
+                for cell in &mut self.data {
+                    cell.$op_assign(&rhs);
+                }
+            }
+        }
+    };
+}
+
+scalar_op!(Add, add, AddAssign, add_assign);
+scalar_op!(Sub, sub, SubAssign, sub_assign);
+scalar_op!(Mul, mul, MulAssign, mul_assign);
+scalar_op!(Div, div, DivAssign, div_assign);
+
+/// Implement operators with a primitive scalar on the left and a grid on
+/// the right. The orphan rules rule out doing this generically.
+macro_rules! scalar_lhs_ops {
+    ($($t:ty),*) => {
+        $(
+            scalar_lhs_ops!(@op $t, Add, add);
+            scalar_lhs_ops!(@op $t, Sub, sub);
+            scalar_lhs_ops!(@op $t, Mul, mul);
+            scalar_lhs_ops!(@op $t, Div, div);
+        )*
+    };
+    (@op $t:ty, $Op:ident, $op:ident) => {
+        impl<'a> $Op<&'a Grid<$t>> for $t {
+            type Output = Grid<$t>;
+
+            fn $op(self, rhs: &'a Grid<$t>) -> Grid<$t> {
+                rhs.map(|&cell| self.$op(cell))
+            }
+        }
+
+        impl $Op<Grid<$t>> for $t {
+            type Output = Grid<$t>;
+
+            fn $op(self, mut rhs: Grid<$t>) -> Grid<$t> {
+                for cell in &mut rhs.data {
+                    *cell = self.$op(*cell);
+                }
+
+                rhs
+            }
+        }
+    };
+}
+
+scalar_lhs_ops!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::labeled;
+
+    #[test]
+    fn test_borrowed_ops() {
+        let a = labeled(3, 2);
+        let b = Grid::new(3, 2, 2);
+
+        assert_eq!(vec![2, 3, 4, 5, 6, 7], (&a + &b).data);
+        assert_eq!(vec![0, 1, 2, 3, 4, 5], (&(&a + &b) - &b).data);
+        assert_eq!(vec![0, 2, 4, 6, 8, 10], (&a * &b).data);
+    }
+
+    #[test]
+    fn test_owned_ops() {
+        let a = labeled(3, 2).map(|&cell| cell as f64);
+        let b = Grid::new(3, 2, 0.5);
+
+        assert_eq!(&a + &b, a.clone() + b.clone());
+        assert_eq!(&a - &b, a.clone() - b.clone());
+        assert_eq!(&a * &b, a * b);
+    }
+
+    #[test]
+    fn test_assign_ops() {
+        let mut grid = labeled(2, 2);
+        let delta = Grid::new(2, 2, 3);
+
+        grid += &delta;
+        assert_eq!(vec![3, 4, 5, 6], grid.data);
+        grid *= &delta;
+        assert_eq!(vec![9, 12, 15, 18], grid.data);
+        grid -= &delta;
+        assert_eq!(vec![6, 9, 12, 15], grid.data);
+    }
+
+    #[test]
+    fn test_checked_mismatch() {
+        let a = labeled(3, 2);
+
+        assert_eq!(
+            Err(GridError::DimensionMismatch {
+                expected: (3, 2),
+                found: (2, 3)
+            }),
+            a.checked_sub_grid(&labeled(2, 3))
+        );
+        assert!(a.checked_mul_grid(&labeled(3, 3)).is_err());
+    }
+
+    #[test]
+    fn test_scalar_ops() {
+        let grid = labeled(3, 2).map(|&cell| cell as i32);
+
+        assert_eq!(vec![10, 11, 12, 13, 14, 15], (&grid + 10).data);
+        assert_eq!(vec![-1, 0, 1, 2, 3, 4], (&grid - 1).data);
+        assert_eq!(vec![0, 3, 6, 9, 12, 15], (grid.clone() * 3).data);
+        assert_eq!(vec![0, 0, 1, 1, 2, 2], (grid.clone() / 2).data);
+        assert_eq!(&grid * 3, grid.clone() * 3);
+    }
+
+    #[test]
+    fn test_scalar_assign_ops() {
+        let mut heights = Grid::new(2, 2, 2.0);
+        heights.set((1, 1), 6.0);
+
+        heights /= 2.0;
+        assert_eq!(vec![1.0, 1.0, 1.0, 3.0], heights.data);
+        heights *= 4.0;
+        heights -= 1.0;
+        heights += 0.5;
+        assert_eq!(vec![3.5, 3.5, 3.5, 11.5], heights.data);
+    }
+
+    #[test]
+    fn test_scalar_lhs_ops() {
+        let grid = labeled(2, 2).map(|&cell| cell as i32 + 1);
+
+        // Subtraction and division don't commute.
+        assert_eq!(vec![9, 8, 7, 6], (10 - &grid).data);
+        assert_eq!(vec![-9, -8, -7, -6], (&grid - 10).data);
+        assert_eq!(vec![12, 6, 4, 3], (12 / grid.clone()).data);
+        assert_eq!(&grid * 5, 5 * &grid);
+        assert_eq!(grid.clone() + 1, 1 + grid);
+
+        let mut floats: Grid<f64> = Grid::new(2, 1, 2.0);
+        floats.set((1, 0), 4.0);
+        assert_eq!(vec![0.5, 0.25], (1.0 / &floats).data);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add grids: expected a 3x2 grid, found 2x3")]
+    fn test_add_mismatch_panics() {
+        let _ = &labeled(3, 2) + &labeled(2, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot subtract grids")]
+    fn test_sub_assign_mismatch_panics() {
+        let mut grid = labeled(3, 2);
+        grid -= &labeled(3, 1);
+    }
+}
@@ -0,0 +1,355 @@
+//! A compact binary format for saving and loading grids.
+//!
+//! A file starts with a 22-byte header: the magic bytes `GRDD`, a format
+//! version byte, the encoded size of one cell, and the column and row counts
+//! as little-endian `u64`s. The cells follow in row-major order.
+
+use crate::Grid;
+use std::convert::TryFrom;
+use std::io::{self, Read, Write};
+use std::marker::PhantomData;
+
+/// Bytes identifying the start of an encoded grid.
+const MAGIC: [u8; 4] = *b"GRDD";
+
+/// The version of the format written by `Grid::write_to`.
+const VERSION: u8 = 1;
+
+/// Number of cells decoded per read, bounding what a corrupt header can
+/// make `read_from` allocate ahead of the data actually present.
+const CHUNK_CELLS: usize = 4096;
+
+//////////////////////////////////////////////////////////////////////////////
+// Grid Elements
+//////////////////////////////////////////////////////////////////////////////
+
+/// A cell type with a fixed-size binary encoding.
+///
+/// Implemented for the primitive integer and float types, little-endian,
+/// and for `bool` as a single byte. `usize` and `isize` are stored in eight
+/// bytes so files move between platforms.
+pub trait GridElement: Sized {
+    /// The number of bytes one encoded value takes, from 1 to 255.
+    ///
+    /// Reading or writing a grid of a type whose length is outside that
+    /// range fails to compile.
+    ///
+    /// ```compile_fail
+    /// use gridd::{Grid, GridElement};
+    ///
+    /// struct Unit;
+    ///
+    /// impl GridElement for Unit {
+    ///     const ENCODED_LEN: usize = 0;
+    ///
+    ///     fn encode(&self, _: &mut [u8]) {}
+    ///
+    ///     fn decode(_: &[u8]) -> Option<Self> {
+    ///         Some(Unit)
+    ///     }
+    /// }
+    ///
+    /// let _ = Grid::<Unit>::read_from(&[][..]);
+    /// ```
+    const ENCODED_LEN: usize;
+
+    /// Write `self` into `buf`, which is exactly `ENCODED_LEN` bytes long.
+    fn encode(&self, buf: &mut [u8]);
+
+    /// Read a value from `buf`, which is exactly `ENCODED_LEN` bytes long,
+    /// or `None` if the bytes are not a valid encoding.
+    fn decode(buf: &[u8]) -> Option<Self>;
+}
+
+macro_rules! le_element {
+    ($($t:ty),*) => {
+        $(
+            impl GridElement for $t {
+                const ENCODED_LEN: usize = std::mem::size_of::<$t>();
+
+                fn encode(&self, buf: &mut [u8]) {
+                    buf.copy_from_slice(&self.to_le_bytes());
+                }
+
+                fn decode(buf: &[u8]) -> Option<Self> {
+                    <[u8; std::mem::size_of::<$t>()]>::try_from(buf)
+                        .ok()
+                        .map(<$t>::from_le_bytes)
+                }
+            }
+        )*
+    };
+}
+
+le_element!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+macro_rules! wide_element {
+    ($($t:ty => $wide:ty),*) => {
+        $(
+            impl GridElement for $t {
+                const ENCODED_LEN: usize = <$wide as GridElement>::ENCODED_LEN;
+
+                fn encode(&self, buf: &mut [u8]) {
+                    (*self as $wide).encode(buf);
+                }
+
+                fn decode(buf: &[u8]) -> Option<Self> {
+                    <$wide>::decode(buf).and_then(|wide| <$t>::try_from(wide).ok())
+                }
+            }
+        )*
+    };
+}
+
+wide_element!(usize => u64, isize => i64);
+
+impl GridElement for bool {
+    const ENCODED_LEN: usize = 1;
+
+    fn encode(&self, buf: &mut [u8]) {
+        buf[0] = *self as u8;
+    }
+
+    fn decode(buf: &[u8]) -> Option<Self> {
+        match buf {
+            [0] => Some(false),
+            [1] => Some(true),
+            _ => None,
+        }
+    }
+}
+
+/// The encoded length of `T`, checked when the format is used with `T`.
+///
+/// The header stores the length in one byte, and a length of zero would
+/// leave cells without bytes to be decoded from.
+struct EncodedLen<T>(PhantomData<T>);
+
+impl<T> EncodedLen<T>
+where
+    T: GridElement,
+{
+    const BYTES: usize = {
+        assert!(
+            T::ENCODED_LEN > 0 && T::ENCODED_LEN <= u8::MAX as usize,
+            "GridElement::ENCODED_LEN must be from 1 to 255"
+        );
+        T::ENCODED_LEN
+    };
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Grid Integration
+//////////////////////////////////////////////////////////////////////////////
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+impl<T> Grid<T>
+where
+    T: GridElement,
+{
+    /// Write the grid to `w` in the binary format.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `w` cannot be written to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut bytes = Vec::new();
+    /// Grid::new(3, 2, 7u16).write_to(&mut bytes).unwrap();
+    ///
+    /// assert_eq!(22 + 6 * 2, bytes.len());
+    /// assert_eq!(Grid::new(3, 2, 7u16), Grid::read_from(&bytes[..]).unwrap());
+    /// ```
+    pub fn write_to<W>(&self, mut w: W) -> io::Result<()>
+    where
+        W: Write,
+    {
+        let len = EncodedLen::<T>::BYTES;
+        let mut header = Vec::with_capacity(22);
+        header.extend_from_slice(&MAGIC);
+        header.push(VERSION);
+        header.push(len as u8);
+        header.extend_from_slice(&(self.col_count as u64).to_le_bytes());
+        header.extend_from_slice(&(self.row_count as u64).to_le_bytes());
+        w.write_all(&header)?;
+
+        let mut buf = vec![0; CHUNK_CELLS * len];
+        for cells in self.data.chunks(CHUNK_CELLS) {
+            let bytes = &mut buf[..cells.len() * len];
+
+            for (cell, out) in cells.iter().zip(bytes.chunks_exact_mut(len)) {
+                cell.encode(out);
+            }
+
+            w.write_all(bytes)?;
+        }
+
+        Ok(())
+    }
+
+    /// Read a grid written by `Grid::write_to` from `r`.
+    ///
+    /// Cells are read in chunks, so a header claiming more cells than the
+    /// input holds fails at the end of the input rather than allocating
+    /// for every claimed cell up front.
+    ///
+    /// # Errors
+    ///
+    /// Fails with `ErrorKind::InvalidData` if the header is not one this
+    /// version writes for `T`, its dimensions overflow, or a cell is not a
+    /// valid encoding, and with `ErrorKind::UnexpectedEof` if the input
+    /// ends early.
+    pub fn read_from<R>(mut r: R) -> io::Result<Self>
+    where
+        R: Read,
+    {
+        let len = EncodedLen::<T>::BYTES;
+        let mut header = [0; 22];
+        r.read_exact(&mut header)?;
+
+        if header[..4] != MAGIC {
+            return Err(invalid_data(String::from("not a gridd binary grid")));
+        }
+        if header[4] != VERSION {
+            return Err(invalid_data(format!("unsupported format version {}", header[4])));
+        }
+        if usize::from(header[5]) != len {
+            return Err(invalid_data(format!(
+                "cells are {} bytes, expected {}",
+                header[5], len
+            )));
+        }
+
+        let dim = |bytes: &[u8]| {
+            let dim = u64::from_le_bytes(<[u8; 8]>::try_from(bytes).expect("eight bytes"));
+            usize::try_from(dim).ok()
+        };
+        let (cols, rows) = (dim(&header[6..14]), dim(&header[14..22]));
+        let (col_count, row_count, cell_count) = match (cols, rows) {
+            (Some(cols), Some(rows)) => match cols.checked_mul(rows) {
+                Some(cells) if cells.checked_mul(len).is_some() => (cols, rows, cells),
+                _ => return Err(invalid_data(format!("dimensions {}x{} overflow", cols, rows))),
+            },
+            _ => return Err(invalid_data(String::from("dimensions overflow"))),
+        };
+
+        let mut data = Vec::with_capacity(cell_count.min(CHUNK_CELLS));
+        let mut buf = vec![0; CHUNK_CELLS * len];
+
+        while data.len() < cell_count {
+            let cells = (cell_count - data.len()).min(CHUNK_CELLS);
+            let bytes = &mut buf[..cells * len];
+            r.read_exact(bytes)?;
+
+            for encoded in bytes.chunks_exact(len) {
+                let cell = T::decode(encoded).ok_or_else(|| {
+                    invalid_data(format!("invalid encoding for cell {}", data.len()))
+                })?;
+                data.push(cell);
+            }
+        }
+
+        Ok(Grid {
+            col_count,
+            row_count,
+            data,
+        })
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::labeled;
+
+    fn encode<T: GridElement>(grid: &Grid<T>) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        grid.write_to(&mut bytes).unwrap();
+
+        bytes
+    }
+
+    fn read_err<T: GridElement>(bytes: &[u8]) -> io::Error {
+        match Grid::<T>::read_from(bytes) {
+            Ok(_) => panic!("read an invalid grid"),
+            Err(err) => err,
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let bytes = labeled(5, 3).map(|&cell| cell as u8 * 17);
+        assert_eq!(bytes, Grid::read_from(&encode(&bytes)[..]).unwrap());
+
+        let words = labeled(70, 90).map(|&cell| (cell as u32).wrapping_mul(2_654_435_761));
+        assert_eq!(words, Grid::read_from(&encode(&words)[..]).unwrap());
+
+        let floats = labeled(4, 4).map(|&cell| cell as f64 / 3.0 - 2.0);
+        assert_eq!(floats, Grid::read_from(&encode(&floats)[..]).unwrap());
+
+        let flags = labeled(3, 3).map(|&cell| cell % 2 == 0);
+        assert_eq!(flags, Grid::read_from(&encode(&flags)[..]).unwrap());
+
+        let empty: Grid<usize> = Grid::new(0, 4, 0);
+        assert_eq!(empty, Grid::read_from(&encode(&empty)[..]).unwrap());
+    }
+
+    #[test]
+    fn test_header_layout() {
+        let bytes = encode(&Grid::new(2, 1, 0x0102u16));
+
+        assert_eq!(b"GRDD", &bytes[..4]);
+        assert_eq!([1, 2], bytes[4..6]);
+        assert_eq!(2u64.to_le_bytes(), bytes[6..14]);
+        assert_eq!(1u64.to_le_bytes(), bytes[14..22]);
+        assert_eq!([2, 1, 2, 1], bytes[22..]);
+    }
+
+    #[test]
+    fn test_truncated() {
+        let bytes = encode(&labeled(8, 8).map(|&cell| cell as u32));
+
+        for &len in &[0, 10, 22, 23, bytes.len() - 1] {
+            assert_eq!(io::ErrorKind::UnexpectedEof, read_err::<u32>(&bytes[..len]).kind());
+        }
+    }
+
+    #[test]
+    fn test_corrupt_header() {
+        let good = encode(&Grid::new(2, 2, 1u8));
+        let corrupt = |at: usize, value: u8| {
+            let mut bytes = good.clone();
+            bytes[at] = value;
+            read_err::<u8>(&bytes)
+        };
+
+        assert_eq!(io::ErrorKind::InvalidData, corrupt(0, b'X').kind());
+        assert_eq!(io::ErrorKind::InvalidData, corrupt(4, 9).kind());
+        assert_eq!(io::ErrorKind::InvalidData, read_err::<u16>(&good).kind());
+
+        let mut huge = good[..6].to_vec();
+        huge.extend_from_slice(&u64::MAX.to_le_bytes());
+        huge.extend_from_slice(&u64::MAX.to_le_bytes());
+        assert_eq!(io::ErrorKind::InvalidData, read_err::<u8>(&huge).kind());
+
+        let mut absurd = good[..6].to_vec();
+        absurd.extend_from_slice(&(1u64 << 31).to_le_bytes());
+        absurd.extend_from_slice(&(1u64 << 31).to_le_bytes());
+        assert_eq!(io::ErrorKind::UnexpectedEof, read_err::<u8>(&absurd).kind());
+
+        let mut bad_bool = encode(&Grid::new(1, 1, true));
+        bad_bool[22] = 2;
+        assert_eq!(io::ErrorKind::InvalidData, read_err::<bool>(&bad_bool).kind());
+    }
+}
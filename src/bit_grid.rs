@@ -0,0 +1,411 @@
+//! Bit-packed grids of booleans.
+
+use crate::{Coord, Grid, GridError};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// The number of cells packed into each word.
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A grid of booleans stored one bit per cell.
+///
+/// Cells are packed in row-major order, 64 to a `u64` word, so bulk
+/// operations like `and` and `count_ones` work a word at a time. Bits past
+/// the last cell are always clear, so they never show up in counts or
+/// comparisons.
+///
+/// # Examples
+///
+/// ```
+/// use gridd::BitGrid;
+///
+/// let mut visible = BitGrid::new(100, 100, false);
+/// visible.set((3, 4), true);
+///
+/// assert_eq!(Some(true), visible.get((3, 4)));
+/// assert_eq!(1, visible.count_ones());
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, PartialEq)]
+pub struct BitGrid {
+    col_count: usize,
+    row_count: usize,
+    words: Vec<u64>,
+}
+
+impl BitGrid {
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Create a grid with every cell set to `val`.
+    pub fn new(col_count: usize, row_count: usize, val: bool) -> Self {
+        let len = col_count * row_count;
+        let word = if val { !0 } else { 0 };
+        let mut grid = Self {
+            col_count,
+            row_count,
+            words: vec![word; len.div_ceil(WORD_BITS)],
+        };
+
+        grid.clear_tail();
+        grid
+    }
+
+    /// Clear the unused bits of the last word.
+    fn clear_tail(&mut self) {
+        let used = (self.col_count * self.row_count) % WORD_BITS;
+
+        if used != 0 {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1 << used) - 1;
+            }
+        }
+    }
+
+    /// Get the word holding a cell and the cell's bit within it.
+    fn bit_index(&self, (col, row): Coord) -> (usize, u32) {
+        let index = row * self.col_count + col;
+
+        (index / WORD_BITS, (index % WORD_BITS) as u32)
+    }
+
+    //////////////////////////////////
+    // Shape
+    //////////////////////////////////
+
+    /// Get the column count.
+    pub fn col_count(&self) -> usize {
+        self.col_count
+    }
+
+    /// Get the row count.
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// Determine if a coordinate is within the grid.
+    pub fn contains(&self, (col, row): Coord) -> bool {
+        col < self.col_count && row < self.row_count
+    }
+
+    //////////////////////////////////
+    // Access
+    //////////////////////////////////
+
+    /// Get the value of some cell.
+    pub fn get(&self, coord: Coord) -> Option<bool> {
+        if !self.contains(coord) {
+            return None;
+        }
+
+        let (word, bit) = self.bit_index(coord);
+        Some((self.words[word] >> bit) & 1 == 1)
+    }
+
+    /// Set a cell's value. Coordinates out of bounds are ignored.
+    pub fn set(&mut self, coord: Coord, val: bool) {
+        if !self.contains(coord) {
+            return;
+        }
+
+        let (word, bit) = self.bit_index(coord);
+        if val {
+            self.words[word] |= 1 << bit;
+        } else {
+            self.words[word] &= !(1 << bit);
+        }
+    }
+
+    /// Set every cell to `val`.
+    pub fn fill(&mut self, val: bool) {
+        self.words.fill(if val { !0 } else { 0 });
+        self.clear_tail();
+    }
+
+    //////////////////////////////////
+    // Counting & Iteration
+    //////////////////////////////////
+
+    /// Count the cells that are set.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Iterate over the coordinates of the cells that are set, in
+    /// row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::BitGrid;
+    ///
+    /// let mut mask = BitGrid::new(3, 3, false);
+    /// mask.set((2, 0), true);
+    /// mask.set((1, 2), true);
+    ///
+    /// assert_eq!(vec![(2, 0), (1, 2)], mask.iter_ones().collect::<Vec<_>>());
+    /// ```
+    pub fn iter_ones(&self) -> impl Iterator<Item = Coord> + '_ {
+        let col_count = self.col_count;
+
+        self.words.iter().enumerate().flat_map(move |(i, &word)| {
+            let mut rest = word;
+
+            core::iter::from_fn(move || {
+                if rest == 0 {
+                    return None;
+                }
+
+                let bit = rest.trailing_zeros() as usize;
+                rest &= rest - 1;
+
+                let index = i * WORD_BITS + bit;
+                Some((index % col_count, index / col_count))
+            })
+        })
+    }
+
+    //////////////////////////////////
+    // Boolean Operations
+    //////////////////////////////////
+
+    /// Combine two equally-sized grids a word at a time.
+    fn zip_words<F>(&self, other: &Self, f: F) -> Result<Self, GridError>
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        let expected = (self.col_count, self.row_count);
+        let found = (other.col_count, other.row_count);
+        if expected != found {
+            return Err(GridError::DimensionMismatch { expected, found });
+        }
+
+        Ok(Self {
+            col_count: self.col_count,
+            row_count: self.row_count,
+            words: self.words.iter().zip(&other.words).map(|(&a, &b)| f(a, b)).collect(),
+        })
+    }
+
+    /// Get the cells set in both grids.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DimensionMismatch` error if the grids differ in size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::BitGrid;
+    ///
+    /// let mut walls = BitGrid::new(4, 4, false);
+    /// walls.set((1, 1), true);
+    /// walls.set((2, 2), true);
+    /// let mut lit = BitGrid::new(4, 4, false);
+    /// lit.set((1, 1), true);
+    ///
+    /// assert_eq!(vec![(1, 1)], walls.and(&lit).unwrap().iter_ones().collect::<Vec<_>>());
+    /// assert!(walls.and(&BitGrid::new(4, 5, false)).is_err());
+    /// ```
+    pub fn and(&self, other: &Self) -> Result<Self, GridError> {
+        self.zip_words(other, |a, b| a & b)
+    }
+
+    /// Get the cells set in either grid.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DimensionMismatch` error if the grids differ in size.
+    pub fn or(&self, other: &Self) -> Result<Self, GridError> {
+        self.zip_words(other, |a, b| a | b)
+    }
+
+    /// Get the cells set in exactly one of the grids.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `DimensionMismatch` error if the grids differ in size.
+    pub fn xor(&self, other: &Self) -> Result<Self, GridError> {
+        self.zip_words(other, |a, b| a ^ b)
+    }
+
+    /// Get the cells that are not set.
+    pub fn not(&self) -> Self {
+        let mut grid = Self {
+            col_count: self.col_count,
+            row_count: self.row_count,
+            words: self.words.iter().map(|word| !word).collect(),
+        };
+
+        grid.clear_tail();
+        grid
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Conversions
+//////////////////////////////////////////////////////////////////////////////
+
+impl From<&Grid<bool>> for BitGrid {
+    fn from(grid: &Grid<bool>) -> Self {
+        let mut words = vec![0; grid.data.len().div_ceil(WORD_BITS)];
+
+        for (word, cells) in words.iter_mut().zip(grid.data.chunks(WORD_BITS)) {
+            for (bit, &cell) in cells.iter().enumerate() {
+                *word |= u64::from(cell) << bit;
+            }
+        }
+
+        Self {
+            col_count: grid.col_count,
+            row_count: grid.row_count,
+            words,
+        }
+    }
+}
+
+impl From<&BitGrid> for Grid<bool> {
+    fn from(grid: &BitGrid) -> Self {
+        let len = grid.col_count * grid.row_count;
+        let data = (0..len)
+            .map(|index| (grid.words[index / WORD_BITS] >> (index % WORD_BITS)) & 1 == 1)
+            .collect();
+
+        Grid {
+            col_count: grid.col_count,
+            row_count: grid.row_count,
+            data,
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Generate a random `Grid<bool>`, with widths straddling word sizes.
+    fn bool_grid(max_cols: usize, max_rows: usize) -> impl Strategy<Value = Grid<bool>> {
+        (0..=max_cols, 0..=max_rows).prop_flat_map(|(cols, rows)| {
+            prop::collection::vec(any::<bool>(), cols * rows)
+                .prop_map(move |data| Grid::from_vec(cols, rows, data).unwrap())
+        })
+    }
+
+    /// Generate two random `Grid<bool>`s of the same size.
+    fn bool_grid_pair() -> impl Strategy<Value = (Grid<bool>, Grid<bool>)> {
+        (0usize..150, 0usize..6).prop_flat_map(|(cols, rows)| {
+            let cells = move || {
+                prop::collection::vec(any::<bool>(), cols * rows)
+                    .prop_map(move |data| Grid::from_vec(cols, rows, data).unwrap())
+            };
+
+            (cells(), cells())
+        })
+    }
+
+    #[test]
+    fn test_padding_stays_clear() {
+        let mut grid = BitGrid::new(3, 3, true);
+        assert_eq!(9, grid.count_ones());
+        assert_eq!(BitGrid::new(3, 3, false), grid.not());
+        assert_eq!(9, grid.not().not().count_ones());
+
+        grid.fill(false);
+        assert_eq!(9, grid.not().count_ones());
+        assert_eq!(9, grid.not().iter_ones().count());
+        assert_eq!(grid.not(), BitGrid::new(3, 3, true));
+
+        let wide = BitGrid::new(65, 1, true);
+        assert_eq!(65, wide.count_ones());
+        assert_eq!(Some((64, 0)), wide.iter_ones().last());
+    }
+
+    #[test]
+    fn test_get_set() {
+        let mut grid = BitGrid::new(70, 2, false);
+        grid.set((69, 0), true);
+        grid.set((0, 1), true);
+        grid.set((70, 0), true);
+        grid.set((0, 2), true);
+
+        assert_eq!(Some(true), grid.get((69, 0)));
+        assert_eq!(Some(true), grid.get((0, 1)));
+        assert_eq!(Some(false), grid.get((1, 1)));
+        assert_eq!(None, grid.get((70, 0)));
+        assert_eq!(2, grid.count_ones());
+
+        grid.set((69, 0), false);
+        assert_eq!(vec![(0, 1)], grid.iter_ones().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_mismatched_dims() {
+        let a = BitGrid::new(8, 8, true);
+        let b = BitGrid::new(16, 4, true);
+        let err = GridError::DimensionMismatch {
+            expected: (8, 8),
+            found: (16, 4),
+        };
+
+        assert_eq!(Err(err.clone()), a.and(&b));
+        assert_eq!(Err(err.clone()), a.or(&b));
+        assert_eq!(Err(err), a.xor(&b));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_bool_grid(grid in bool_grid(150, 6)) {
+            let bits = BitGrid::from(&grid);
+
+            prop_assert_eq!(&grid, &Grid::from(&bits));
+            prop_assert_eq!(grid.iter().filter(|&&cell| cell).count(), bits.count_ones());
+            for (coord, &cell) in grid.indexed_iter() {
+                prop_assert_eq!(Some(cell), bits.get(coord));
+            }
+
+            let ones = grid.indexed_iter().filter(|(_, &cell)| cell).map(|(coord, _)| coord);
+            prop_assert_eq!(ones.collect::<Vec<_>>(), bits.iter_ones().collect::<Vec<_>>());
+
+            let flipped = grid.map(|&cell| !cell);
+            prop_assert_eq!(BitGrid::from(&flipped), bits.not());
+            prop_assert_eq!(&bits, &bits.not().not());
+        }
+
+        #[test]
+        fn prop_boolean_ops_match((a, b) in bool_grid_pair()) {
+            let (bits_a, bits_b) = (BitGrid::from(&a), BitGrid::from(&b));
+            let zip = |f: fn(bool, bool) -> bool| {
+                let data = a.iter().zip(b.iter()).map(|(&x, &y)| f(x, y)).collect();
+                BitGrid::from(&Grid::from_vec(a.col_count(), a.row_count(), data).unwrap())
+            };
+
+            prop_assert_eq!(zip(|x, y| x & y), bits_a.and(&bits_b).unwrap());
+            prop_assert_eq!(zip(|x, y| x | y), bits_a.or(&bits_b).unwrap());
+            prop_assert_eq!(zip(|x, y| x ^ y), bits_a.xor(&bits_b).unwrap());
+        }
+
+        #[test]
+        fn prop_writes_match(
+            grid in bool_grid(150, 6),
+            writes in prop::collection::vec((0usize..160, 0usize..8, any::<bool>()), 0..40),
+        ) {
+            let mut dense = grid.clone();
+            let mut bits = BitGrid::from(&grid);
+
+            for (col, row, val) in writes {
+                dense.set((col, row), val);
+                bits.set((col, row), val);
+            }
+            prop_assert_eq!(BitGrid::from(&dense), bits.clone());
+
+            dense = Grid::new(dense.col_count(), dense.row_count(), true);
+            bits.fill(true);
+            prop_assert_eq!(BitGrid::from(&dense), bits);
+        }
+    }
+}
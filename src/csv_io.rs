@@ -0,0 +1,365 @@
+//! Reading and writing grids as CSV, behind the `csv` feature.
+
+use crate::{Coord, Grid, GridError};
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io;
+use std::str::FromStr;
+
+//////////////////////////////////////////////////////////////////////////////
+// Options & Errors
+//////////////////////////////////////////////////////////////////////////////
+
+/// Settings for reading and writing CSV.
+///
+/// The defaults use a comma delimiter and no header row.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct CsvOptions {
+    delimiter: u8,
+    headers: bool,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            headers: false,
+        }
+    }
+}
+
+impl CsvOptions {
+    /// Create the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the byte separating fields.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Skip the first record when reading, and write column indices as a
+    /// first record when writing.
+    pub fn headers(mut self, headers: bool) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    fn reader<R>(&self, r: R) -> csv::Reader<R>
+    where
+        R: io::Read,
+    {
+        csv::ReaderBuilder::new()
+            .delimiter(self.delimiter)
+            .has_headers(self.headers)
+            .flexible(true)
+            .from_reader(r)
+    }
+}
+
+/// Errors returned when reading or writing a grid as CSV.
+#[derive(Debug)]
+pub enum CsvError<E = Infallible> {
+    /// The CSV could not be read or written.
+    Csv(csv::Error),
+    /// The records did not form a grid. Ragged records are reported as
+    /// `GridError::RaggedLine` with the record's grid row, counting from 1.
+    /// A header record is not counted, and neither are line breaks inside
+    /// quoted fields.
+    Grid(GridError),
+    /// The field at `coord` could not be parsed.
+    Field { coord: Coord, error: E },
+}
+
+impl<E> From<csv::Error> for CsvError<E> {
+    fn from(err: csv::Error) -> Self {
+        CsvError::Csv(err)
+    }
+}
+
+impl<E> From<GridError> for CsvError<E> {
+    fn from(err: GridError) -> Self {
+        CsvError::Grid(err)
+    }
+}
+
+impl<E> fmt::Display for CsvError<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CsvError::Csv(err) => err.fmt(f),
+            CsvError::Grid(err) => err.fmt(f),
+            CsvError::Field { coord, error } => write!(
+                f,
+                "cannot parse the field at ({}, {}): {}",
+                coord.0, coord.1, error
+            ),
+        }
+    }
+}
+
+impl<E> Error for CsvError<E>
+where
+    E: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CsvError::Csv(err) => Some(err),
+            CsvError::Grid(err) => Some(err),
+            CsvError::Field { error, .. } => Some(error),
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Reading
+//////////////////////////////////////////////////////////////////////////////
+
+impl Grid<String> {
+    /// Read a grid of fields from CSV with the default options, one row
+    /// per record.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the CSV is malformed or its records differ in length.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let grid = Grid::from_csv_reader("a,\"b,c\"\nd,e\n".as_bytes()).unwrap();
+    ///
+    /// assert_eq!(Some(&String::from("b,c")), grid.get((1, 0)));
+    /// ```
+    pub fn from_csv_reader<R>(r: R) -> Result<Self, CsvError>
+    where
+        R: io::Read,
+    {
+        Self::from_csv_reader_with(r, &CsvOptions::default())
+    }
+
+    /// Read a grid of fields from CSV, one row per record.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the CSV is malformed or its records differ in length.
+    pub fn from_csv_reader_with<R>(r: R, opts: &CsvOptions) -> Result<Self, CsvError>
+    where
+        R: io::Read,
+    {
+        Self::parse_csv_reader(r, opts)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Read a grid from CSV, parsing each field with `FromStr`.
+    ///
+    /// # Errors
+    ///
+    /// Fails if the CSV is malformed, its records differ in length, or a
+    /// field does not parse.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{CsvOptions, Grid};
+    ///
+    /// let opts = CsvOptions::new().delimiter(b';').headers(true);
+    /// let grid: Grid<u8> = Grid::parse_csv_reader("x;y\n1;2\n3;4".as_bytes(), &opts).unwrap();
+    ///
+    /// assert_eq!((2, 2), (grid.col_count(), grid.row_count()));
+    /// assert_eq!(Some(&3), grid.get((0, 1)));
+    /// ```
+    pub fn parse_csv_reader<R>(r: R, opts: &CsvOptions) -> Result<Self, CsvError<T::Err>>
+    where
+        R: io::Read,
+        T: FromStr,
+    {
+        let mut col_count = 0;
+        let mut row_count = 0;
+        let mut data = Vec::new();
+        let mut record = csv::StringRecord::new();
+        let mut reader = opts.reader(r);
+
+        while reader.read_record(&mut record)? {
+            if row_count == 0 {
+                col_count = record.len();
+            } else if record.len() != col_count {
+                return Err(GridError::RaggedLine {
+                    line: row_count + 1,
+                    expected: col_count,
+                    found: record.len(),
+                }
+                .into());
+            }
+
+            for (col, field) in record.iter().enumerate() {
+                let cell = field.parse().map_err(|error| CsvError::Field {
+                    coord: (col, row_count),
+                    error,
+                })?;
+                data.push(cell);
+            }
+
+            row_count += 1;
+        }
+
+        Ok(Grid {
+            col_count,
+            row_count,
+            data,
+        })
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Writing
+//////////////////////////////////////////////////////////////////////////////
+
+impl<T> Grid<T>
+where
+    T: Display,
+{
+    /// Write the grid as CSV with the default options, one record per row.
+    ///
+    /// Fields containing delimiters, quotes, or newlines are quoted.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `w` cannot be written to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut out = Vec::new();
+    /// Grid::new(2, 2, 5).write_csv(&mut out).unwrap();
+    ///
+    /// assert_eq!(b"5,5\n5,5\n", &out[..]);
+    /// ```
+    pub fn write_csv<W>(&self, w: W) -> Result<(), CsvError>
+    where
+        W: io::Write,
+    {
+        self.write_csv_with(w, &CsvOptions::default())
+    }
+
+    /// Write the grid as CSV, one record per row.
+    ///
+    /// # Errors
+    ///
+    /// Fails if `w` cannot be written to.
+    pub fn write_csv_with<W>(&self, w: W, opts: &CsvOptions) -> Result<(), CsvError>
+    where
+        W: io::Write,
+    {
+        let mut writer = csv::WriterBuilder::new()
+            .delimiter(opts.delimiter)
+            .from_writer(w);
+
+        if opts.headers {
+            writer.write_record((0..self.col_count).map(|col| col.to_string()))?;
+        }
+
+        for row in 0..self.row_count {
+            let cells = self.data[self.row_range(row)].iter();
+            writer.write_record(cells.map(|cell| cell.to_string()))?;
+        }
+
+        writer.flush().map_err(csv::Error::from)?;
+
+        Ok(())
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::labeled;
+
+    fn to_csv<T: Display>(grid: &Grid<T>, opts: &CsvOptions) -> String {
+        let mut out = Vec::new();
+        grid.write_csv_with(&mut out, opts).unwrap();
+
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn test_csv_round_trip() {
+        let grid = labeled(3, 4).map(|&cell| cell as f64 * 1.5 - 4.0);
+        let text = to_csv(&grid, &CsvOptions::new());
+
+        assert!(text.starts_with("-4,-2.5,-1\n0.5,2,3.5\n"));
+
+        let parsed = Grid::parse_csv_reader(text.as_bytes(), &CsvOptions::new());
+        assert_eq!(grid, parsed.unwrap());
+    }
+
+    #[test]
+    fn test_csv_quoting() {
+        let mut grid = Grid::new(2, 2, String::from("plain"));
+        grid.set((0, 0), String::from("a, b"));
+        grid.set((1, 1), String::from("two\nlines and \"quotes\""));
+
+        let text = to_csv(&grid, &CsvOptions::new());
+        assert_eq!("\"a, b\",plain\nplain,\"two\nlines and \"\"quotes\"\"\"\n", text);
+        assert_eq!(grid, Grid::from_csv_reader(text.as_bytes()).unwrap());
+    }
+
+    #[test]
+    fn test_csv_headers_and_delimiter() {
+        let opts = CsvOptions::new().headers(true).delimiter(b'\t');
+        let grid = labeled(2, 2);
+        let text = to_csv(&grid, &opts);
+
+        assert_eq!("0\t1\n0\t1\n2\t3\n", text);
+        assert_eq!(grid, Grid::parse_csv_reader(text.as_bytes(), &opts).unwrap());
+
+        let opts = CsvOptions::new().headers(true);
+        let skipped = Grid::from_csv_reader_with("name,age\nada,36\n".as_bytes(), &opts);
+        assert_eq!(1, skipped.unwrap().row_count());
+    }
+
+    #[test]
+    fn test_csv_errors() {
+        let ragged = Grid::from_csv_reader("1,2\n3,\"4\n5\"\n6\n".as_bytes()).unwrap_err();
+        match ragged {
+            CsvError::Grid(err) => assert_eq!(
+                GridError::RaggedLine {
+                    line: 3,
+                    expected: 2,
+                    found: 1,
+                },
+                err
+            ),
+            err => panic!("unexpected error {}", err),
+        }
+
+        let opts = CsvOptions::new().headers(true);
+        let ragged = Grid::from_csv_reader_with("a,b\n1,2\n3\n".as_bytes(), &opts);
+        match ragged.unwrap_err() {
+            CsvError::Grid(GridError::RaggedLine { line, .. }) => assert_eq!(2, line),
+            err => panic!("unexpected error {}", err),
+        }
+
+        let bad =Grid::<u8>::parse_csv_reader("1,2\n3,x".as_bytes(), &CsvOptions::new());
+        match bad.unwrap_err() {
+            CsvError::Field { coord, .. } => assert_eq!((1, 1), coord),
+            err => panic!("unexpected error {}", err),
+        }
+
+        let empty = Grid::from_csv_reader("".as_bytes()).unwrap();
+        assert_eq!((0, 0), (empty.col_count(), empty.row_count()));
+    }
+}
@@ -0,0 +1,92 @@
+//! Iterators that move rows out of a `Grid`.
+
+use alloc::vec::{self, Vec};
+use core::iter::FusedIterator;
+
+//////////////////////////////////////////////////////////////////////////////
+// Draining Rows
+//////////////////////////////////////////////////////////////////////////////
+
+/// An iterator that removes a range of rows from a `Grid` and yields each
+/// as a `Vec`.
+///
+/// Created by `Grid::drain_rows`. The whole range is removed when the
+/// iterator is dropped, even if it was not fully consumed.
+#[derive(Debug)]
+pub struct DrainRows<'a, T> {
+    cells: vec::Drain<'a, T>,
+    col_count: usize,
+    remaining: usize,
+    row_count: &'a mut usize,
+    final_row_count: usize,
+}
+
+impl<'a, T> DrainRows<'a, T> {
+    /// Wrap a drain over `row_count`'s grid that covers whole rows.
+    ///
+    /// While the iterator is alive the grid's row count only covers the
+    /// rows before the drained range, matching the length `Vec::drain`
+    /// leaves the buffer at, so leaking the iterator keeps the grid
+    /// consistent.
+    pub(crate) fn new(
+        cells: vec::Drain<'a, T>,
+        col_count: usize,
+        row_count: &'a mut usize,
+        start: usize,
+        end: usize,
+    ) -> Self {
+        let final_row_count = *row_count - (end - start);
+        *row_count = start;
+
+        Self {
+            cells,
+            col_count,
+            remaining: end - start,
+            row_count,
+            final_row_count,
+        }
+    }
+}
+
+impl<'a, T> Iterator for DrainRows<'a, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        Some(self.cells.by_ref().take(self.col_count).collect())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for DrainRows<'a, T> {
+    fn next_back(&mut self) -> Option<Vec<T>> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        self.remaining -= 1;
+        let mut row: Vec<T> = self.cells.by_ref().rev().take(self.col_count).collect();
+        row.reverse();
+
+        Some(row)
+    }
+}
+
+impl<'a, T> ExactSizeIterator for DrainRows<'a, T> {}
+
+impl<'a, T> FusedIterator for DrainRows<'a, T> {}
+
+impl<'a, T> Drop for DrainRows<'a, T> {
+    fn drop(&mut self) {
+        // The wrapped `Drain` drops any unyielded cells and moves the rows
+        // after the range back into place once this returns.
+        *self.row_count = self.final_row_count;
+    }
+}
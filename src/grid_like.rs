@@ -0,0 +1,348 @@
+//! Traits for code that is generic over grids and views of grids.
+
+use crate::{
+    Coord, Grid, GridView, GridViewMut, Offset, StaticGrid, StridedView, TransposedView,
+};
+#[cfg(feature = "std")]
+use crate::HashGrid;
+
+//////////////////////////////////////////////////////////////////////////////
+// Read Access
+//////////////////////////////////////////////////////////////////////////////
+
+/// Anything cells can be read from by (column, row) coordinate.
+///
+/// Implemented by `Grid`, its borrowed views, and references to any of
+/// them. `MapView` is not included, since it produces values on demand
+/// rather than handing out references to stored cells.
+///
+/// # Examples
+///
+/// ```
+/// use gridd::{Grid, GridLike, Rect};
+///
+/// fn count_walls<G: GridLike<Item = char>>(grid: &G) -> usize {
+///     GridLike::iter(grid).filter(|&&c| c == '#').count()
+/// }
+///
+/// let mut grid = Grid::new(4, 4, '.');
+/// grid.set((0, 0), '#');
+/// grid.set((2, 2), '#');
+///
+/// assert_eq!(2, count_walls(&grid));
+/// assert_eq!(1, count_walls(&grid.view(Rect::new((1, 1), 3, 3)).unwrap()));
+/// ```
+pub trait GridLike {
+    /// The type of value stored in each cell.
+    type Item;
+
+    /// Get the column count.
+    fn col_count(&self) -> usize;
+
+    /// Get the row count.
+    fn row_count(&self) -> usize;
+
+    /// Get an immutable reference to some cell.
+    fn get(&self, coord: Coord) -> Option<&Self::Item>;
+
+    /// Determine if a coordinate is within bounds.
+    fn contains(&self, (col, row): Coord) -> bool {
+        col < self.col_count() && row < self.row_count()
+    }
+
+    /// Get an immutable reference to the cell with the given positional
+    /// relationship to the provided coordinate.
+    fn rget(&self, anchor: Coord, vec: Offset) -> Option<&Self::Item> {
+        vec.rcoord(anchor).and_then(|coord| self.get(coord))
+    }
+
+    /// Iterate over the cells in row-major order.
+    fn iter(&self) -> impl Iterator<Item = &Self::Item> {
+        GridLike::indexed_iter(self).map(|(_, cell)| cell)
+    }
+
+    /// Iterate over the cells in row-major order, along with their
+    /// coordinates.
+    fn indexed_iter(&self) -> impl Iterator<Item = (Coord, &Self::Item)> {
+        let col_count = self.col_count();
+
+        (0..self.row_count()).flat_map(move |row| {
+            (0..col_count)
+                .filter_map(move |col| self.get((col, row)).map(|cell| ((col, row), cell)))
+        })
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Write Access
+//////////////////////////////////////////////////////////////////////////////
+
+/// Anything cells can be read from and written to by coordinate.
+pub trait GridLikeMut: GridLike {
+    /// Get a mutable reference to some cell.
+    fn get_mut(&mut self, coord: Coord) -> Option<&mut Self::Item>;
+
+    /// Set a cell's value. Coordinates out of bounds are ignored.
+    fn set(&mut self, coord: Coord, new_val: Self::Item) {
+        if let Some(val) = self.get_mut(coord) {
+            *val = new_val;
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Implementations
+//////////////////////////////////////////////////////////////////////////////
+
+/// Implement `GridLike` by forwarding to a type's inherent methods.
+macro_rules! forward_grid_like {
+    ($($ty:ty),*) => {
+        $(
+            impl<'a, T> GridLike for $ty {
+                type Item = T;
+
+                fn col_count(&self) -> usize {
+                    <$ty>::col_count(self)
+                }
+
+                fn row_count(&self) -> usize {
+                    <$ty>::row_count(self)
+                }
+
+                fn get(&self, coord: Coord) -> Option<&T> {
+                    <$ty>::get(self, coord)
+                }
+            }
+        )*
+    };
+}
+
+forward_grid_like!(
+    GridView<'a, T>,
+    GridViewMut<'a, T>,
+    StridedView<'a, T>,
+    TransposedView<'a, T>
+);
+
+impl<T> GridLike for Grid<T> {
+    type Item = T;
+
+    fn col_count(&self) -> usize {
+        Grid::col_count(self)
+    }
+
+    fn row_count(&self) -> usize {
+        Grid::row_count(self)
+    }
+
+    fn get(&self, coord: Coord) -> Option<&T> {
+        Grid::get(self, coord)
+    }
+}
+
+impl<T, const C: usize, const R: usize> GridLike for StaticGrid<T, C, R> {
+    type Item = T;
+
+    fn col_count(&self) -> usize {
+        C
+    }
+
+    fn row_count(&self) -> usize {
+        R
+    }
+
+    fn get(&self, coord: Coord) -> Option<&T> {
+        StaticGrid::get(self, coord)
+    }
+}
+
+/// An unbounded `HashGrid` measures its extent on every call to
+/// `col_count` or `row_count`, visiting each occupied cell, so generic code
+/// should read the counts once rather than per cell. The provided
+/// `indexed_iter` does, then probes every coordinate within the extent.
+#[cfg(feature = "std")]
+impl<T> GridLike for HashGrid<T> {
+    type Item = T;
+
+    fn col_count(&self) -> usize {
+        HashGrid::col_count(self)
+    }
+
+    fn row_count(&self) -> usize {
+        HashGrid::row_count(self)
+    }
+
+    fn get(&self, coord: Coord) -> Option<&T> {
+        HashGrid::get(self, coord)
+    }
+
+    fn contains(&self, coord: Coord) -> bool {
+        HashGrid::contains(self, coord)
+    }
+}
+
+impl<G> GridLike for &G
+where
+    G: GridLike + ?Sized,
+{
+    type Item = G::Item;
+
+    fn col_count(&self) -> usize {
+        (**self).col_count()
+    }
+
+    fn row_count(&self) -> usize {
+        (**self).row_count()
+    }
+
+    fn get(&self, coord: Coord) -> Option<&G::Item> {
+        (**self).get(coord)
+    }
+
+    fn contains(&self, coord: Coord) -> bool {
+        (**self).contains(coord)
+    }
+}
+
+impl<G> GridLike for &mut G
+where
+    G: GridLike + ?Sized,
+{
+    type Item = G::Item;
+
+    fn col_count(&self) -> usize {
+        (**self).col_count()
+    }
+
+    fn row_count(&self) -> usize {
+        (**self).row_count()
+    }
+
+    fn get(&self, coord: Coord) -> Option<&G::Item> {
+        (**self).get(coord)
+    }
+
+    fn contains(&self, coord: Coord) -> bool {
+        (**self).contains(coord)
+    }
+}
+
+impl<T> GridLikeMut for Grid<T> {
+    fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        Grid::get_mut(self, coord)
+    }
+}
+
+impl<'a, T> GridLikeMut for GridViewMut<'a, T> {
+    fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        GridViewMut::get_mut(self, coord)
+    }
+}
+
+impl<T, const C: usize, const R: usize> GridLikeMut for StaticGrid<T, C, R> {
+    fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        StaticGrid::get_mut(self, coord)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> GridLikeMut for HashGrid<T> {
+    fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        HashGrid::get_mut(self, coord)
+    }
+
+    fn set(&mut self, coord: Coord, new_val: T) {
+        HashGrid::set(self, coord, new_val)
+    }
+}
+
+impl<G> GridLikeMut for &mut G
+where
+    G: GridLikeMut + ?Sized,
+{
+    fn get_mut(&mut self, coord: Coord) -> Option<&mut G::Item> {
+        (**self).get_mut(coord)
+    }
+
+    fn set(&mut self, coord: Coord, new_val: G::Item) {
+        (**self).set(coord, new_val)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::labeled;
+    use crate::Rect;
+
+    /// Sum each row, using nothing but the trait.
+    fn row_sums<G: GridLike<Item = usize>>(grid: &G) -> Vec<usize> {
+        (0..grid.row_count())
+            .map(|row| {
+                (0..grid.col_count())
+                    .filter_map(|col| GridLike::get(grid, (col, row)))
+                    .sum()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_generic_over_grid_and_views() {
+        let grid = labeled(3, 2);
+        let whole = grid.view(Rect::new((0, 0), 3, 2)).unwrap();
+
+        assert_eq!(vec![3, 12], row_sums(&grid));
+        assert_eq!(row_sums(&grid), row_sums(&whole));
+        assert_eq!(row_sums(&grid), row_sums(&&grid));
+        assert_eq!(row_sums(&grid.transpose()), row_sums(&grid.transposed()));
+        assert_eq!(vec![3, 12], row_sums(&grid.strided(1, 1)));
+
+        let fixed: StaticGrid<usize, 3, 2> = StaticGrid::from_fn(|(col, row)| col + row * 3);
+        assert_eq!(row_sums(&grid), row_sums(&fixed));
+
+        let mut fixed = fixed;
+        mark_diagonal(&mut fixed);
+        assert_eq!(vec![3, 8], row_sums(&fixed));
+    }
+
+    #[test]
+    fn test_provided_methods() {
+        let grid = labeled(4, 3);
+        let view = grid.view(Rect::new((1, 1), 2, 2)).unwrap();
+
+        assert!(GridLike::iter(&grid).eq(grid.iter()));
+        assert!(GridLike::indexed_iter(&view).eq(view.indexed_iter()));
+        assert_eq!(
+            grid.transposed().iter().collect::<Vec<_>>(),
+            GridLike::iter(&grid.transposed()).collect::<Vec<_>>()
+        );
+        assert!(GridLike::contains(&view, (1, 1)));
+        assert!(!GridLike::contains(&view, (2, 0)));
+        assert_eq!(Some(&9), GridLike::rget(&view, (0, 0), Offset::SOUTH));
+    }
+
+    /// Write a value along the main diagonal, using nothing but the trait.
+    fn mark_diagonal<G: GridLikeMut<Item = usize>>(grid: &mut G) {
+        for i in 0..grid.col_count().min(grid.row_count()) {
+            grid.set((i, i), 0);
+        }
+    }
+
+    #[test]
+    fn test_generic_mut() {
+        let mut grid = labeled(4, 4);
+        mark_diagonal(&mut grid.view_mut(Rect::new((1, 1), 2, 3)).unwrap());
+
+        let mut expected = labeled(4, 4);
+        expected.set((1, 1), 0);
+        expected.set((2, 2), 0);
+        assert_eq!(expected, grid);
+
+        mark_diagonal(&mut &mut grid);
+        assert_eq!(Some(&0), grid.get((3, 3)));
+    }
+}
@@ -0,0 +1,363 @@
+//! Sparse grids that store only their occupied cells.
+
+use crate::{Coord, Grid, Offset};
+use std::collections::HashMap;
+
+/// A sparse grid, storing occupied cells in a `HashMap` keyed by
+/// coordinate.
+///
+/// Suited to huge, mostly-empty boards, where a dense `Grid` would spend
+/// most of its memory on a background value. A grid is either bounded,
+/// ignoring writes outside its column and row counts like `Grid`, or
+/// unbounded, accepting any coordinate.
+///
+/// # Examples
+///
+/// ```
+/// use gridd::HashGrid;
+///
+/// let mut life = HashGrid::new();
+/// life.set((1_000_000, 7), true);
+///
+/// assert_eq!(Some(&true), life.get((1_000_000, 7)));
+/// assert_eq!(None, life.get((0, 0)));
+/// assert_eq!(1, life.len());
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct HashGrid<T> {
+    bounds: Option<(usize, usize)>,
+    cells: HashMap<Coord, T>,
+}
+
+impl<T> HashGrid<T> {
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Create an empty, unbounded grid.
+    pub fn new() -> Self {
+        Self {
+            bounds: None,
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Create an empty grid with the given column and row counts.
+    pub fn with_size(col_count: usize, row_count: usize) -> Self {
+        Self {
+            bounds: Some((col_count, row_count)),
+            cells: HashMap::new(),
+        }
+    }
+
+    //////////////////////////////////
+    // Shape
+    //////////////////////////////////
+
+    /// Get the grid's (column, row) counts, or `None` if it is unbounded.
+    pub fn bounds(&self) -> Option<(usize, usize)> {
+        self.bounds
+    }
+
+    /// Get the (column, row) counts of the smallest grid anchored at
+    /// `(0, 0)` that holds every occupied cell.
+    ///
+    /// This visits every occupied cell. The counts saturate at
+    /// `usize::MAX`, so a cell in the last possible column or row is left
+    /// just outside them.
+    pub fn extent(&self) -> (usize, usize) {
+        self.cells.keys().fold((0, 0), |(cols, rows), &(col, row)| {
+            (cols.max(col.saturating_add(1)), rows.max(row.saturating_add(1)))
+        })
+    }
+
+    /// Get the column count: the bound if there is one, or else the
+    /// extent's.
+    ///
+    /// For an unbounded grid, this visits every occupied cell.
+    pub fn col_count(&self) -> usize {
+        self.bounds.unwrap_or_else(|| self.extent()).0
+    }
+
+    /// Get the row count: the bound if there is one, or else the extent's.
+    ///
+    /// For an unbounded grid, this visits every occupied cell.
+    pub fn row_count(&self) -> usize {
+        self.bounds.unwrap_or_else(|| self.extent()).1
+    }
+
+    /// Determine if a coordinate is within the grid's bounds. Every
+    /// coordinate is within an unbounded grid.
+    pub fn contains(&self, (col, row): Coord) -> bool {
+        self.bounds
+            .is_none_or(|(col_count, row_count)| col < col_count && row < row_count)
+    }
+
+    /// Determine if a cell holds a value.
+    pub fn is_occupied(&self, coord: Coord) -> bool {
+        self.cells.contains_key(&coord)
+    }
+
+    /// Get the number of occupied cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Determine if no cell is occupied.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    //////////////////////////////////
+    // Access
+    //////////////////////////////////
+
+    /// Get an immutable reference to some occupied cell.
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.cells.get(&coord)
+    }
+
+    /// Get a mutable reference to some occupied cell.
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.cells.get_mut(&coord)
+    }
+
+    /// Get an immutable reference to the occupied cell with the given
+    /// positional relationship to the provided coordinate.
+    pub fn rget(&self, anchor: Coord, vec: Offset) -> Option<&T> {
+        vec.rcoord(anchor).and_then(|coord| self.get(coord))
+    }
+
+    /// Get a mutable reference to the occupied cell with the given
+    /// positional relationship to the provided coordinate.
+    pub fn rget_mut(&mut self, anchor: Coord, vec: Offset) -> Option<&mut T> {
+        match vec.rcoord(anchor) {
+            Some(coord) => self.get_mut(coord),
+            _ => None,
+        }
+    }
+
+    /// Occupy a cell with a value. Coordinates out of bounds are ignored.
+    pub fn set(&mut self, coord: Coord, new_val: T) {
+        if self.contains(coord) {
+            self.cells.insert(coord, new_val);
+        }
+    }
+
+    /// Empty a cell, returning its value if it was occupied.
+    pub fn remove(&mut self, coord: Coord) -> Option<T> {
+        self.cells.remove(&coord)
+    }
+
+    //////////////////////////////////
+    // Iteration
+    //////////////////////////////////
+
+    /// Iterate over the occupied cells, along with their coordinates, in
+    /// no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> + '_ {
+        self.cells.iter().map(|(&coord, cell)| (coord, cell))
+    }
+
+    /// Iterate mutably over the occupied cells, along with their
+    /// coordinates, in no particular order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Coord, &mut T)> + '_ {
+        self.cells.iter_mut().map(|(&coord, cell)| (coord, cell))
+    }
+
+    //////////////////////////////////
+    // Conversions
+    //////////////////////////////////
+
+    /// Create a grid bounded to a dense grid's size, occupied by every cell
+    /// not equal to `background`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Grid, HashGrid};
+    ///
+    /// let dense: Grid<char> = "..#\n#..".parse().unwrap();
+    /// let sparse = HashGrid::from_dense(dense.clone(), &'.');
+    ///
+    /// assert_eq!(Some((3, 2)), sparse.bounds());
+    /// assert_eq!(2, sparse.len());
+    /// assert_eq!(dense, sparse.to_dense('.'));
+    /// ```
+    pub fn from_dense(grid: Grid<T>, background: &T) -> Self
+    where
+        T: PartialEq,
+    {
+        let col_count = grid.col_count;
+        let cells = grid
+            .data
+            .into_iter()
+            .enumerate()
+            .filter(|(_, cell)| cell != background)
+            .map(|(index, cell)| ((index % col_count, index / col_count), cell))
+            .collect();
+
+        Self {
+            bounds: Some((col_count, grid.row_count)),
+            cells,
+        }
+    }
+
+    /// Create a dense grid filled with `background`, with the occupied
+    /// cells written over it.
+    ///
+    /// The dense grid's size is the bound, or the extent if the grid is
+    /// unbounded.
+    pub fn to_dense(&self, background: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let (col_count, row_count) = self.bounds.unwrap_or_else(|| self.extent());
+        let cells = self.iter().map(|(coord, cell)| (coord, cell.clone()));
+
+        Grid::from_sparse(col_count, row_count, background, cells)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Connectivity, GridLike};
+    use std::collections::VecDeque;
+
+    /// Find the length of the shortest path between two cells, stepping
+    /// only onto cells that `open` accepts.
+    fn bfs<G, F>(grid: &G, start: Coord, goal: Coord, open: F) -> Option<usize>
+    where
+        G: GridLike,
+        F: Fn(Option<&G::Item>) -> bool,
+    {
+        let mut dist = HashMap::new();
+        let mut queue = VecDeque::new();
+        dist.insert(start, 0);
+        queue.push_back(start);
+
+        while let Some(coord) = queue.pop_front() {
+            if coord == goal {
+                return dist.get(&goal).copied();
+            }
+
+            for &vec in Connectivity::Four.offsets() {
+                let next = match vec.rcoord(coord) {
+                    Some(next) if grid.contains(next) && open(grid.get(next)) => next,
+                    _ => continue,
+                };
+
+                if !dist.contains_key(&next) {
+                    dist.insert(next, dist[&coord] + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        None
+    }
+
+    #[test]
+    fn test_bounded_writes() {
+        let mut grid = HashGrid::with_size(3, 2);
+        grid.set((2, 1), 'a');
+        grid.set((3, 0), 'b');
+        grid.set((0, 2), 'c');
+
+        assert_eq!(1, grid.len());
+        assert!(grid.is_occupied((2, 1)));
+        assert!(!grid.is_occupied((3, 0)));
+        assert!(grid.contains((0, 0)));
+        assert!(!grid.contains((0, 2)));
+        assert_eq!((3, 2), (grid.col_count(), grid.row_count()));
+        assert_eq!((3, 2), grid.extent());
+
+        *grid.get_mut((2, 1)).unwrap() = 'z';
+        assert_eq!(Some(&'z'), grid.rget((1, 0), Offset::from((1, 1))));
+        assert_eq!(None, grid.rget((0, 0), Offset::from((-1, 0))));
+        assert_eq!(Some('z'), grid.remove((2, 1)));
+        assert!(grid.is_empty());
+    }
+
+    #[test]
+    fn test_unbounded() {
+        let mut grid = HashGrid::new();
+        assert_eq!((0, 0), grid.extent());
+
+        grid.set((500, 2), 1);
+        grid.set((4, 900), 2);
+        *grid.rget_mut((499, 2), Offset::EAST).unwrap() += 10;
+
+        assert_eq!(None, grid.bounds());
+        assert!(grid.contains((usize::MAX, usize::MAX)));
+        assert_eq!((501, 901), (grid.col_count(), grid.row_count()));
+        assert_eq!(Some(&11), grid.get((500, 2)));
+
+        grid.remove((500, 2));
+        assert_eq!((5, 901), grid.extent());
+
+        grid.set((usize::MAX, 3), 4);
+        assert_eq!((usize::MAX, 901), grid.extent());
+        assert_eq!(Some(&4), grid.get((usize::MAX, 3)));
+    }
+
+    #[test]
+    fn test_iteration_visits_occupied_cells() {
+        let mut grid = HashGrid::with_size(1000, 1000);
+        grid.set((10, 20), 1);
+        grid.set((999, 0), 2);
+        grid.set((0, 999), 3);
+
+        let mut cells: Vec<_> = grid.iter().map(|(coord, &cell)| (coord, cell)).collect();
+        cells.sort_unstable();
+        assert_eq!(vec![((0, 999), 3), ((10, 20), 1), ((999, 0), 2)], cells);
+
+        grid.iter_mut().for_each(|(_, cell)| *cell *= 10);
+        assert_eq!(60, grid.iter().map(|(_, &cell)| cell).sum::<i32>());
+    }
+
+    #[test]
+    fn test_dense_round_trip() {
+        let dense: Grid<u8> = Grid::from_sparse(6, 4, 0, vec![((1, 1), 5), ((5, 3), 9)]);
+        let sparse = HashGrid::from_dense(dense.clone(), &0);
+
+        assert_eq!(2, sparse.len());
+        assert_eq!(Some(&9), sparse.get((5, 3)));
+        assert_eq!(dense, sparse.to_dense(0));
+        assert_eq!(sparse, HashGrid::from_dense(sparse.to_dense(0), &0));
+
+        let empty: Grid<u8> = Grid::new(0, 3, 0);
+        assert_eq!(empty, HashGrid::from_dense(empty.clone(), &0).to_dense(0));
+
+        let mut unbounded = HashGrid::new();
+        unbounded.set((2, 1), 'x');
+        assert_eq!(Grid::from_sparse(3, 2, '.', vec![((2, 1), 'x')]), unbounded.to_dense('.'));
+    }
+
+    #[test]
+    fn test_bfs_matches_dense() {
+        let dense: Grid<char> = concat!(
+            "..#.......\n",
+            ".##.####..\n",
+            "....#..#..\n",
+            "###.#.##.#\n",
+            "....#.....",
+        )
+        .parse()
+        .unwrap();
+        let sparse = HashGrid::from_dense(dense.clone(), &'.');
+        let open = |cell: Option<&char>| cell.is_none_or(|&c| c != '#');
+
+        for &goal in &[(9, 4), (5, 2), (0, 4), (2, 0)] {
+            let expected = bfs(&dense, (0, 0), goal, open);
+            assert_eq!(expected, bfs(&sparse, (0, 0), goal, open));
+        }
+        assert_eq!(Some(17), bfs(&sparse, (0, 0), (9, 4), open));
+        assert_eq!(None, bfs(&sparse, (0, 0), (2, 0), open));
+    }
+}
@@ -0,0 +1,180 @@
+//! Conversions to and from `image` buffers, behind the `image` feature.
+//!
+//! Pixel `(x, y)` of an image is cell `(col, row)` of the matching grid.
+
+use crate::Grid;
+use image::{GrayImage, Rgb, RgbImage};
+use std::convert::TryFrom;
+
+/// Get an image's `(width, height)` for a grid.
+///
+/// Panics if either dimension does not fit in a `u32`.
+fn image_dims<T>(grid: &Grid<T>) -> (u32, u32) {
+    let dim = |len| u32::try_from(len).expect("grid is too large for an image");
+
+    (dim(grid.col_count), dim(grid.row_count))
+}
+
+impl Grid<u8> {
+    /// Copy the grid into a grayscale image.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either dimension does not fit in a `u32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 2, 0);
+    /// grid.set((2, 1), 255);
+    ///
+    /// let img = grid.to_luma_image();
+    /// assert_eq!((3, 2), img.dimensions());
+    /// assert_eq!([255], img.get_pixel(2, 1).0);
+    /// ```
+    pub fn to_luma_image(&self) -> GrayImage {
+        let (width, height) = image_dims(self);
+
+        GrayImage::from_raw(width, height, self.data.clone())
+            .expect("a grid's buffer matches its shape")
+    }
+
+    /// Create a grid from a grayscale image.
+    pub fn from_luma_image(img: &GrayImage) -> Self {
+        let (col_count, row_count) = (img.width() as usize, img.height() as usize);
+
+        // An image's buffer may be longer than its pixels; drop the excess.
+        Self {
+            col_count,
+            row_count,
+            data: img.as_raw()[..col_count * row_count].to_vec(),
+        }
+    }
+}
+
+impl Grid<[u8; 3]> {
+    /// Copy the grid into an RGB image.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either dimension does not fit in a `u32`.
+    pub fn to_rgb_image(&self) -> RgbImage {
+        let (width, height) = image_dims(self);
+        let raw = self.data.iter().flatten().copied().collect();
+
+        RgbImage::from_raw(width, height, raw).expect("a grid's buffer matches its shape")
+    }
+
+    /// Create a grid from an RGB image.
+    pub fn from_rgb_image(img: &RgbImage) -> Self {
+        Self {
+            col_count: img.width() as usize,
+            row_count: img.height() as usize,
+            data: img.pixels().map(|pixel| pixel.0).collect(),
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Draw the grid as an RGB image, coloring each cell with `palette`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either dimension does not fit in a `u32`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    /// use image::Rgb;
+    ///
+    /// let mut walls = Grid::new(4, 4, false);
+    /// walls.set((1, 2), true);
+    ///
+    /// let img = walls.to_image_with(|&wall| if wall { Rgb([0, 0, 0]) } else { Rgb([255; 3]) });
+    /// assert_eq!(&Rgb([0, 0, 0]), img.get_pixel(1, 2));
+    /// ```
+    pub fn to_image_with<F>(&self, palette: F) -> RgbImage
+    where
+        F: Fn(&T) -> Rgb<u8>,
+    {
+        let (width, height) = image_dims(self);
+
+        RgbImage::from_fn(width, height, |x, y| {
+            palette(&self.data[self.flat_index((x as usize, y as usize))])
+        })
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::labeled;
+    use image::Luma;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Terrain {
+        Water,
+        Grass,
+        Rock,
+    }
+
+    fn color(terrain: &Terrain) -> Rgb<u8> {
+        match terrain {
+            Terrain::Water => Rgb([0, 0, 200]),
+            Terrain::Grass => Rgb([0, 160, 0]),
+            Terrain::Rock => Rgb([120, 120, 120]),
+        }
+    }
+
+    #[test]
+    fn test_luma_round_trip() {
+        let img = GrayImage::from_fn(5, 3, |x, y| Luma([(x * 40 + y) as u8]));
+        let grid = Grid::from_luma_image(&img);
+
+        assert_eq!((5, 3), (grid.col_count(), grid.row_count()));
+        assert_eq!(Some(&162), grid.get((4, 2)));
+        assert_eq!(img, grid.to_luma_image());
+    }
+
+    #[test]
+    fn test_luma_oversized_buffer() {
+        let img = GrayImage::from_raw(2, 2, (1..=10).collect()).unwrap();
+        let grid = Grid::from_luma_image(&img);
+
+        assert_eq!((2, 2), (grid.col_count(), grid.row_count()));
+        assert_eq!(&[1, 2, 3, 4], grid.as_slice());
+    }
+
+    #[test]
+    fn test_rgb_round_trip() {
+        let grid = labeled(4, 2).map(|&cell| [cell as u8, 10 * cell as u8, 255]);
+        let img = grid.to_rgb_image();
+
+        assert_eq!((4, 2), img.dimensions());
+        assert_eq!(&Rgb([6, 60, 255]), img.get_pixel(2, 1));
+        assert_eq!(grid, Grid::from_rgb_image(&img));
+    }
+
+    #[test]
+    fn test_palette() {
+        let mut map = Grid::new(3, 2, Terrain::Grass);
+        map.set((0, 0), Terrain::Water);
+        map.set((2, 1), Terrain::Rock);
+
+        let img = map.to_image_with(color);
+        assert_eq!((3, 2), img.dimensions());
+        for ((col, row), terrain) in map.indexed_iter() {
+            assert_eq!(&color(terrain), img.get_pixel(col as u32, row as u32));
+        }
+
+        let empty: Grid<Terrain> = Grid::new(0, 4, Terrain::Water);
+        assert_eq!((0, 4), empty.to_image_with(color).dimensions());
+    }
+}
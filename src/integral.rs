@@ -0,0 +1,182 @@
+//! Summed-area tables for constant-time rectangle sums.
+
+use crate::{Grid, Rect};
+use core::ops::{Add, Sub};
+
+//////////////////////////////////////////////////////////////////////////////
+// Integral Images
+//////////////////////////////////////////////////////////////////////////////
+
+/// A summed-area table over a grid, answering rectangle-sum queries in
+/// constant time.
+///
+/// Created by `Grid::integral_image`. Sums are accumulated in `S`, which
+/// can be wider than the grid's cell type to avoid overflow.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct IntegralGrid<S> {
+    /// Cell `(c, r)` holds the sum of every source cell with column below
+    /// `c` and row below `r`, so the first row and column are all zero.
+    table: Grid<S>,
+}
+
+impl<S> IntegralGrid<S>
+where
+    S: Copy + Add<Output = S> + Sub<Output = S>,
+{
+    /// Get the source grid's column count.
+    pub fn col_count(&self) -> usize {
+        self.table.col_count() - 1
+    }
+
+    /// Get the source grid's row count.
+    pub fn row_count(&self) -> usize {
+        self.table.row_count() - 1
+    }
+
+    /// Get the sum of every source cell inside `rect`.
+    ///
+    /// The rectangle covers columns `origin.0..origin.0 + col_count` and
+    /// rows `origin.1..origin.1 + row_count`, so an empty rectangle sums
+    /// to zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rect` does not lie entirely within the source grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Grid, Rect};
+    ///
+    /// let walls = Grid::new(20, 20, 1u8);
+    /// let table = walls.integral_image::<u64>();
+    ///
+    /// assert_eq!(100, table.rect_sum(Rect::new((5, 5), 10, 10)));
+    /// assert_eq!(400, table.rect_sum(Rect::new((0, 0), 20, 20)));
+    /// ```
+    pub fn rect_sum(&self, rect: Rect) -> S {
+        assert!(
+            rect.col_end() <= self.col_count() && rect.row_end() <= self.row_count(),
+            "rectangle {:?} is out of bounds for a {}x{} grid",
+            rect,
+            self.col_count(),
+            self.row_count()
+        );
+
+        let (col, row) = rect.origin;
+        let (col_end, row_end) = (rect.col_end(), rect.row_end());
+        let at = |coord| self.table.data[self.table.flat_index(coord)];
+
+        // Subtract within each row first, so unsigned sums never dip below
+        // zero partway through.
+        (at((col_end, row_end)) - at((col, row_end))) - (at((col_end, row)) - at((col, row)))
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Grid Integration
+//////////////////////////////////////////////////////////////////////////////
+
+impl<T> Grid<T>
+where
+    T: Copy,
+{
+    /// Build a summed-area table of the grid, accumulating in `S`.
+    ///
+    /// Building the table takes one pass over the grid, after which
+    /// `IntegralGrid::rect_sum` answers each query in constant time.
+    pub fn integral_image<S>(&self) -> IntegralGrid<S>
+    where
+        S: Copy + Default + From<T> + Add<Output = S> + Sub<Output = S>,
+    {
+        let stride = self.col_count() + 1;
+        let mut table = Grid::new(stride, self.row_count() + 1, S::default());
+
+        for row in 0..self.row_count() {
+            let mut row_sum = S::default();
+
+            for col in 0..self.col_count() {
+                row_sum = row_sum + S::from(self.data[self.flat_index((col, row))]);
+
+                let above = table.data[col + 1 + stride * row];
+                table.data[col + 1 + stride * (row + 1)] = above + row_sum;
+            }
+        }
+
+        IntegralGrid { table }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::labeled;
+    use proptest::prelude::*;
+
+    /// Sum a rectangle one cell at a time.
+    fn brute_sum(grid: &Grid<u8>, rect: Rect) -> u64 {
+        grid.indexed_iter()
+            .filter(|&(coord, _)| rect.contains(coord))
+            .map(|(_, &cell)| u64::from(cell))
+            .sum()
+    }
+
+    #[test]
+    fn test_rect_sum() {
+        let table = labeled(4, 3).integral_image::<usize>();
+
+        assert_eq!((4, 3), (table.col_count(), table.row_count()));
+        assert_eq!(66, table.rect_sum(Rect::new((0, 0), 4, 3)));
+        assert_eq!(6, table.rect_sum(Rect::new((2, 1), 1, 1)));
+        assert_eq!(5 + 6 + 9 + 10, table.rect_sum(Rect::new((1, 1), 2, 2)));
+        assert_eq!(0, table.rect_sum(Rect::new((4, 3), 0, 0)));
+    }
+
+    #[test]
+    fn test_rect_sum_widens() {
+        let table = Grid::new(300, 300, u8::MAX).integral_image::<u64>();
+
+        assert_eq!(300 * 300 * 255, table.rect_sum(Rect::new((0, 0), 300, 300)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rect_sum_out_of_bounds() {
+        labeled(4, 3).integral_image::<usize>().rect_sum(Rect::new((3, 0), 2, 1));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_rect_sum_matches_brute_force(
+            cols in 1usize..12,
+            rows in 1usize..12,
+            cells in prop::collection::vec(any::<u8>(), 144),
+            corners in prop::collection::vec((0usize..13, 0usize..13, 0usize..13, 0usize..13), 16),
+        ) {
+            let mut grid = Grid::new(cols, rows, 0);
+            for (cell, &value) in grid.iter_mut().zip(&cells) {
+                *cell = value;
+            }
+            let table = grid.integral_image::<u64>();
+
+            for (a, b, c, d) in corners {
+                let (col_a, col_b) = (a % (cols + 1), b % (cols + 1));
+                let (row_a, row_b) = (c % (rows + 1), d % (rows + 1));
+                let rect = Rect::new(
+                    (col_a.min(col_b), row_a.min(row_b)),
+                    col_a.abs_diff(col_b),
+                    row_a.abs_diff(row_b),
+                );
+
+                prop_assert_eq!(brute_sum(&grid, rect), table.rect_sum(rect));
+            }
+
+            prop_assert_eq!(u64::from(cells[0]), table.rect_sum(Rect::new((0, 0), 1, 1)));
+            prop_assert_eq!(brute_sum(&grid, grid.bounds()), table.rect_sum(grid.bounds()));
+        }
+    }
+}
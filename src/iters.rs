@@ -0,0 +1,45 @@
+//! Iterators over the cells of a `Grid`.
+
+use core::iter::{FusedIterator, StepBy};
+use core::slice;
+
+//////////////////////////////////////////////////////////////////////////////
+// Columns
+//////////////////////////////////////////////////////////////////////////////
+
+/// An iterator over the cells of one column of a `Grid`, top to bottom.
+#[derive(Clone, Debug)]
+pub struct ColumnIter<'a, T> {
+    cells: StepBy<slice::Iter<'a, T>>,
+}
+
+impl<'a, T> ColumnIter<'a, T> {
+    /// Iterate over column `col` of a row-major buffer `col_count` wide.
+    pub(crate) fn new(data: &'a [T], col: usize, col_count: usize) -> Self {
+        Self {
+            cells: data[col.min(data.len())..].iter().step_by(col_count.max(1)),
+        }
+    }
+}
+
+impl<'a, T> Iterator for ColumnIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        self.cells.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.cells.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for ColumnIter<'a, T> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        self.cells.next_back()
+    }
+}
+
+impl<'a, T> ExactSizeIterator for ColumnIter<'a, T> {}
+
+impl<'a, T> FusedIterator for ColumnIter<'a, T> {}
@@ -54,8 +54,95 @@
 //!
 //! Implementations are provided for scalar multiplication, vector addition,
 //! and vector subtraction.
+//!
+//! # `no_std` Support
+//! Gridd only needs `alloc`. Disabling the default `std` feature builds it
+//! as `#![no_std]`, leaving out the `std::io` binary format, the
+//! `std::error::Error` impls, `HashGrid`, and the `HashMap`-based methods.
+//! Features for crates that need `std` (`csv`, `image`, `pathfinding`,
+//! `petgraph`, `proptest`, and `rayon`) turn it back on.
+
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+use alloc::collections::{BTreeMap, VecDeque};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash::Hash;
+use core::iter::{self, Product, Sum};
+use core::ops::{Add, AddAssign, Bound, Mul, Range, RangeBounds, Sub};
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+#[cfg(feature = "std")]
+use std::error::Error;
 
-use std::ops::{Add, Mul, Sub};
+#[cfg(feature = "approx")]
+mod approx_eq;
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
+mod arith;
+#[cfg(feature = "std")]
+mod binary;
+mod bit_grid;
+#[cfg(feature = "csv")]
+mod csv_io;
+mod drain;
+mod grid_like;
+#[cfg(feature = "std")]
+mod hash_grid;
+#[cfg(feature = "image")]
+mod image_impl;
+mod integral;
+mod iters;
+#[cfg(feature = "nalgebra")]
+mod nalgebra_impl;
+#[cfg(feature = "ndarray")]
+mod ndarray_impl;
+mod offset_grid;
+mod parse;
+#[cfg(feature = "pathfinding")]
+mod pathfinding_impl;
+#[cfg(feature = "petgraph")]
+mod petgraph_impl;
+#[cfg(feature = "bytemuck")]
+mod pod;
+#[cfg(feature = "rand")]
+mod rand_impl;
+#[cfg(feature = "rayon")]
+mod rayon_impl;
+mod render;
+mod rle;
+#[cfg(feature = "serde")]
+mod serde_impl;
+#[cfg(feature = "serde")]
+pub mod serde_rows;
+mod static_grid;
+mod stencil;
+mod transform;
+mod view;
+
+#[cfg(feature = "std")]
+pub use binary::GridElement;
+pub use bit_grid::BitGrid;
+#[cfg(feature = "csv")]
+pub use csv_io::{CsvError, CsvOptions};
+pub use drain::DrainRows;
+pub use grid_like::{GridLike, GridLikeMut};
+#[cfg(feature = "std")]
+pub use hash_grid::HashGrid;
+pub use integral::IntegralGrid;
+pub use iters::ColumnIter;
+pub use offset_grid::{OffsetGrid, SignedCoord};
+pub use parse::ParseError;
+pub use render::RenderOptions;
+pub use rle::RleGrid;
+pub use static_grid::StaticGrid;
+pub use transform::Transform;
+pub use view::{GridView, GridViewMut, MapView, StridedView, TransposedView};
 
 //////////////////////////////////////////////////////////////////////////////
 // Type Aliases
@@ -70,6 +157,7 @@ pub type Coord = (usize, usize);
 
 /// A two-dimensional offset vector used to relate grid elements spatially.
 #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Offset {
     pub col_offset: i32,
     pub row_offset: i32,
@@ -212,20 +300,356 @@ impl Offset {
 }
 
 //////////////////////////////////////////////////////////////////////////////
-// Fixed-Size 2D Grids
+// Rectangles
+//////////////////////////////////////////////////////////////////////////////
+
+/// An axis-aligned rectangle of cells, given by its top-left corner and its
+/// dimensions.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rect {
+    pub origin: Coord,
+    pub col_count: usize,
+    pub row_count: usize,
+}
+
+impl From<(Coord, (usize, usize))> for Rect {
+    fn from((origin, (col_count, row_count)): (Coord, (usize, usize))) -> Self {
+        Self::new(origin, col_count, row_count)
+    }
+}
+
+impl Rect {
+    /// Create a new `Rect` from its top-left corner and dimensions.
+    pub fn new(origin: Coord, col_count: usize, row_count: usize) -> Self {
+        Self {
+            origin,
+            col_count,
+            row_count,
+        }
+    }
+
+    /// Get the column just past the rectangle's right edge.
+    pub fn col_end(&self) -> usize {
+        self.origin.0.saturating_add(self.col_count)
+    }
+
+    /// Get the row just past the rectangle's bottom edge.
+    pub fn row_end(&self) -> usize {
+        self.origin.1.saturating_add(self.row_count)
+    }
+
+    /// Get the number of cells in the rectangle.
+    pub fn area(&self) -> usize {
+        self.col_count * self.row_count
+    }
+
+    /// Determine if the rectangle contains no cells.
+    pub fn is_empty(&self) -> bool {
+        self.col_count == 0 || self.row_count == 0
+    }
+
+    /// Determine if a coordinate is within the rectangle.
+    pub fn contains(&self, (col, row): Coord) -> bool {
+        (self.origin.0..self.col_end()).contains(&col)
+            && (self.origin.1..self.row_end()).contains(&row)
+    }
+
+    /// Determine if another rectangle lies entirely within this one.
+    pub fn contains_rect(&self, other: Rect) -> bool {
+        other.origin.0 >= self.origin.0
+            && other.origin.1 >= self.origin.1
+            && other.col_end() <= self.col_end()
+            && other.row_end() <= self.row_end()
+    }
+
+    /// Get the overlap of two rectangles, which is empty if they are
+    /// disjoint.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Rect;
+    ///
+    /// let a = Rect::new((0, 0), 4, 4);
+    /// let b = Rect::new((2, 3), 5, 5);
+    ///
+    /// assert_eq!(Rect::new((2, 3), 2, 1), a.intersection(b));
+    /// ```
+    pub fn intersection(&self, other: Rect) -> Rect {
+        let col = self.origin.0.max(other.origin.0);
+        let row = self.origin.1.max(other.origin.1);
+        let col_end = self.col_end().min(other.col_end());
+        let row_end = self.row_end().min(other.row_end());
+
+        Rect::new(
+            (col, row),
+            col_end.saturating_sub(col),
+            row_end.saturating_sub(row),
+        )
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Connectivity
+//////////////////////////////////////////////////////////////////////////////
+
+/// Which cells count as adjacent to one another.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Connectivity {
+    /// Cells sharing an edge: north, east, south, and west.
+    Four,
+    /// Cells sharing an edge or a corner.
+    Eight,
+}
+
+impl Connectivity {
+    /// Get the offsets to every adjacent cell, clockwise from north.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Connectivity, Offset};
+    ///
+    /// assert_eq!(4, Connectivity::Four.offsets().len());
+    /// assert_eq!(Offset::from((1, -1)), Connectivity::Eight.offsets()[1]);
+    /// ```
+    pub fn offsets(self) -> &'static [Offset] {
+        const FOUR: [Offset; 4] = [Offset::NORTH, Offset::EAST, Offset::SOUTH, Offset::WEST];
+        const EIGHT: [Offset; 8] = [
+            Offset::NORTH,
+            Offset {
+                col_offset: 1,
+                row_offset: -1,
+            },
+            Offset::EAST,
+            Offset {
+                col_offset: 1,
+                row_offset: 1,
+            },
+            Offset::SOUTH,
+            Offset {
+                col_offset: -1,
+                row_offset: 1,
+            },
+            Offset::WEST,
+            Offset {
+                col_offset: -1,
+                row_offset: -1,
+            },
+        ];
+
+        match self {
+            Connectivity::Four => &FOUR,
+            Connectivity::Eight => &EIGHT,
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Padding Modes
+//////////////////////////////////////////////////////////////////////////////
+
+/// How `Grid::pad` fills the border it adds around a grid.
+///
+/// With a row `a b c`, padding two cells on each side gives:
+///
+/// - `Constant(x)`: `x x | a b c | x x`
+/// - `Edge`: `a a | a b c | c c`
+/// - `Reflect`: `b a | a b c | c b`
+/// - `Wrap`: `b c | a b c | a b`
+///
+/// Corner regions apply the same rule along both axes.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PadMode<T> {
+    /// Fill with a fixed value.
+    Constant(T),
+    /// Repeat the nearest edge cell.
+    Edge,
+    /// Mirror the grid at its edges, repeating the edge cell itself.
+    Reflect,
+    /// Continue from the opposite edge, as if the grid were a torus.
+    Wrap,
+}
+
+impl<T> PadMode<T> {
+    /// Map a possibly out-of-range index along an axis of length `len` to
+    /// the source index it copies, or `None` for the constant fill.
+    fn source_index(&self, index: isize, len: usize) -> Option<usize> {
+        let len = len as isize;
+
+        if (0..len).contains(&index) {
+            return Some(index as usize);
+        }
+
+        let source = match self {
+            PadMode::Constant(_) => return None,
+            PadMode::Edge => index.clamp(0, len - 1),
+            PadMode::Wrap => index.rem_euclid(len),
+            PadMode::Reflect => {
+                let m = index.rem_euclid(2 * len);
+                if m < len {
+                    m
+                } else {
+                    2 * len - 1 - m
+                }
+            }
+        };
+
+        Some(source as usize)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Errors
+//////////////////////////////////////////////////////////////////////////////
+
+/// Errors returned by fallible `Grid` operations.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum GridError {
+    /// A column index was outside the grid.
+    ColOutOfBounds { col: usize, col_count: usize },
+    /// A row index was outside the grid.
+    RowOutOfBounds { row: usize, row_count: usize },
+    /// An index list did not contain every index below `len` exactly once.
+    InvalidPermutation { len: usize },
+    /// An operation requiring a square grid was given a rectangular one.
+    NotSquare { col_count: usize, row_count: usize },
+    /// A grid's (column, row) dimensions were not the ones required.
+    DimensionMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    /// An operation needing at least one cell was given an empty grid.
+    EmptyGrid,
+    /// A row or column had the wrong number of cells.
+    LengthMismatch { expected: usize, found: usize },
+    /// A block's dimensions did not match the others in its block row or
+    /// block column.
+    IncompatibleBlock { block: Coord },
+    /// Two regions that must be disjoint shared the given cells.
+    RegionsOverlap { overlap: Rect },
+    /// A line of parsed text had a different number of cells than the
+    /// first. `line` counts from 1.
+    RaggedLine {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::ColOutOfBounds { col, col_count } => write!(
+                f,
+                "column {} is out of bounds for a grid with {} columns",
+                col, col_count
+            ),
+            GridError::RowOutOfBounds { row, row_count } => write!(
+                f,
+                "row {} is out of bounds for a grid with {} rows",
+                row, row_count
+            ),
+            GridError::InvalidPermutation { len } => write!(
+                f,
+                "expected a permutation of the indices 0..{}",
+                len
+            ),
+            GridError::NotSquare {
+                col_count,
+                row_count,
+            } => write!(
+                f,
+                "expected a square grid, found {} columns and {} rows",
+                col_count, row_count
+            ),
+            GridError::DimensionMismatch { expected, found } => write!(
+                f,
+                "expected a {}x{} grid, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+            GridError::EmptyGrid => write!(f, "the grid contains no cells"),
+            GridError::LengthMismatch { expected, found } => write!(
+                f,
+                "expected {} cells, found {}",
+                expected, found
+            ),
+            GridError::IncompatibleBlock { block } => write!(
+                f,
+                "block ({}, {}) does not line up with its block row or column",
+                block.0, block.1
+            ),
+            GridError::RegionsOverlap { overlap } => write!(
+                f,
+                "the regions overlap in the {}x{} rectangle at ({}, {})",
+                overlap.col_count, overlap.row_count, overlap.origin.0, overlap.origin.1
+            ),
+            GridError::RaggedLine {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {} has {} cells, expected {}",
+                line, found, expected
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for GridError {}
+
+//////////////////////////////////////////////////////////////////////////////
+// 2D Grids
 //////////////////////////////////////////////////////////////////////////////
 
 /// A two-dimensional grid.
+///
+/// Cells are stored contiguously in row-major order, so the cell at
+/// `(col, row)` is at index `row * col_count + col` of `Grid::as_slice`.
+/// This layout is a stable guarantee.
 #[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "serde_impl::RawGrid<T>")
+)]
 pub struct Grid<T> {
     col_count: usize,
     row_count: usize,
     data: Vec<T>,
 }
 
+/// Side length of the square tiles used by cache-blocked traversals.
+const TILE_LEN: usize = 32;
+
+/// Call `f(col, row)` once for every cell of a `cols` by `rows` region,
+/// visiting it tile by tile rather than row by row.
+fn for_each_tiled<F>(cols: usize, rows: usize, mut f: F)
+where
+    F: FnMut(usize, usize),
+{
+    for tile_row in (0..rows).step_by(TILE_LEN) {
+        let row_end = (tile_row + TILE_LEN).min(rows);
+
+        for tile_col in (0..cols).step_by(TILE_LEN) {
+            let col_end = (tile_col + TILE_LEN).min(cols);
+
+            for row in tile_row..row_end {
+                for col in tile_col..col_end {
+                    f(col, row);
+                }
+            }
+        }
+    }
+}
+
 impl<T> Grid<T>
 where
-    T: Copy,
+    T: Clone,
 {
     //////////////////////////////////
     // Instantiation
@@ -237,8 +661,8 @@ where
         let capactiy = row_count * col_count;
 
         Self {
-            col_count: col_count,
-            row_count: row_count,
+            col_count,
+            row_count,
             data: vec![default; capactiy],
         }
     }
@@ -250,208 +674,6066 @@ where
         Self::new(side_len, side_len, default)
     }
 
+    /// Create a new `Grid` populated with a background value, then write
+    /// each listed cell over it.
+    ///
+    /// Later cells at the same coordinate win, and out-of-bounds cells are
+    /// skipped, as with `Grid::extend`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let grid = Grid::from_sparse(3, 2, '.', vec![((1, 0), '#'), ((2, 1), '@')]);
+    ///
+    /// assert_eq!(grid, [['.', '#', '.'], ['.', '.', '@']]);
+    /// ```
+    pub fn from_sparse<I>(col_count: usize, row_count: usize, background: T, cells: I) -> Self
+    where
+        I: IntoIterator<Item = (Coord, T)>,
+    {
+        let mut grid = Self::new(col_count, row_count, background);
+        grid.extend(cells);
+
+        grid
+    }
+
     //////////////////////////////////
     // Other Operations
     //////////////////////////////////
 
     /// Perform a transposition.
+    ///
+    /// Cell (col, row) of this grid becomes cell (row, col) of the result,
+    /// whose column and row counts are swapped.
     pub fn transpose(&self) -> Self {
-        if let Some(&val) = self.get((0, 0)) {
-            let mut new_grid = Self::new(self.row_count, self.col_count, val);
+        // Clone each cell straight into its slot in the new buffer, tile by
+        // tile, rather than filling the buffer first only to overwrite it.
+        let (cols, rows) = (self.col_count, self.row_count);
+        let len = self.data.len();
+        let mut data = Vec::with_capacity(len);
+        let slots = &mut data.spare_capacity_mut()[..len];
 
-            for src_row in 0..self.row_count {
-                for src_col in 0..self.col_count {
-                    if let Some(&val) = self.get((src_col, src_row)) {
-                        new_grid.set((src_row, src_col), val)
-                    }
-                }
-            }
+        for_each_tiled(cols, rows, |col, row| {
+            slots[row + rows * col].write(self.data[col + cols * row].clone());
+        });
 
-            new_grid
-        } else {
-            Self {
-                col_count: 0,
-                row_count: 0,
-                data: Vec::new(),
-            }
+        // SAFETY: the tiles visit every coordinate exactly once, so each of
+        // the first `len` slots was written. If a clone panics, the panic
+        // reaches us before this point and the written cells are leaked.
+        unsafe { data.set_len(len) };
+
+        Self {
+            col_count: self.row_count,
+            row_count: self.col_count,
+            data,
         }
     }
-}
 
-impl<T> Grid<T> {
-    //////////////////////////////////
-    // Utilities
-    //////////////////////////////////
+    /// Overwrite a buffer of this grid's size with its transposition.
+    ///
+    /// Cells are visited tile by tile so that both the reads and the
+    /// strided writes stay within a small, cache-resident working set.
+    fn transpose_cells_into(&self, dest: &mut [T]) {
+        let (cols, rows) = (self.col_count, self.row_count);
 
-    /// Get the flat-vector index from the column and row indices.
-    fn flat_index(&self, (col, row): Coord) -> usize {
-        col + self.col_count * row
+        for_each_tiled(cols, rows, |col, row| {
+            dest[row + rows * col].clone_from(&self.data[col + cols * row]);
+        });
+    }
+
+    /// Write the transposition of this grid into an existing grid without
+    /// reallocating it.
+    ///
+    /// `dest` must have this grid's column and row counts swapped; otherwise
+    /// it is left unmodified and an error is returned.
+    pub fn transpose_into(&self, dest: &mut Self) -> Result<(), GridError> {
+        let expected = (self.row_count, self.col_count);
+        let found = (dest.col_count, dest.row_count);
+
+        if expected != found {
+            return Err(GridError::DimensionMismatch { expected, found });
+        }
+
+        self.transpose_cells_into(&mut dest.data);
+
+        Ok(())
     }
 
     //////////////////////////////////
-    // Get & Set
+    // Flips & Rotations
     //////////////////////////////////
 
-    /// Get a `Grid`'s column count.
-    pub fn col_count(&self) -> usize {
-        self.col_count
-    }
+    /// Mirror the grid across its vertical axis.
+    ///
+    /// Cell (col, row) moves to (col_count - 1 - col, row). See
+    /// `flip_horizontal_in_place` to avoid the allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 2, 0);
+    /// grid.set((0, 1), 1);
+    ///
+    /// assert_eq!(Some(&1), grid.flip_horizontal().get((2, 1)));
+    /// ```
+    pub fn flip_horizontal(&self) -> Self {
+        let mut grid = self.clone();
+        grid.flip_horizontal_in_place();
 
-    /// Get a `Grid`'s row count.
-    pub fn row_count(&self) -> usize {
-        self.row_count
+        grid
     }
 
-    /// Get an immutable reference to some cell.
-    pub fn get(&self, coord: Coord) -> Option<&T>
-    {
-        if self.contains(coord) {
-            let index = self.flat_index(coord);
+    /// Mirror the grid across its horizontal axis.
+    ///
+    /// Cell (col, row) moves to (col, row_count - 1 - row). See
+    /// `flip_vertical_in_place` to avoid the allocation.
+    pub fn flip_vertical(&self) -> Self {
+        let mut grid = self.clone();
+        grid.flip_vertical_in_place();
 
-            Some(&self.data[index])
-        } else {
-            None
-        }
+        grid
     }
 
-    /// Get a mutable reference to some cell.
-    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
-        if self.contains(coord) {
-            let index = self.flat_index(coord);
+    /// Rotate the grid a quarter-turn clockwise.
+    ///
+    /// The column and row counts are swapped, and cell (col, row) moves to
+    /// (row_count - 1 - row, col). The old top-left corner (0, 0) ends up
+    /// as the top-right corner of the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(2, 3, 0);
+    /// grid.set((0, 0), 1);
+    ///
+    /// let rotated = grid.rotate_cw();
+    /// assert_eq!((3, 2), (rotated.col_count(), rotated.row_count()));
+    /// assert_eq!(Some(&1), rotated.get((2, 0)));
+    /// ```
+    pub fn rotate_cw(&self) -> Self {
+        let mut grid = self.transpose();
+        grid.flip_horizontal_in_place();
 
-            Some(&mut self.data[index])
-        } else {
-            None
-        }
+        grid
     }
 
-    /// Get an immutable reference to the cell with the given positional
-    /// relationship to the provided coordinate.
-    pub fn rget(&self, anchor: Coord, vec: Offset) -> Option<&T> {
-        match vec.rcoord(anchor) {
-            Some(coord) => self.get(coord),
-            _ => None,
-        }
-    }
+    /// Rotate the grid a quarter-turn counter-clockwise.
+    ///
+    /// The column and row counts are swapped, and cell (col, row) moves to
+    /// (row, col_count - 1 - col). The old top-left corner (0, 0) ends up
+    /// as the bottom-left corner of the result.
+    pub fn rotate_ccw(&self) -> Self {
+        let mut grid = self.transpose();
+        grid.flip_vertical_in_place();
 
-    /// Get a mutable reference to the cell with the given positional
-    /// relationship to the provided coordinate.
-    pub fn rget_mut(&mut self, anchor: Coord, vec: Offset) -> Option<&mut T> {
-        match vec.rcoord(anchor) {
-            Some(coord) => self.get_mut(coord),
-            _ => None,
-        }
+        grid
     }
 
-    /// Set a cell's value.
-    pub fn set(&mut self, coord: Coord, new_val: T) {
-        match self.get_mut(coord) {
-            Some(val) => {
-                *val = new_val;
-            }
-            None => (),
-        }
-    }
+    /// Rotate the grid a half-turn about its center.
+    ///
+    /// Cell (col, row) moves to (col_count - 1 - col, row_count - 1 - row).
+    /// See `rotate_180_in_place` to avoid the allocation.
+    pub fn rotate_180(&self) -> Self {
+        let mut grid = self.clone();
+        grid.rotate_180_in_place();
 
-    /// Set the value of a cell with the given positional relationship to
-    /// the provided coordinate.
-    pub fn rset(&mut self, coord: Coord, vec: Offset, new_val: T) {
-        if let Some(rcoord) = vec.rcoord(coord) {
-            self.set(rcoord, new_val);
-        }
+        grid
     }
 
     //////////////////////////////////
-    // Boolean Operations
+    // Scaling
     //////////////////////////////////
 
-    /// Determine if a coordinate is within the grid
-    pub fn contains(&self, (col, row): Coord) -> bool {
-        col < self.col_count && row < self.row_count
-    }
-}
+    /// Scale the grid up by integer factors, replacing every cell with a
+    /// `factor_col` by `factor_row` block of copies of itself.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either factor is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(2, 1, 0);
+    /// grid.set((1, 0), 1);
+    ///
+    /// let scaled = grid.upscale(2, 3);
+    /// assert_eq!((4, 3), (scaled.col_count(), scaled.row_count()));
+    /// assert_eq!(Some(&1), scaled.get((2, 2)));
+    /// ```
+    pub fn upscale(&self, factor_col: usize, factor_row: usize) -> Self {
+        assert!(
+            factor_col != 0 && factor_row != 0,
+            "upscale factors must be non-zero"
+        );
 
-//////////////////////////////////////////////////////////////////////////////
-// Unit Tests
-//////////////////////////////////////////////////////////////////////////////
+        let col_count = self.col_count * factor_col;
+        let row_count = self.row_count * factor_row;
+        let mut data = Vec::with_capacity(col_count * row_count);
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+        for row in 0..self.row_count {
+            let start = data.len();
 
-    #[test]
-    fn test_get_mut() {
-        let mut grid = Grid::new(1, 1, 'a');
-        let value = grid.get_mut((0, 0)).unwrap();
+            for cell in &self.data[self.row_range(row)] {
+                data.extend(iter::repeat_n(cell, factor_col).cloned());
+            }
+            for _ in 1..factor_row {
+                data.extend_from_within(start..start + col_count);
+            }
+        }
 
-        assert_eq!(&'a', value);
-        *value = 'b';
-        assert_eq!(&'b', value);
+        Self {
+            col_count,
+            row_count,
+            data,
+        }
     }
 
-    #[test]
-    fn test_get_set() {
-        let mut grid = Grid::new(5, 5, 'a');
+    /// Resize the grid's contents to arbitrary dimensions using
+    /// nearest-neighbor sampling.
+    ///
+    /// Each destination cell copies the source cell under its center: along
+    /// each axis, destination index `d` of `new_len` samples source index
+    /// `floor((d + 0.5) * old_len / new_len)`. Resampling to the same
+    /// dimensions is therefore an exact copy, and a zero target dimension
+    /// produces an empty grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grid is empty but the target dimensions are not, since
+    /// there is nothing to sample.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(2, 2, 0);
+    /// grid.set((1, 1), 1);
+    ///
+    /// let resampled = grid.resample(3, 3);
+    /// assert_eq!(Some(&0), resampled.get((0, 0)));
+    /// assert_eq!(Some(&1), resampled.get((2, 2)));
+    /// ```
+    pub fn resample(&self, new_cols: usize, new_rows: usize) -> Self {
+        let sample = |dest: usize, old_len: usize, new_len: usize| {
+            (2 * dest + 1) * old_len / (2 * new_len)
+        };
 
-        assert_eq!(Some(&'a'), grid.get((2, 3)));
-        assert_eq!(Some(&'a'), grid.get((3, 3)));
-        assert_eq!(Some(&'a'), grid.get((3, 4)));
+        let mut data = Vec::with_capacity(new_cols * new_rows);
 
-        grid.set((2, 3), 'b');
-        grid.set((3, 3), 'c');
-        grid.set((3, 4), 'd');
+        if new_cols != 0 && new_rows != 0 {
+            assert!(
+                !self.data.is_empty(),
+                "cannot resample an empty grid to non-zero dimensions"
+            );
 
-        assert_eq!(Some(&'b'), grid.get((2, 3)));
+            let src_cols: Vec<usize> = (0..new_cols)
+                .map(|col| sample(col, self.col_count, new_cols))
+                .collect();
+
+            for row in 0..new_rows {
+                let src_row = &self.data[self.row_range(sample(row, self.row_count, new_rows))];
+                data.extend(src_cols.iter().map(|&col| src_row[col].clone()));
+            }
+        }
+
+        Self {
+            col_count: new_cols,
+            row_count: new_rows,
+            data,
+        }
+    }
+
+    //////////////////////////////////
+    // Extraction
+    //////////////////////////////////
+
+    /// Copy a rectangular region out into a new grid.
+    ///
+    /// Returns `None` unless the rectangle lies entirely within the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Grid, Rect};
+    ///
+    /// let mut grid = Grid::new(4, 4, 0);
+    /// grid.set((2, 1), 1);
+    ///
+    /// let sub = grid.subgrid(Rect::new((1, 1), 2, 2)).unwrap();
+    /// assert_eq!(Some(&1), sub.get((1, 0)));
+    /// assert_eq!(None, grid.subgrid(((3, 3), (2, 2))));
+    /// ```
+    pub fn subgrid(&self, rect: impl Into<Rect>) -> Option<Self> {
+        let rect = rect.into();
+
+        if self.bounds().contains_rect(rect) {
+            Some(self.copy_rect(rect))
+        } else {
+            None
+        }
+    }
+
+    /// Copy whatever part of a rectangular region overlaps the grid out
+    /// into a new grid.
+    ///
+    /// The result is empty if the rectangle lies entirely outside the grid.
+    pub fn subgrid_clipped(&self, rect: impl Into<Rect>) -> Self {
+        self.copy_rect(self.bounds().intersection(rect.into()))
+    }
+
+    /// Copy the grid out into a list of its rows.
+    ///
+    /// This is `Grid::to_rows` for a borrowed grid.
+    pub fn clone_rows(&self) -> Vec<Vec<T>> {
+        (0..self.row_count)
+            .map(|row| self.data[self.row_range(row)].to_vec())
+            .collect()
+    }
+
+    /// Copy the grid out into a list of its columns.
+    ///
+    /// This is `Grid::to_columns` for a borrowed grid.
+    pub fn clone_columns(&self) -> Vec<Vec<T>> {
+        (0..self.col_count)
+            .map(|col| self.data[col..].iter().step_by(self.col_count).cloned().collect())
+            .collect()
+    }
+
+    /// Create a copy of the smallest region containing every cell that is
+    /// not background, along with the offset of that region's origin.
+    ///
+    /// Adding the returned offset to a coordinate in the trimmed grid gives
+    /// the matching coordinate in the original. If every cell is
+    /// background, the result is a 0x0 grid and a zero offset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Grid, Offset};
+    ///
+    /// let mut grid = Grid::new(4, 3, '.');
+    /// grid.set((2, 1), '#');
+    /// let (trimmed, offset) = grid.trim(|&c| c == '.');
+    ///
+    /// assert_eq!(Grid::new(1, 1, '#'), trimmed);
+    /// assert_eq!(Offset { col_offset: 2, row_offset: 1 }, offset);
+    /// ```
+    pub fn trim<F>(&self, is_background: F) -> (Self, Offset)
+    where
+        F: Fn(&T) -> bool,
+    {
+        match self.bounding_box(|cell| !is_background(cell)) {
+            Some(rect) => {
+                let offset = Offset {
+                    col_offset: rect.origin.0 as i32,
+                    row_offset: rect.origin.1 as i32,
+                };
+
+                (self.copy_rect(rect), offset)
+            }
+            None => (
+                Self {
+                    col_count: 0,
+                    row_count: 0,
+                    data: Vec::new(),
+                },
+                Offset::default(),
+            ),
+        }
+    }
+
+    //////////////////////////////////
+    // Padding
+    //////////////////////////////////
+
+    /// Create a copy of the grid with a border added on each side, filled
+    /// according to a `PadMode`.
+    ///
+    /// Every mode except `PadMode::Constant` copies existing cells into the
+    /// border, so padding an empty grid with one of them is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Grid, PadMode};
+    ///
+    /// let grid = Grid::new(2, 2, 1);
+    /// let padded = grid.pad(1, 1, 0, 2, PadMode::Constant(0)).unwrap();
+    ///
+    /// assert_eq!((4, 4), (padded.col_count(), padded.row_count()));
+    /// assert_eq!(Some(&1), padded.get((1, 0)));
+    /// assert_eq!(Some(&0), padded.get((1, 2)));
+    /// ```
+    pub fn pad(
+        &self,
+        left: usize,
+        right: usize,
+        top: usize,
+        bottom: usize,
+        mode: PadMode<T>,
+    ) -> Result<Self, GridError> {
+        let is_constant = matches!(mode, PadMode::Constant(_));
+
+        if self.data.is_empty() && !is_constant {
+            return Err(GridError::EmptyGrid);
+        }
+
+        let col_count = left + self.col_count + right;
+        let row_count = top + self.row_count + bottom;
+        let src_cols: Vec<Option<usize>> = (0..col_count)
+            .map(|col| mode.source_index(col as isize - left as isize, self.col_count))
+            .collect();
+        let mut data = Vec::with_capacity(col_count * row_count);
+
+        for row in 0..row_count {
+            let src_row = mode.source_index(row as isize - top as isize, self.row_count);
+
+            for &src_col in &src_cols {
+                let cell = match (&mode, src_col, src_row) {
+                    (_, Some(col), Some(row)) => &self.data[self.flat_index((col, row))],
+                    (PadMode::Constant(fill), _, _) => fill,
+                    _ => unreachable!("only constant padding leaves cells unmapped"),
+                };
+                data.push(cell.clone());
+            }
+        }
+
+        Ok(Self {
+            col_count,
+            row_count,
+            data,
+        })
+    }
+
+    /// Copy a rectangle already known to lie within the grid.
+    ///
+    /// Empty rectangles may lie anywhere, since no cells are copied.
+    fn copy_rect(&self, rect: Rect) -> Self {
+        if rect.is_empty() {
+            Self {
+                col_count: rect.col_count,
+                row_count: rect.row_count,
+                data: Vec::new(),
+            }
+        } else {
+            GridView::new(self, rect.origin, rect.col_count, rect.row_count).to_grid()
+        }
+    }
+
+    //////////////////////////////////
+    // Resizing
+    //////////////////////////////////
+
+    /// Change the grid's dimensions in place.
+    ///
+    /// Cells whose coordinates are valid in both the old and new dimensions
+    /// keep their values; newly exposed cells are set to `fill`, and cells
+    /// that fall outside the new dimensions are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(2, 2, 1);
+    /// grid.resize(3, 1, 0);
+    ///
+    /// assert_eq!(Some(&1), grid.get((1, 0)));
+    /// assert_eq!(Some(&0), grid.get((2, 0)));
+    /// assert_eq!(None, grid.get((0, 1)));
+    /// ```
+    pub fn resize(&mut self, new_cols: usize, new_rows: usize, fill: T) {
+        let old_cols = self.col_count;
+
+        if new_rows < self.row_count {
+            self.data.truncate(old_cols * new_rows);
+            self.row_count = new_rows;
+        }
+
+        let kept_rows = self.row_count;
+
+        // Re-stride the surviving rows. Each pass moves cells toward their
+        // destination in an order that never overwrites an unmoved cell; the
+        // slots left behind hold either dropped columns or `fill` clones.
+        if new_cols < old_cols {
+            for row in 1..kept_rows {
+                for col in 0..new_cols {
+                    self.data.swap(new_cols * row + col, old_cols * row + col);
+                }
+            }
+            self.data.truncate(new_cols * kept_rows);
+        } else if new_cols > old_cols {
+            self.data.resize(new_cols * kept_rows, fill.clone());
+            for row in (1..kept_rows).rev() {
+                for col in (0..old_cols).rev() {
+                    self.data.swap(new_cols * row + col, old_cols * row + col);
+                }
+            }
+        }
+
+        self.col_count = new_cols;
+        self.row_count = new_rows;
+        self.data.resize(new_cols * new_rows, fill);
+    }
+
+    /// Grow the grid just enough to contain a coordinate, filling new cells
+    /// with `fill`.
+    ///
+    /// Existing cells keep their coordinates. Whenever the grid grows, room
+    /// is reserved for at least double its previous extent along each axis
+    /// that grew, so repeated growth reallocates only logarithmically often.
+    /// Adding columns still re-lays out every row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col` or `row` is `usize::MAX`, since no grid dimension can
+    /// reach past it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(1, 1, 1);
+    /// grid.ensure_contains((3, 2), 0);
+    /// grid.set((3, 2), 5);
+    ///
+    /// assert_eq!((4, 3), (grid.col_count(), grid.row_count()));
+    /// assert_eq!(Some(&1), grid.get((0, 0)));
+    /// ```
+    pub fn ensure_contains(&mut self, (col, row): Coord, fill: T) {
+        if self.contains((col, row)) {
+            return;
+        }
+
+        let past = |i: usize| i.checked_add(1).expect("grid dimension overflows usize");
+        let new_cols = self.col_count.max(past(col));
+        let new_rows = self.row_count.max(past(row));
+        let grow = |old: usize, new: usize| {
+            if new > old {
+                new.max(old.saturating_mul(2))
+            } else {
+                old
+            }
+        };
+        let wanted = grow(self.col_count, new_cols).saturating_mul(grow(self.row_count, new_rows));
+
+        self.data.reserve(wanted.saturating_sub(self.data.len()));
+        self.resize(new_cols, new_rows, fill);
+    }
+
+    //////////////////////////////////
+    // Joining
+    //////////////////////////////////
+
+    /// Create a grid by stitching together a grid of blocks.
+    ///
+    /// Every block in a block row must have the same row count, and every
+    /// block in a block column the same column count. Otherwise the error
+    /// names the first block, in row-major order, that does not line up.
+    /// A grid of no blocks stitches into an empty grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut blocks = Grid::new(2, 1, Grid::new(1, 2, 0));
+    /// blocks.set((1, 0), Grid::new(3, 2, 1));
+    /// let grid = Grid::from_blocks(&blocks).unwrap();
+    ///
+    /// assert_eq!((4, 2), (grid.col_count(), grid.row_count()));
+    /// assert_eq!(Some(&1), grid.get((1, 1)));
+    /// ```
+    pub fn from_blocks(blocks: &Grid<Grid<T>>) -> Result<Self, GridError> {
+        if blocks.data.is_empty() {
+            return Ok(Self {
+                col_count: 0,
+                row_count: 0,
+                data: Vec::new(),
+            });
+        }
+
+        let widths: Vec<usize> = (0..blocks.col_count)
+            .map(|col| blocks.data[col].col_count)
+            .collect();
+        let heights: Vec<usize> = (0..blocks.row_count)
+            .map(|row| blocks.data[blocks.flat_index((0, row))].row_count)
+            .collect();
+
+        for ((col, row), block) in blocks.indexed_iter() {
+            if block.col_count != widths[col] || block.row_count != heights[row] {
+                return Err(GridError::IncompatibleBlock { block: (col, row) });
+            }
+        }
+
+        let col_count = widths.iter().sum();
+        let row_count = heights.iter().sum();
+        let mut data = Vec::with_capacity(col_count * row_count);
+
+        for (block_row, &height) in heights.iter().enumerate() {
+            let row_blocks = &blocks.data[blocks.row_range(block_row)];
+
+            for row in 0..height {
+                for block in row_blocks {
+                    data.extend_from_slice(&block.data[block.row_range(row)]);
+                }
+            }
+        }
+
+        Ok(Self {
+            col_count,
+            row_count,
+            data,
+        })
+    }
+
+    /// Create a grid with `right` placed to the right of this one.
+    ///
+    /// Both grids must have the same row count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let grid = Grid::new(1, 2, 0).concat_horizontal(&Grid::new(2, 2, 1)).unwrap();
+    ///
+    /// assert_eq!((3, 2), (grid.col_count(), grid.row_count()));
+    /// assert_eq!(Some(&1), grid.get((1, 1)));
+    /// ```
+    pub fn concat_horizontal(&self, right: &Self) -> Result<Self, GridError> {
+        self.check_same_rows(right)?;
+
+        let col_count = self.col_count + right.col_count;
+        let mut data = Vec::with_capacity(col_count * self.row_count);
+
+        for row in 0..self.row_count {
+            data.extend_from_slice(&self.data[self.row_range(row)]);
+            data.extend_from_slice(&right.data[right.row_range(row)]);
+        }
+
+        Ok(Self {
+            col_count,
+            row_count: self.row_count,
+            data,
+        })
+    }
+
+    /// Create a grid with `bottom` placed below this one.
+    ///
+    /// Both grids must have the same column count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let grid = Grid::new(2, 1, 0).concat_vertical(&Grid::new(2, 2, 1)).unwrap();
+    ///
+    /// assert_eq!((2, 3), (grid.col_count(), grid.row_count()));
+    /// assert_eq!(Some(&1), grid.get((0, 1)));
+    /// ```
+    pub fn concat_vertical(&self, bottom: &Self) -> Result<Self, GridError> {
+        self.check_same_cols(bottom)?;
+
+        let mut data = Vec::with_capacity(self.data.len() + bottom.data.len());
+        data.extend_from_slice(&self.data);
+        data.extend_from_slice(&bottom.data);
+
+        Ok(Self {
+            col_count: self.col_count,
+            row_count: self.row_count + bottom.row_count,
+            data,
+        })
+    }
+}
+
+impl<T> Grid<T> {
+    //////////////////////////////////
+    // Utilities
+    //////////////////////////////////
+
+    /// Get the flat-vector index from the column and row indices.
+    fn flat_index(&self, (col, row): Coord) -> usize {
+        col + self.col_count * row
+    }
+
+    /// Get the rectangle covering the whole grid.
+    fn bounds(&self) -> Rect {
+        Rect::new((0, 0), self.col_count, self.row_count)
+    }
+
+    /// Ensure a row given as a vector is as long as the grid is wide.
+    fn check_row_len(&self, len: usize) -> Result<(), GridError> {
+        if len == self.col_count {
+            Ok(())
+        } else {
+            Err(GridError::LengthMismatch {
+                expected: self.col_count,
+                found: len,
+            })
+        }
+    }
+
+    /// Ensure another grid has as many rows as this one.
+    fn check_same_rows<U>(&self, other: &Grid<U>) -> Result<(), GridError> {
+        if other.row_count == self.row_count {
+            Ok(())
+        } else {
+            Err(GridError::DimensionMismatch {
+                expected: (other.col_count, self.row_count),
+                found: (other.col_count, other.row_count),
+            })
+        }
+    }
+
+    /// Ensure another grid has the same dimensions as this one.
+    fn check_same_dims<U>(&self, other: &Grid<U>) -> Result<(), GridError> {
+        if (other.col_count, other.row_count) == (self.col_count, self.row_count) {
+            Ok(())
+        } else {
+            Err(GridError::DimensionMismatch {
+                expected: (self.col_count, self.row_count),
+                found: (other.col_count, other.row_count),
+            })
+        }
+    }
+
+    /// Ensure another grid has as many columns as this one.
+    fn check_same_cols<U>(&self, other: &Grid<U>) -> Result<(), GridError> {
+        if other.col_count == self.col_count {
+            Ok(())
+        } else {
+            Err(GridError::DimensionMismatch {
+                expected: (self.col_count, other.row_count),
+                found: (other.col_count, other.row_count),
+            })
+        }
+    }
+
+    /// Ensure a column given as a vector is as long as the grid is tall.
+    fn check_col_len(&self, len: usize) -> Result<(), GridError> {
+        if len == self.row_count {
+            Ok(())
+        } else {
+            Err(GridError::LengthMismatch {
+                expected: self.row_count,
+                found: len,
+            })
+        }
+    }
+
+    /// Ensure a column index is within the grid.
+    fn check_col(&self, col: usize) -> Result<(), GridError> {
+        if col < self.col_count {
+            Ok(())
+        } else {
+            Err(GridError::ColOutOfBounds {
+                col,
+                col_count: self.col_count,
+            })
+        }
+    }
+
+    /// Ensure a row index is within the grid.
+    fn check_row(&self, row: usize) -> Result<(), GridError> {
+        if row < self.row_count {
+            Ok(())
+        } else {
+            Err(GridError::RowOutOfBounds {
+                row,
+                row_count: self.row_count,
+            })
+        }
+    }
+
+    /// Ensure a rectangle lies entirely within the grid.
+    fn check_rect(&self, rect: Rect) -> Result<(), GridError> {
+        if rect.col_end() > self.col_count {
+            Err(GridError::ColOutOfBounds {
+                col: rect.col_end() - 1,
+                col_count: self.col_count,
+            })
+        } else if rect.row_end() > self.row_count {
+            Err(GridError::RowOutOfBounds {
+                row: rect.row_end() - 1,
+                row_count: self.row_count,
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Normalize a signed shift amount into `0..len`.
+    fn normalize_shift(by: isize, len: usize) -> usize {
+        if len == 0 {
+            0
+        } else {
+            by.rem_euclid(len as isize) as usize
+        }
+    }
+
+    /// Reverse the rows `start..end` of a single column in place.
+    fn reverse_col_segment(&mut self, col: usize, start: usize, end: usize) {
+        let (mut lo, mut hi) = (start, end);
+
+        while lo + 1 < hi {
+            hi -= 1;
+            let a = self.flat_index((col, lo));
+            let b = self.flat_index((col, hi));
+            self.data.swap(a, b);
+            lo += 1;
+        }
+    }
+
+    /// Get the flat-vector index range covered by a row.
+    fn row_range(&self, row: usize) -> Range<usize> {
+        let start = row * self.col_count;
+
+        start..start + self.col_count
+    }
+
+    /// Swap the contents of two distinct rows.
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        let (lo, hi) = if a < b { (a, b) } else { (b, a) };
+        let lo_range = self.row_range(lo);
+        let (head, tail) = self.data.split_at_mut(hi * self.col_count);
+
+        head[lo_range].swap_with_slice(&mut tail[..self.col_count]);
+    }
+
+    /// Swap the contents of two columns.
+    fn swap_cols(&mut self, a: usize, b: usize) {
+        for row in 0..self.row_count {
+            let offset = row * self.col_count;
+            self.data.swap(offset + a, offset + b);
+        }
+    }
+
+    /// Determine if `perm` contains every index below `len` exactly once.
+    fn is_permutation(perm: &[usize], len: usize) -> bool {
+        if perm.len() != len {
+            return false;
+        }
+
+        let mut seen = vec![false; len];
+        perm.iter().all(|&i| i < len && !core::mem::replace(&mut seen[i], true))
+    }
+
+    /// Walk the cycles of a valid permutation of `0..len`, calling `swap` so
+    /// that position `i` ends up holding the item previously at `perm(i)`.
+    fn follow_permutation<P, F>(len: usize, perm: P, mut swap: F)
+    where
+        P: Fn(usize) -> usize,
+        F: FnMut(usize, usize),
+    {
+        let mut placed = vec![false; len];
+
+        for start in 0..len {
+            if placed[start] {
+                continue;
+            }
+            placed[start] = true;
+
+            let mut cur = start;
+            while perm(cur) != start {
+                let next = perm(cur);
+                swap(cur, next);
+                placed[next] = true;
+                cur = next;
+            }
+        }
+    }
+
+    /// Rearrange rows so that row `i` afterwards holds the row previously
+    /// at `perm[i]`. The permutation must already be valid.
+    fn apply_row_permutation(&mut self, perm: &[usize]) {
+        Self::follow_permutation(perm.len(), |i| perm[i], |a, b| self.swap_rows(a, b));
+    }
+
+    /// Rearrange columns so that column `i` afterwards holds the column
+    /// previously at `perm[i]`. The permutation must already be valid.
+    fn apply_col_permutation(&mut self, perm: &[usize]) {
+        Self::follow_permutation(perm.len(), |i| perm[i], |a, b| self.swap_cols(a, b));
+    }
+
+    //////////////////////////////////
+    // Get & Set
+    //////////////////////////////////
+
+    /// Get a `Grid`'s column count.
+    pub fn col_count(&self) -> usize {
+        self.col_count
+    }
+
+    /// Get a `Grid`'s row count.
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// Get an immutable reference to some cell.
+    pub fn get(&self, coord: Coord) -> Option<&T>
+    {
+        if self.contains(coord) {
+            let index = self.flat_index(coord);
+
+            Some(&self.data[index])
+        } else {
+            None
+        }
+    }
+
+    /// Get a mutable reference to some cell.
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        if self.contains(coord) {
+            let index = self.flat_index(coord);
+
+            Some(&mut self.data[index])
+        } else {
+            None
+        }
+    }
+
+    /// Get immutable references to several cells at once.
+    ///
+    /// Each entry of the result lines up with the coordinate at the same
+    /// position, and is `None` where that coordinate is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 3, 0);
+    /// grid.set((2, 2), 1);
+    ///
+    /// let [nw, se, outside] = grid.get_batch([(0, 0), (2, 2), (3, 0)]);
+    /// assert_eq!((Some(&0), Some(&1), None), (nw, se, outside));
+    /// ```
+    pub fn get_batch<const N: usize>(&self, coords: [Coord; N]) -> [Option<&T>; N] {
+        coords.map(|coord| self.get(coord))
+    }
+
+    /// Get immutable references to the cells at each coordinate, in order.
+    ///
+    /// Like `get_batch`, out-of-bounds coordinates yield `None` so the
+    /// output stays aligned with the input.
+    pub fn get_iter<I>(&self, coords: I) -> impl Iterator<Item = Option<&T>> + use<'_, T, I>
+    where
+        I: IntoIterator<Item = Coord>,
+    {
+        coords.into_iter().map(move |coord| self.get(coord))
+    }
+
+    /// Get an immutable reference to the cell with the given positional
+    /// relationship to the provided coordinate.
+    pub fn rget(&self, anchor: Coord, vec: Offset) -> Option<&T> {
+        match vec.rcoord(anchor) {
+            Some(coord) => self.get(coord),
+            _ => None,
+        }
+    }
+
+    /// Get a mutable reference to the cell with the given positional
+    /// relationship to the provided coordinate.
+    pub fn rget_mut(&mut self, anchor: Coord, vec: Offset) -> Option<&mut T> {
+        match vec.rcoord(anchor) {
+            Some(coord) => self.get_mut(coord),
+            _ => None,
+        }
+    }
+
+    /// Set a cell's value.
+    pub fn set(&mut self, coord: Coord, new_val: T) {
+        if let Some(val) = self.get_mut(coord) {
+            *val = new_val;
+        }
+    }
+
+    /// Set the value of a cell with the given positional relationship to
+    /// the provided coordinate.
+    pub fn rset(&mut self, coord: Coord, vec: Offset, new_val: T) {
+        if let Some(rcoord) = vec.rcoord(coord) {
+            self.set(rcoord, new_val);
+        }
+    }
+
+    //////////////////////////////////
+    // Boolean Operations
+    //////////////////////////////////
+
+    /// Determine if a coordinate is within the grid
+    pub fn contains(&self, (col, row): Coord) -> bool {
+        col < self.col_count && row < self.row_count
+    }
+
+    /// Determine if a rectangle of this grid holds the same values as the
+    /// equally-sized rectangle of `other` starting at `other_origin`.
+    ///
+    /// `other` may be this grid itself.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ColOutOfBounds` or `RowOutOfBounds` error if either
+    /// rectangle does not lie entirely within its grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Grid, Rect};
+    ///
+    /// let mut grid = Grid::new(4, 2, 0);
+    /// grid.set((0, 0), 1);
+    /// grid.set((2, 0), 1);
+    ///
+    /// let tile = Rect::new((0, 0), 2, 2);
+    /// assert_eq!(Ok(true), grid.region_eq(tile, &grid, (2, 0)));
+    /// assert!(grid.region_eq(tile, &grid, (3, 0)).is_err());
+    /// ```
+    pub fn region_eq(
+        &self,
+        self_rect: Rect,
+        other: &Grid<T>,
+        other_origin: Coord,
+    ) -> Result<bool, GridError>
+    where
+        T: PartialEq,
+    {
+        let other_rect = Rect::new(other_origin, self_rect.col_count, self_rect.row_count);
+        self.check_rect(self_rect)?;
+        other.check_rect(other_rect)?;
+
+        let mut row_pairs = (self_rect.origin.1..self_rect.row_end()).zip(other_origin.1..);
+
+        Ok(row_pairs.all(|(row, other_row)| {
+            let start = self.flat_index((self_rect.origin.0, row));
+            let other_start = other.flat_index((other_origin.0, other_row));
+
+            self.data[start..start + self_rect.col_count]
+                == other.data[other_start..other_start + self_rect.col_count]
+        }))
+    }
+
+    //////////////////////////////////
+    // Iteration
+    //////////////////////////////////
+
+    /// Iterate over the grid's cells in row-major order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        self.data.iter()
+    }
+
+    /// Iterate mutably over the grid's cells in row-major order.
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator + '_ {
+        self.data.iter_mut()
+    }
+
+    /// Iterate over the grid's cells in row-major order, along with their
+    /// coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let grid = Grid::new(2, 2, 0);
+    /// let coords: Vec<_> = grid.indexed_iter().map(|(coord, _)| coord).collect();
+    ///
+    /// assert_eq!(vec![(0, 0), (1, 0), (0, 1), (1, 1)], coords);
+    /// ```
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Coord, &T)> + '_ {
+        (0..self.row_count).flat_map(move |row| {
+            self.data[self.row_range(row)]
+                .iter()
+                .enumerate()
+                .map(move |(col, cell)| ((col, row), cell))
+        })
+    }
+
+    /// Iterate over every cell sharing a row or column with `coord`, along
+    /// with their coordinates.
+    ///
+    /// Cells are yielded in row-major order, and `coord` itself is yielded
+    /// once, so a grid with `c` columns and `r` rows yields `c + r - 1`
+    /// cells. An out-of-bounds `coord` yields nothing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let grid = Grid::new(3, 3, 0);
+    /// let coords: Vec<_> = grid.cross((1, 1)).map(|(coord, _)| coord).collect();
+    ///
+    /// assert_eq!(vec![(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)], coords);
+    /// ```
+    pub fn cross(&self, (col, row): Coord) -> impl Iterator<Item = (Coord, &T)> + '_ {
+        let rows = if self.contains((col, row)) {
+            self.data.chunks(self.col_count)
+        } else {
+            self.data[..0].chunks(1)
+        };
+
+        rows.enumerate().flat_map(move |(r, cells)| {
+            let cols = if r == row { 0..cells.len() } else { col..col + 1 };
+            let start = cols.start;
+
+            cells[cols]
+                .iter()
+                .enumerate()
+                .map(move |(c, cell)| ((start + c, r), cell))
+        })
+    }
+
+    /// Iterate mutably over every cell sharing a row or column with
+    /// `coord`, along with their coordinates.
+    ///
+    /// Cells are yielded in the same order as `cross`.
+    pub fn cross_mut(&mut self, (col, row): Coord) -> impl Iterator<Item = (Coord, &mut T)> + '_ {
+        let rows = if self.contains((col, row)) {
+            self.data.chunks_mut(self.col_count)
+        } else {
+            self.data[..0].chunks_mut(1)
+        };
+
+        rows.enumerate().flat_map(move |(r, cells)| {
+            let cols = if r == row { 0..cells.len() } else { col..col + 1 };
+            let start = cols.start;
+
+            cells[cols]
+                .iter_mut()
+                .enumerate()
+                .map(move |(c, cell)| ((start + c, r), cell))
+        })
+    }
+
+    //////////////////////////////////
+    // Mapping
+    //////////////////////////////////
+
+    /// Create a grid of the same shape by applying `f` to every cell in
+    /// row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let lengths = Grid::new(2, 2, "abc").map(|cell| cell.len());
+    ///
+    /// assert_eq!(Grid::new(2, 2, 3), lengths);
+    /// ```
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            col_count: self.col_count,
+            row_count: self.row_count,
+            data: self.data.iter().map(f).collect(),
+        }
+    }
+
+    /// Create a grid of the same shape by applying `f` to every cell and its
+    /// coordinates in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let sums = Grid::new(2, 2, 10).map_indexed(|(col, row), &cell| cell + col + row);
+    ///
+    /// assert_eq!(Some(&12), sums.get((1, 1)));
+    /// ```
+    pub fn map_indexed<U, F>(&self, mut f: F) -> Grid<U>
+    where
+        F: FnMut(Coord, &T) -> U,
+    {
+        Grid {
+            col_count: self.col_count,
+            row_count: self.row_count,
+            data: self.indexed_iter().map(|(coord, cell)| f(coord, cell)).collect(),
+        }
+    }
+
+    /// Convert every cell to another type, consuming the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let wide: Grid<u32> = Grid::new(2, 2, 200u8).cast();
+    ///
+    /// assert_eq!(800, wide.sum());
+    /// ```
+    pub fn cast<U>(self) -> Grid<U>
+    where
+        U: From<T>,
+    {
+        Grid {
+            col_count: self.col_count,
+            row_count: self.row_count,
+            data: self.data.into_iter().map(U::from).collect(),
+        }
+    }
+
+    /// Try to convert every cell to another type, consuming the grid.
+    ///
+    /// Conversion stops at the first cell, in row-major order, that fails
+    /// to convert, returning its coordinate along with the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 2, 7i64);
+    /// assert!(grid.clone().try_cast::<u8>().is_ok());
+    ///
+    /// grid.set((1, 1), -1);
+    /// assert_eq!((1, 1), grid.try_cast::<u8>().unwrap_err().0);
+    /// ```
+    pub fn try_cast<U>(self) -> Result<Grid<U>, (Coord, U::Error)>
+    where
+        U: TryFrom<T>,
+    {
+        let col_count = self.col_count;
+        let mut data = Vec::with_capacity(self.data.len());
+
+        for (index, cell) in self.data.into_iter().enumerate() {
+            match U::try_from(cell) {
+                Ok(cell) => data.push(cell),
+                Err(err) => return Err(((index % col_count, index / col_count), err)),
+            }
+        }
+
+        Ok(Grid {
+            col_count,
+            row_count: self.row_count,
+            data,
+        })
+    }
+
+    //////////////////////////////////
+    // Views
+    //////////////////////////////////
+
+    /// Borrow a rectangular region of the grid without copying it.
+    ///
+    /// Returns `None` unless the rectangle lies entirely within the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Grid, Rect};
+    ///
+    /// let mut grid = Grid::new(4, 4, 0);
+    /// grid.set((2, 1), 1);
+    ///
+    /// let view = grid.view(Rect::new((1, 1), 2, 2)).unwrap();
+    /// assert_eq!(Some(&1), view.get((1, 0)));
+    /// assert!(grid.view(((3, 3), (2, 2))).is_none());
+    /// ```
+    pub fn view(&self, rect: impl Into<Rect>) -> Option<GridView<'_, T>> {
+        let rect = rect.into();
+
+        if self.bounds().contains_rect(rect) {
+            Some(GridView::new(self, rect.origin, rect.col_count, rect.row_count))
+        } else {
+            None
+        }
+    }
+
+    /// Borrow the grid as if it were transposed, without copying it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 2, 0);
+    /// grid.set((2, 1), 1);
+    /// let view = grid.transposed();
+    ///
+    /// assert_eq!((2, 3), (view.col_count(), view.row_count()));
+    /// assert_eq!(Some(&1), view.get((1, 2)));
+    /// ```
+    pub fn transposed(&self) -> TransposedView<'_, T> {
+        TransposedView::new(self)
+    }
+
+    /// Borrow the grid as if every cell had been passed through `f`, without
+    /// building a mapped copy.
+    ///
+    /// `f` runs each time a cell is read, and may run more than once for
+    /// the same cell. Use `map` to compute every value exactly once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let grid = Grid::new(2, 2, 3);
+    /// let doubled = grid.map_view(|&cell| cell * 2);
+    ///
+    /// assert_eq!(Some(6), doubled.get((1, 1)));
+    /// ```
+    pub fn map_view<'a, U, F>(&'a self, f: F) -> MapView<'a, T, U, F>
+    where
+        F: Fn(&'a T) -> U,
+    {
+        MapView::new(self, f)
+    }
+
+    /// Borrow every `col_step`th column and `row_step`th row of the grid,
+    /// starting from (0, 0), without copying them.
+    ///
+    /// A step of 1 along both axes views the whole grid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either step is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(5, 4, 0);
+    /// grid.set((4, 2), 1);
+    /// let coarse = grid.strided(2, 2);
+    ///
+    /// assert_eq!((3, 2), (coarse.col_count(), coarse.row_count()));
+    /// assert_eq!(Some(&1), coarse.get((2, 1)));
+    /// ```
+    pub fn strided(&self, col_step: usize, row_step: usize) -> StridedView<'_, T> {
+        self.strided_from((0, 0), col_step, row_step)
+    }
+
+    /// Borrow every `col_step`th column and `row_step`th row of the grid,
+    /// starting from `origin`, without copying them.
+    ///
+    /// If `origin` lies outside the grid, the view is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either step is zero.
+    pub fn strided_from(
+        &self,
+        origin: Coord,
+        col_step: usize,
+        row_step: usize,
+    ) -> StridedView<'_, T> {
+        StridedView::new(self, origin, col_step, row_step)
+    }
+
+    /// Mutably borrow a rectangular region of the grid without copying it.
+    ///
+    /// Returns `None` unless the rectangle lies entirely within the grid.
+    /// Writes through the view cannot reach cells outside the rectangle.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Grid, Rect};
+    ///
+    /// let mut grid = Grid::new(4, 4, 0);
+    /// grid.view_mut(Rect::new((1, 1), 2, 2)).unwrap().fill(1);
+    ///
+    /// assert_eq!(Some(&1), grid.get((2, 2)));
+    /// assert_eq!(Some(&0), grid.get((3, 3)));
+    /// ```
+    pub fn view_mut(&mut self, rect: impl Into<Rect>) -> Option<GridViewMut<'_, T>> {
+        let rect = rect.into();
+
+        if self.bounds().contains_rect(rect) {
+            Some(GridViewMut::new(self, rect.origin, rect.col_count, rect.row_count))
+        } else {
+            None
+        }
+    }
+
+    /// Mutably borrow the rows before `row` and the rows from `row` onward
+    /// as two views that can be used at the same time.
+    ///
+    /// The halves never overlap, so they may be handed to different
+    /// threads. Splitting at 0 or at the row count gives one view with no
+    /// rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` is greater than the row count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(2, 3, 0);
+    /// let (mut top, mut bottom) = grid.split_at_row_mut(1);
+    /// top.fill(1);
+    /// bottom.fill(2);
+    ///
+    /// assert_eq!(vec![1, 1, 2, 2, 2, 2], grid.iter().copied().collect::<Vec<_>>());
+    /// ```
+    pub fn split_at_row_mut(&mut self, row: usize) -> (GridViewMut<'_, T>, GridViewMut<'_, T>) {
+        assert!(
+            row <= self.row_count,
+            "cannot split at row {} of a grid with {} rows",
+            row,
+            self.row_count
+        );
+
+        let (col_count, row_count) = (self.col_count, self.row_count);
+        let (top, bottom) = self.data.split_at_mut(col_count * row);
+
+        (
+            GridViewMut::from_rows(top, col_count, 0, col_count, row),
+            GridViewMut::from_rows(bottom, col_count, 0, col_count, row_count - row),
+        )
+    }
+
+    //////////////////////////////////
+    // Searching
+    //////////////////////////////////
+
+    /// Get the first cell matching `pred`, along with its coordinate.
+    ///
+    /// Cells are scanned in row-major order, so the match nearest the top
+    /// wins, then the leftmost within that row. Scanning stops at the
+    /// first match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 3, 0);
+    /// grid.set((2, 0), 5);
+    /// grid.set((0, 1), 7);
+    ///
+    /// assert_eq!(Some(((0, 1), &7)), grid.find(|_, &c| c > 5));
+    /// assert_eq!(None, grid.find(|(col, row), _| col + row > 4));
+    /// ```
+    pub fn find<F>(&self, mut pred: F) -> Option<(Coord, &T)>
+    where
+        F: FnMut(Coord, &T) -> bool,
+    {
+        self.indexed_iter().find(|&(coord, cell)| pred(coord, cell))
+    }
+
+    /// Get the coordinate of the first cell equal to `value`, scanning in
+    /// row-major order like `find`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut maze = Grid::new(4, 3, '.');
+    /// maze.set((1, 2), 'S');
+    ///
+    /// assert_eq!(Some((1, 2)), maze.find_value(&'S'));
+    /// assert_eq!(None, maze.find_value(&'E'));
+    /// ```
+    pub fn find_value(&self, value: &T) -> Option<Coord>
+    where
+        T: PartialEq,
+    {
+        self.find(|_, cell| cell == value).map(|(coord, _)| coord)
+    }
+
+    /// Lazily iterate over the coordinates of every cell matching `pred`,
+    /// in row-major order.
+    ///
+    /// Only coordinates are yielded, so they can be collected and then
+    /// used to modify the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 2, 0);
+    /// grid.set((1, 0), 4);
+    /// grid.set((2, 1), 9);
+    ///
+    /// let targets: Vec<_> = grid.positions(|_, &c| c > 0).collect();
+    /// for &coord in &targets {
+    ///     grid.set(coord, 0);
+    /// }
+    ///
+    /// assert_eq!(vec![(1, 0), (2, 1)], targets);
+    /// assert_eq!(None, grid.find_value(&4));
+    /// ```
+    pub fn positions<F>(&self, mut pred: F) -> impl Iterator<Item = Coord> + use<'_, T, F>
+    where
+        F: FnMut(Coord, &T) -> bool,
+    {
+        self.indexed_iter()
+            .filter(move |&(coord, cell)| pred(coord, cell))
+            .map(|(coord, _)| coord)
+    }
+
+    /// Lazily iterate over the coordinates of every cell equal to `value`,
+    /// in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut board = Grid::new(3, 3, '.');
+    /// board.set((2, 0), 'O');
+    /// board.set((0, 2), 'O');
+    ///
+    /// assert_eq!(vec![(2, 0), (0, 2)], board.positions_of(&'O').collect::<Vec<_>>());
+    /// ```
+    pub fn positions_of<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Coord> + 'a
+    where
+        T: PartialEq,
+    {
+        self.positions(move |_, cell| cell == value)
+    }
+
+    /// Get the cell with the greatest key, along with its coordinate, or
+    /// `None` if the grid is empty.
+    ///
+    /// Ties go to the first cell in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut heights = Grid::new(3, 3, 0);
+    /// heights.set((1, 2), 7);
+    ///
+    /// assert_eq!(Some(((1, 2), &7)), heights.max_by_key(|_, &h| h));
+    /// assert_eq!(Some(((2, 2), &0)), heights.max_by_key(|(col, row), _| col + row));
+    /// ```
+    pub fn max_by_key<K, F>(&self, key: F) -> Option<(Coord, &T)>
+    where
+        K: Ord,
+        F: FnMut(Coord, &T) -> K,
+    {
+        self.extremum_by_key(key, Ordering::Greater)
+    }
+
+    /// Get the cell with the least key, along with its coordinate, or
+    /// `None` if the grid is empty.
+    ///
+    /// Ties go to the first cell in row-major order.
+    pub fn min_by_key<K, F>(&self, key: F) -> Option<(Coord, &T)>
+    where
+        K: Ord,
+        F: FnMut(Coord, &T) -> K,
+    {
+        self.extremum_by_key(key, Ordering::Less)
+    }
+
+    /// Get the greatest cell, along with its coordinate, or `None` if the
+    /// grid is empty.
+    ///
+    /// Ties go to the first cell in row-major order. This is not named
+    /// `max`, since `Grid` itself implements `Ord`.
+    pub fn max_cell(&self) -> Option<(Coord, &T)>
+    where
+        T: Ord,
+    {
+        self.indexed_iter().fold(None, |best, (coord, cell)| match best {
+            Some((_, best_cell)) if cell <= best_cell => best,
+            _ => Some((coord, cell)),
+        })
+    }
+
+    /// Get the least cell, along with its coordinate, or `None` if the grid
+    /// is empty.
+    ///
+    /// Ties go to the first cell in row-major order.
+    pub fn min_cell(&self) -> Option<(Coord, &T)>
+    where
+        T: Ord,
+    {
+        self.indexed_iter().fold(None, |best, (coord, cell)| match best {
+            Some((_, best_cell)) if cell >= best_cell => best,
+            _ => Some((coord, cell)),
+        })
+    }
+
+    /// Get the first cell whose key compares as `wanted` against every
+    /// earlier key.
+    fn extremum_by_key<K, F>(&self, mut key: F, wanted: Ordering) -> Option<(Coord, &T)>
+    where
+        K: Ord,
+        F: FnMut(Coord, &T) -> K,
+    {
+        let mut best: Option<(K, Coord, &T)> = None;
+
+        for (coord, cell) in self.indexed_iter() {
+            let k = key(coord, cell);
+
+            if best.as_ref().is_none_or(|(best_k, _, _)| k.cmp(best_k) == wanted) {
+                best = Some((k, coord, cell));
+            }
+        }
+
+        best.map(|(_, coord, cell)| (coord, cell))
+    }
+
+    /// Get the top-left coordinate of the first place `pattern` occurs
+    /// within the grid.
+    ///
+    /// Candidate positions are tried in row-major order. A pattern larger
+    /// than the grid along either axis never matches, even if it has no
+    /// cells. Otherwise, a pattern with no cells matches only at `(0, 0)`,
+    /// and not at all within an empty grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 3, '.');
+    /// grid.set((0, 0), 'a');
+    /// grid.set((1, 1), 'a');
+    /// grid.set((1, 2), 'b');
+    ///
+    /// let mut pattern = Grid::new(1, 2, 'a');
+    /// pattern.set((0, 1), 'b');
+    ///
+    /// assert_eq!(Some((1, 1)), grid.find_subgrid(&pattern));
+    /// ```
+    pub fn find_subgrid(&self, pattern: &Grid<T>) -> Option<Coord>
+    where
+        T: PartialEq,
+    {
+        self.find_subgrids(pattern).next()
+    }
+
+    /// Lazily iterate over the top-left coordinates of every place
+    /// `pattern` occurs within the grid, including overlapping
+    /// occurrences, in row-major order.
+    pub fn find_subgrids<'a>(&'a self, pattern: &'a Grid<T>) -> impl Iterator<Item = Coord> + 'a
+    where
+        T: PartialEq,
+    {
+        self.subgrid_matches(pattern, |cell, expected| cell == expected)
+    }
+
+    /// Get the top-left coordinate of the first place `pattern` occurs
+    /// within the grid, where `None` cells of the pattern match anything.
+    ///
+    /// Candidates are tried in the same order as `find_subgrid`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 2, '#');
+    /// grid.set((0, 0), '.');
+    ///
+    /// let mut pattern = Grid::new(2, 2, Some('#'));
+    /// pattern.set((0, 0), None);
+    ///
+    /// assert_eq!(Some((0, 0)), grid.find_subgrid_masked(&pattern));
+    /// assert_eq!(Some((1, 0)), grid.find_subgrid(&Grid::new(2, 2, '#')));
+    /// ```
+    pub fn find_subgrid_masked(&self, pattern: &Grid<Option<T>>) -> Option<Coord>
+    where
+        T: PartialEq,
+    {
+        self.find_subgrids_masked(pattern).next()
+    }
+
+    /// Lazily iterate over the top-left coordinates of every place
+    /// `pattern` occurs within the grid, where `None` cells of the pattern
+    /// match anything.
+    pub fn find_subgrids_masked<'a>(
+        &'a self,
+        pattern: &'a Grid<Option<T>>,
+    ) -> impl Iterator<Item = Coord> + 'a
+    where
+        T: PartialEq,
+    {
+        self.subgrid_matches(pattern, |cell, expected| {
+            expected.as_ref().is_none_or(|expected| cell == expected)
+        })
+    }
+
+    /// Lazily iterate over every position where each cell of `pattern`
+    /// satisfies `eq` against the cell it covers.
+    fn subgrid_matches<'a, U, F>(
+        &'a self,
+        pattern: &'a Grid<U>,
+        eq: F,
+    ) -> impl Iterator<Item = Coord> + 'a
+    where
+        F: Fn(&T, &U) -> bool + 'a,
+    {
+        let fits = pattern.col_count <= self.col_count && pattern.row_count <= self.row_count;
+        let (cols, rows) = if !fits {
+            (0, 0)
+        } else if pattern.data.is_empty() {
+            let origin = usize::from(!self.data.is_empty());
+            (origin, origin)
+        } else {
+            (
+                self.col_count - pattern.col_count + 1,
+                self.row_count - pattern.row_count + 1,
+            )
+        };
+
+        (0..rows)
+            .flat_map(move |row| (0..cols).map(move |col| (col, row)))
+            .filter(move |&(col, row)| {
+                // An empty pattern has nothing to compare. Otherwise, stop at
+                // the first pattern row that fails to line up.
+                pattern.data.is_empty()
+                    || (0..pattern.row_count).all(|r| {
+                        let start = self.flat_index((col, row + r));
+                        let cells = &self.data[start..start + pattern.col_count];
+
+                        cells
+                            .iter()
+                            .zip(&pattern.data[pattern.row_range(r)])
+                            .all(|(cell, expected)| eq(cell, expected))
+                    })
+            })
+    }
+
+    /// Get the first coordinate, in row-major order, where this grid and
+    /// `other` disagree.
+    ///
+    /// Cells present in both grids disagree when `eq` returns false for
+    /// them. If the grids differ in size, coordinates within one grid but
+    /// not the other also disagree. Useful for pinpointing why two grids
+    /// don't compare equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let a = Grid::new(3, 2, 1.0);
+    /// let mut b = a.clone();
+    /// b.set((2, 1), 1.5);
+    ///
+    /// let close = |x: &f64, y: &f64| (x - y).abs() < 0.1;
+    /// assert_eq!(Some((2, 1)), a.first_mismatch(&b, close));
+    /// assert_eq!(Some((0, 2)), a.first_mismatch(&Grid::new(3, 3, 1.0), close));
+    /// ```
+    pub fn first_mismatch<F>(&self, other: &Self, mut eq: F) -> Option<Coord>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let col_count = self.col_count.max(other.col_count);
+        let row_count = self.row_count.max(other.row_count);
+
+        (0..row_count)
+            .flat_map(|row| (0..col_count).map(move |col| (col, row)))
+            .find(|&coord| match (self.get(coord), other.get(coord)) {
+                (Some(a), Some(b)) => !eq(a, b),
+                (None, None) => false,
+                _ => true,
+            })
+    }
+
+    /// Get the smallest rectangle containing every cell matching `pred`, or
+    /// `None` if no cell matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Grid, Rect};
+    ///
+    /// let mut grid = Grid::new(4, 4, 0);
+    /// grid.set((1, 2), 1);
+    /// grid.set((2, 1), 1);
+    ///
+    /// assert_eq!(Some(Rect::new((1, 1), 2, 2)), grid.bounding_box(|&c| c == 1));
+    /// assert_eq!(None, grid.bounding_box(|&c| c == 2));
+    /// ```
+    pub fn bounding_box<F>(&self, pred: F) -> Option<Rect>
+    where
+        F: Fn(&T) -> bool,
+    {
+        let mut min = (usize::MAX, usize::MAX);
+        let mut max = (0, 0);
+
+        for ((col, row), cell) in self.indexed_iter() {
+            if pred(cell) {
+                min = (min.0.min(col), min.1.min(row));
+                max = (max.0.max(col), max.1.max(row));
+            }
+        }
+
+        if min.0 == usize::MAX {
+            None
+        } else {
+            Some(Rect::new(min, max.0 - min.0 + 1, max.1 - min.1 + 1))
+        }
+    }
+
+    //////////////////////////////////
+    // Counting
+    //////////////////////////////////
+
+    /// Count how many cells hold each distinct value.
+    ///
+    /// Each distinct value is cloned once, when first seen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 2, '.');
+    /// grid.set((1, 1), '#');
+    ///
+    /// let counts = grid.value_counts();
+    /// assert_eq!(Some(&5), counts.get(&'.'));
+    /// assert_eq!(Some(&1), counts.get(&'#'));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn value_counts(&self) -> HashMap<T, usize>
+    where
+        T: Clone + Eq + Hash,
+    {
+        let mut counts = HashMap::new();
+
+        for cell in &self.data {
+            match counts.get_mut(cell) {
+                Some(count) => *count += 1,
+                None => {
+                    counts.insert(cell.clone(), 1);
+                }
+            }
+        }
+
+        counts
+    }
+
+    /// Count how many cells hold each distinct value, ordered by value.
+    ///
+    /// Like `value_counts`, but iterates deterministically.
+    pub fn value_counts_ordered(&self) -> BTreeMap<T, usize>
+    where
+        T: Clone + Ord,
+    {
+        let mut counts = BTreeMap::new();
+
+        for cell in &self.data {
+            match counts.get_mut(cell) {
+                Some(count) => *count += 1,
+                None => {
+                    counts.insert(cell.clone(), 1);
+                }
+            }
+        }
+
+        counts
+    }
+
+    /// Get the set of distinct values held by the grid's cells.
+    #[cfg(feature = "std")]
+    pub fn unique_values(&self) -> HashSet<T>
+    where
+        T: Clone + Eq + Hash,
+    {
+        let mut values = HashSet::new();
+
+        for cell in &self.data {
+            if !values.contains(cell) {
+                values.insert(cell.clone());
+            }
+        }
+
+        values
+    }
+
+    //////////////////////////////////
+    // Numeric Reductions
+    //////////////////////////////////
+
+    /// Add up every cell. An empty grid sums to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// assert_eq!(12, Grid::new(3, 2, 2).sum());
+    /// assert_eq!(0.0, Grid::new(0, 2, 1.5).sum());
+    /// ```
+    pub fn sum(&self) -> T
+    where
+        T: for<'a> Sum<&'a T>,
+    {
+        self.data.iter().sum()
+    }
+
+    /// Multiply every cell together. An empty grid's product is one.
+    pub fn product(&self) -> T
+    where
+        T: for<'a> Product<&'a T>,
+    {
+        self.data.iter().product()
+    }
+
+    /// Add up each row, giving one total per row, top to bottom.
+    ///
+    /// Rows of a grid with no columns each sum to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 2, 1);
+    /// grid.set((2, 1), 5);
+    ///
+    /// assert_eq!(vec![3, 7], grid.row_sums());
+    /// assert_eq!(vec![2, 2, 6], grid.col_sums());
+    /// ```
+    pub fn row_sums(&self) -> Vec<T>
+    where
+        T: for<'a> Sum<&'a T>,
+    {
+        (0..self.row_count)
+            .map(|row| self.data[self.row_range(row)].iter().sum())
+            .collect()
+    }
+
+    /// Add up each column, giving one total per column, left to right.
+    ///
+    /// The grid is read in a single row-major pass, adding each row into
+    /// the running totals.
+    pub fn col_sums(&self) -> Vec<T>
+    where
+        T: for<'a> Sum<&'a T> + for<'a> AddAssign<&'a T>,
+    {
+        let mut sums: Vec<T> = iter::repeat_with(|| iter::empty().sum())
+            .take(self.col_count)
+            .collect();
+
+        for row in 0..self.row_count {
+            for (sum, cell) in sums.iter_mut().zip(&self.data[self.row_range(row)]) {
+                *sum += cell;
+            }
+        }
+
+        sums
+    }
+
+    /// Reduce each row to a single value, giving one result per row, top to
+    /// bottom.
+    ///
+    /// Each row starts from a fresh `init()` and folds in its cells left
+    /// to right, passing each cell's coordinate along with it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 2, 0);
+    /// grid.set((1, 0), 4);
+    /// grid.set((2, 1), 9);
+    ///
+    /// let maxima = grid.fold_rows(|| 0, |best, _, &cell| best.max(cell));
+    /// assert_eq!(vec![4, 9], maxima);
+    /// ```
+    pub fn fold_rows<A, I, F>(&self, init: I, mut f: F) -> Vec<A>
+    where
+        I: Fn() -> A,
+        F: FnMut(A, Coord, &T) -> A,
+    {
+        (0..self.row_count)
+            .map(|row| {
+                self.data[self.row_range(row)]
+                    .iter()
+                    .enumerate()
+                    .fold(init(), |acc, (col, cell)| f(acc, (col, row), cell))
+            })
+            .collect()
+    }
+
+    /// Reduce each column to a single value, giving one result per column,
+    /// left to right.
+    ///
+    /// Each column starts from a fresh `init()` and folds in its cells top
+    /// to bottom. The grid is read in a single row-major pass that advances
+    /// every column's accumulator at once.
+    pub fn fold_cols<A, I, F>(&self, init: I, mut f: F) -> Vec<A>
+    where
+        I: Fn() -> A,
+        F: FnMut(A, Coord, &T) -> A,
+    {
+        let mut accs: Vec<A> = iter::repeat_with(init).take(self.col_count).collect();
+
+        for row in 0..self.row_count {
+            accs = accs
+                .into_iter()
+                .zip(&self.data[self.row_range(row)])
+                .enumerate()
+                .map(|(col, (acc, cell))| f(acc, (col, row), cell))
+                .collect();
+        }
+
+        accs
+    }
+
+    /// Get the least cell value, or `None` if the grid is empty.
+    ///
+    /// Values that don't compare equal to themselves, such as NaN, are
+    /// skipped. This is not named `min`, since `Grid` itself implements
+    /// `Ord`; see `min_cell` for the coordinate of the least cell.
+    pub fn min_value(&self) -> Option<T>
+    where
+        T: Copy + PartialOrd,
+    {
+        self.fold_comparable(|cell, best| cell < best)
+    }
+
+    /// Get the greatest cell value, or `None` if the grid is empty.
+    ///
+    /// Values that don't compare equal to themselves, such as NaN, are
+    /// skipped.
+    pub fn max_value(&self) -> Option<T>
+    where
+        T: Copy + PartialOrd,
+    {
+        self.fold_comparable(|cell, best| cell > best)
+    }
+
+    /// Get the mean cell value, or `None` if the grid is empty.
+    ///
+    /// Cells are accumulated as `f64`, so integer grids cannot overflow
+    /// partway through the sum, at the cost of exactness once the running
+    /// total passes 2^53.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(2, 2, i32::MAX);
+    /// grid.set((0, 0), i32::MAX - 4);
+    ///
+    /// assert_eq!(Some(f64::from(i32::MAX) - 1.0), grid.mean());
+    /// assert_eq!(None, Grid::new(0, 0, 0).mean());
+    /// ```
+    pub fn mean(&self) -> Option<f64>
+    where
+        T: Copy + Into<f64>,
+    {
+        if self.data.is_empty() {
+            None
+        } else {
+            let total: f64 = self.data.iter().map(|&cell| cell.into()).sum();
+
+            Some(total / self.data.len() as f64)
+        }
+    }
+
+    /// Get the first comparable cell value for which `better(cell, best)`
+    /// holds against every value before it.
+    fn fold_comparable<F>(&self, better: F) -> Option<T>
+    where
+        T: Copy + PartialOrd,
+        F: Fn(&T, &T) -> bool,
+    {
+        self.data
+            .iter()
+            .filter(|cell| cell.partial_cmp(cell).is_some())
+            .fold(None, |best, cell| match best {
+                Some(ref best_cell) if !better(cell, best_cell) => best,
+                _ => Some(*cell),
+            })
+    }
+
+    //////////////////////////////////
+    // Prefix Sums
+    //////////////////////////////////
+
+    /// Get a grid where each cell holds the running total of its row, from
+    /// the left edge up to and including that cell.
+    ///
+    /// Overflow behaves as it does for `+=` on the cell type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let grid = Grid::new(3, 2, 1);
+    ///
+    /// assert_eq!(vec![1, 2, 3, 1, 2, 3], grid.cumsum_rows().iter().copied().collect::<Vec<_>>());
+    /// assert_eq!(vec![1, 1, 1, 2, 2, 2], grid.cumsum_cols().iter().copied().collect::<Vec<_>>());
+    /// ```
+    pub fn cumsum_rows(&self) -> Self
+    where
+        T: Clone + for<'a> AddAssign<&'a T>,
+    {
+        let mut sums = self.clone();
+        sums.cumsum_rows_in_place();
+
+        sums
+    }
+
+    /// Get a grid where each cell holds the running total of its column,
+    /// from the top edge down to and including that cell.
+    ///
+    /// Overflow behaves as it does for `+=` on the cell type.
+    pub fn cumsum_cols(&self) -> Self
+    where
+        T: Clone + for<'a> AddAssign<&'a T>,
+    {
+        let mut sums = self.clone();
+        sums.cumsum_cols_in_place();
+
+        sums
+    }
+
+    /// Replace each cell with the running total of its row, as
+    /// `cumsum_rows` does, without allocating.
+    pub fn cumsum_rows_in_place(&mut self)
+    where
+        T: for<'a> AddAssign<&'a T>,
+    {
+        for row in 0..self.row_count {
+            let range = self.row_range(row);
+            let cells = &mut self.data[range];
+
+            for col in 1..cells.len() {
+                let (done, rest) = cells.split_at_mut(col);
+                rest[0] += &done[col - 1];
+            }
+        }
+    }
+
+    /// Replace each cell with the running total of its column, as
+    /// `cumsum_cols` does, without allocating.
+    ///
+    /// Each row is added into the one below it, so the grid is read in
+    /// row-major order.
+    pub fn cumsum_cols_in_place(&mut self)
+    where
+        T: for<'a> AddAssign<&'a T>,
+    {
+        let stride = self.col_count;
+
+        for row in 1..self.row_count {
+            let (above, rest) = self.data.split_at_mut(row * stride);
+
+            for (cell, prev) in rest[..stride].iter_mut().zip(&above[above.len() - stride..]) {
+                *cell += prev;
+            }
+        }
+    }
+
+    //////////////////////////////////
+    // Transposition
+    //////////////////////////////////
+
+    /// Perform a transposition, consuming the grid.
+    ///
+    /// Unlike `transpose`, this works for any cell type: cells are moved
+    /// into place within the existing buffer rather than cloned.
+    pub fn into_transpose(mut self) -> Self {
+        let (cols, rows) = (self.col_count, self.row_count);
+
+        if rows != 0 {
+            let data = &mut self.data;
+            Self::follow_permutation(
+                data.len(),
+                |i| i / rows + cols * (i % rows),
+                |a, b| data.swap(a, b),
+            );
+        }
+
+        Self {
+            col_count: rows,
+            row_count: cols,
+            data: self.data,
+        }
+    }
+
+    /// Transpose a square grid in place.
+    ///
+    /// Cells are swapped across the main diagonal, so no second buffer is
+    /// allocated: peak memory stays at one grid rather than the two needed
+    /// by `transpose`. Rectangular grids are rejected unmodified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::square(2, 0);
+    /// grid.set((1, 0), 1);
+    ///
+    /// grid.transpose_in_place().unwrap();
+    /// assert_eq!(Some(&1), grid.get((0, 1)));
+    /// assert!(Grid::new(2, 3, 0).transpose_in_place().is_err());
+    /// ```
+    pub fn transpose_in_place(&mut self) -> Result<(), GridError> {
+        let side = self.col_count;
+
+        if side != self.row_count {
+            return Err(GridError::NotSquare {
+                col_count: self.col_count,
+                row_count: self.row_count,
+            });
+        }
+
+        // Only tiles on or above the diagonal are visited; each swaps with
+        // its mirror image below the diagonal.
+        for tile_row in (0..side).step_by(TILE_LEN) {
+            let row_end = (tile_row + TILE_LEN).min(side);
+
+            for tile_col in (tile_row..side).step_by(TILE_LEN) {
+                let col_end = (tile_col + TILE_LEN).min(side);
+
+                for row in tile_row..row_end {
+                    let col_start = if tile_col == tile_row { row + 1 } else { tile_col };
+
+                    for col in col_start..col_end {
+                        self.data.swap(col + side * row, row + side * col);
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    //////////////////////////////////
+    // In-Place Flips & Rotations
+    //////////////////////////////////
+
+    /// Mirror the grid across its vertical axis without allocating.
+    ///
+    /// Cell (col, row) moves to (col_count - 1 - col, row).
+    pub fn flip_horizontal_in_place(&mut self) {
+        if self.col_count == 0 {
+            return;
+        }
+
+        for row in self.data.chunks_mut(self.col_count) {
+            row.reverse();
+        }
+    }
+
+    /// Mirror the grid across its horizontal axis without allocating.
+    ///
+    /// Cell (col, row) moves to (col, row_count - 1 - row).
+    pub fn flip_vertical_in_place(&mut self) {
+        let rows = self.row_count;
+
+        for row in 0..rows / 2 {
+            self.swap_rows(row, rows - 1 - row);
+        }
+    }
+
+    /// Rotate the grid a half-turn about its center without allocating.
+    ///
+    /// Cell (col, row) moves to (col_count - 1 - col, row_count - 1 - row),
+    /// which in row-major storage is simply a reversal of every cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 2, 0);
+    /// grid.set((0, 0), 1);
+    ///
+    /// grid.rotate_180_in_place();
+    /// assert_eq!(Some(&1), grid.get((2, 1)));
+    /// ```
+    pub fn rotate_180_in_place(&mut self) {
+        self.data.reverse();
+    }
+
+    //////////////////////////////////
+    // Downsampling
+    //////////////////////////////////
+
+    /// Shrink the grid by integer factors, aggregating each `factor_col` by
+    /// `factor_row` block of cells into a single cell of the result.
+    ///
+    /// When the dimensions aren't multiples of the factors, the blocks
+    /// along the right and bottom edges are smaller, and `agg` sees only the
+    /// cells that actually exist. The result therefore has
+    /// `ceil(col_count / factor_col)` columns and
+    /// `ceil(row_count / factor_row)` rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either factor is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(4, 4, 1);
+    /// grid.set((3, 3), 9);
+    ///
+    /// let pooled = grid.downsample(2, 2, |block| *block.iter().max().unwrap());
+    /// assert_eq!(Some(&1), pooled.get((0, 0)));
+    /// assert_eq!(Some(&9), pooled.get((1, 1)));
+    /// ```
+    pub fn downsample<U, F>(&self, factor_col: usize, factor_row: usize, mut agg: F) -> Grid<U>
+    where
+        F: FnMut(GridView<'_, T>) -> U,
+    {
+        assert!(
+            factor_col != 0 && factor_row != 0,
+            "downsample factors must be non-zero"
+        );
+
+        let col_count = self.col_count.div_ceil(factor_col);
+        let row_count = self.row_count.div_ceil(factor_row);
+        let mut data = Vec::with_capacity(col_count * row_count);
+
+        for row in (0..self.row_count).step_by(factor_row) {
+            let block_rows = factor_row.min(self.row_count - row);
+
+            for col in (0..self.col_count).step_by(factor_col) {
+                let block_cols = factor_col.min(self.col_count - col);
+
+                data.push(agg(GridView::new(self, (col, row), block_cols, block_rows)));
+            }
+        }
+
+        Grid {
+            col_count,
+            row_count,
+            data,
+        }
+    }
+
+    //////////////////////////////////
+    // Cyclic Shifts
+    //////////////////////////////////
+
+    /// Cyclically shift a single row with wraparound.
+    ///
+    /// Positive shifts move cells toward higher column indices; negative
+    /// shifts move them toward lower ones. Shift amounts larger than the
+    /// row are reduced modulo the column count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 1, 0);
+    /// grid.set((0, 0), 1);
+    ///
+    /// grid.rotate_row(0, -1).unwrap();
+    /// assert_eq!(Some(&1), grid.get((2, 0)));
+    /// ```
+    pub fn rotate_row(&mut self, row: usize, by: isize) -> Result<(), GridError> {
+        self.check_row(row)?;
+
+        let shift = Self::normalize_shift(by, self.col_count);
+        let start = self.flat_index((0, row));
+        let end = start + self.col_count;
+
+        self.data[start..end].rotate_right(shift);
+
+        Ok(())
+    }
+
+    /// Cyclically shift a single column with wraparound.
+    ///
+    /// Positive shifts move cells toward higher row indices (down);
+    /// negative shifts move them toward lower ones (up). Shift amounts
+    /// larger than the column are reduced modulo the row count.
+    ///
+    /// Since columns are strided through the underlying storage, the
+    /// rotation is performed in place as three reversals, which needs
+    /// neither `Clone` nor a temporary buffer.
+    pub fn rotate_col(&mut self, col: usize, by: isize) -> Result<(), GridError> {
+        self.check_col(col)?;
+
+        let len = self.row_count;
+        let shift = Self::normalize_shift(by, len);
+
+        if shift != 0 {
+            self.reverse_col_segment(col, 0, len);
+            self.reverse_col_segment(col, 0, shift);
+            self.reverse_col_segment(col, shift, len);
+        }
+
+        Ok(())
+    }
+
+    /// Translate the entire grid by an `Offset`, wrapping cells around the
+    /// edges as if the grid were a torus.
+    ///
+    /// A cell at (col, row) moves to
+    /// ((col + col_offset) mod col_count, (row + row_offset) mod row_count).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Grid, Offset};
+    ///
+    /// let mut grid = Grid::new(3, 3, 0);
+    /// grid.set((2, 2), 1);
+    ///
+    /// grid.roll(Offset::EAST + Offset::SOUTH);
+    /// assert_eq!(Some(&1), grid.get((0, 0)));
+    /// ```
+    pub fn roll(&mut self, offset: Offset) {
+        if self.data.is_empty() {
+            return;
+        }
+
+        let col_shift = Self::normalize_shift(offset.col_offset as isize, self.col_count);
+        let row_shift = Self::normalize_shift(offset.row_offset as isize, self.row_count);
+
+        self.data.rotate_right(row_shift * self.col_count);
+
+        if col_shift != 0 {
+            for row in self.data.chunks_mut(self.col_count) {
+                row.rotate_right(col_shift);
+            }
+        }
+    }
+
+    /// Translate the entire grid by an `Offset`, discarding cells pushed
+    /// past the edges and writing `fill` into the vacated cells.
+    ///
+    /// Shifting by at least the grid's size along either axis leaves a grid
+    /// consisting entirely of `fill`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Grid, Offset};
+    ///
+    /// let mut grid = Grid::new(3, 3, 0);
+    /// grid.set((0, 0), 1);
+    ///
+    /// grid.shift(Offset::SOUTH * 2, 0);
+    /// assert_eq!(Some(&0), grid.get((0, 0)));
+    /// assert_eq!(Some(&1), grid.get((0, 2)));
+    /// ```
+    pub fn shift(&mut self, offset: Offset, fill: T)
+    where
+        T: Clone,
+    {
+        let cols = self.col_count;
+        let col_dist = offset.col_offset.unsigned_abs() as usize;
+        let row_dist = offset.row_offset.unsigned_abs() as usize;
+
+        if col_dist >= cols || row_dist >= self.row_count {
+            for cell in self.data.iter_mut() {
+                *cell = fill.clone();
+            }
+            return;
+        }
+
+        // Rotating first means no cell is overwritten before it has moved;
+        // only the wrapped-around cells are then replaced.
+        let vacated = row_dist * cols;
+        let len = self.data.len();
+        let rows = if offset.row_offset > 0 {
+            self.data.rotate_right(vacated);
+            0..vacated
+        } else {
+            self.data.rotate_left(vacated);
+            len - vacated..len
+        };
+        for cell in &mut self.data[rows] {
+            *cell = fill.clone();
+        }
+
+        if col_dist != 0 {
+            for row in self.data.chunks_mut(cols) {
+                let cells = if offset.col_offset > 0 {
+                    row.rotate_right(col_dist);
+                    0..col_dist
+                } else {
+                    row.rotate_left(col_dist);
+                    cols - col_dist..cols
+                };
+                for cell in &mut row[cells] {
+                    *cell = fill.clone();
+                }
+            }
+        }
+    }
+
+    //////////////////////////////////
+    // Reordering
+    //////////////////////////////////
+
+    /// Stably reorder whole rows by comparing their contents.
+    ///
+    /// Rows are moved as units, so cell data is never cloned.
+    pub fn sort_rows_by<F>(&mut self, mut cmp: F)
+    where
+        F: FnMut(&[T], &[T]) -> Ordering,
+    {
+        let mut perm: Vec<usize> = (0..self.row_count).collect();
+        perm.sort_by(|&a, &b| {
+            cmp(&self.data[self.row_range(a)], &self.data[self.row_range(b)])
+        });
+
+        self.apply_row_permutation(&perm);
+    }
+
+    /// Stably reorder whole rows by a key extracted from each row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(2, 2, 0);
+    /// grid.set((0, 0), 5);
+    ///
+    /// grid.sort_rows_by_key(|row| row[0]);
+    /// assert_eq!(Some(&5), grid.get((0, 1)));
+    /// ```
+    pub fn sort_rows_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: Ord,
+        F: FnMut(&[T]) -> K,
+    {
+        let keys: Vec<K> = (0..self.row_count)
+            .map(|row| key(&self.data[self.row_range(row)]))
+            .collect();
+        let mut perm: Vec<usize> = (0..self.row_count).collect();
+        perm.sort_by(|&a, &b| keys[a].cmp(&keys[b]));
+
+        self.apply_row_permutation(&perm);
+    }
+
+    /// Rearrange rows so that row `i` afterwards holds the row previously
+    /// at `perm[i]`.
+    ///
+    /// The grid is left untouched unless `perm` contains every row index
+    /// exactly once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(1, 3, 0);
+    /// grid.set((0, 2), 7);
+    ///
+    /// grid.permute_rows(&[2, 0, 1]).unwrap();
+    /// assert_eq!(Some(&7), grid.get((0, 0)));
+    /// assert!(grid.permute_rows(&[0, 0, 1]).is_err());
+    /// ```
+    pub fn permute_rows(&mut self, perm: &[usize]) -> Result<(), GridError> {
+        if !Self::is_permutation(perm, self.row_count) {
+            return Err(GridError::InvalidPermutation {
+                len: self.row_count,
+            });
+        }
+
+        self.apply_row_permutation(perm);
+
+        Ok(())
+    }
+
+    /// Rearrange columns so that column `i` afterwards holds the column
+    /// previously at `perm[i]`.
+    ///
+    /// The grid is left untouched unless `perm` contains every column index
+    /// exactly once.
+    pub fn permute_cols(&mut self, perm: &[usize]) -> Result<(), GridError> {
+        if !Self::is_permutation(perm, self.col_count) {
+            return Err(GridError::InvalidPermutation {
+                len: self.col_count,
+            });
+        }
+
+        self.apply_col_permutation(perm);
+
+        Ok(())
+    }
+
+    /// Exchange the contents of two equally-sized rectangles of the grid.
+    ///
+    /// # Errors
+    ///
+    /// The grid is left untouched, and an error returned, if either
+    /// rectangle is out of bounds (`ColOutOfBounds` or `RowOutOfBounds`),
+    /// if their dimensions differ (`DimensionMismatch`), or if they share
+    /// any cells (`RegionsOverlap`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Grid, Rect};
+    ///
+    /// let mut grid = Grid::new(4, 2, 0);
+    /// grid.set((0, 0), 1);
+    ///
+    /// grid.swap_regions(Rect::new((0, 0), 2, 2), Rect::new((2, 0), 2, 2)).unwrap();
+    /// assert_eq!(Some(&0), grid.get((0, 0)));
+    /// assert_eq!(Some(&1), grid.get((2, 0)));
+    /// ```
+    pub fn swap_regions(&mut self, a: Rect, b: Rect) -> Result<(), GridError> {
+        self.check_rect(a)?;
+        self.check_rect(b)?;
+
+        if (a.col_count, a.row_count) != (b.col_count, b.row_count) {
+            return Err(GridError::DimensionMismatch {
+                expected: (a.col_count, a.row_count),
+                found: (b.col_count, b.row_count),
+            });
+        }
+
+        let overlap = a.intersection(b);
+        if !overlap.is_empty() {
+            return Err(GridError::RegionsOverlap { overlap });
+        }
+
+        for row in 0..a.row_count {
+            let a_start = self.flat_index((a.origin.0, a.origin.1 + row));
+            let b_start = self.flat_index((b.origin.0, b.origin.1 + row));
+            let (lo, hi) = if a_start < b_start {
+                (a_start, b_start)
+            } else {
+                (b_start, a_start)
+            };
+
+            // The rectangles are disjoint, so the earlier segment ends before
+            // the later one begins.
+            let (head, tail) = self.data.split_at_mut(hi);
+            head[lo..lo + a.col_count].swap_with_slice(&mut tail[..a.col_count]);
+        }
+
+        Ok(())
+    }
+
+    //////////////////////////////////
+    // Capacity
+    //////////////////////////////////
+
+    /// Get how many rows fit in the grid's current allocation.
+    ///
+    /// A grid with no columns can hold any number of rows, so this returns
+    /// `usize::MAX` for it.
+    pub fn capacity_rows(&self) -> usize {
+        self.data
+            .capacity()
+            .checked_div(self.col_count)
+            .unwrap_or(usize::MAX)
+    }
+
+    /// Reserve room for at least `additional` more rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the required number of cells overflows `usize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 1, 0);
+    /// grid.reserve_rows(10);
+    ///
+    /// assert!(grid.capacity_rows() >= 11);
+    /// ```
+    pub fn reserve_rows(&mut self, additional: usize) {
+        let cells = additional
+            .checked_mul(self.col_count)
+            .expect("capacity overflow");
+
+        self.data.reserve(cells);
+    }
+
+    /// Shrink the grid's allocation as close to its size as possible.
+    pub fn shrink_to_fit(&mut self) {
+        self.data.shrink_to_fit();
+    }
+
+    //////////////////////////////////
+    // Adding & Removing Rows
+    //////////////////////////////////
+
+    /// Append a row to the bottom of the grid.
+    ///
+    /// The row's length must equal the column count, except when the grid
+    /// is 0x0, in which case the row establishes the column count.
+    ///
+    /// Like `Vec::push`, this grows the buffer geometrically, so pushing `n`
+    /// rows takes amortized O(n * col_count) time. Pushes never reallocate
+    /// while `row_count() < capacity_rows()`; use `reserve_rows` to size the
+    /// buffer up front when the final height is known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(0, 0, 0);
+    /// grid.push_row(vec![1, 2, 3]).unwrap();
+    /// grid.push_row(vec![4, 5, 6]).unwrap();
+    ///
+    /// assert_eq!((3, 2), (grid.col_count(), grid.row_count()));
+    /// assert!(grid.push_row(vec![7]).is_err());
+    /// ```
+    pub fn push_row(&mut self, row: Vec<T>) -> Result<(), GridError> {
+        self.insert_row(self.row_count, row)
+    }
+
+    /// Insert a row so that it becomes row `index`, moving later rows down.
+    ///
+    /// An `index` equal to the row count appends the row. The row's length
+    /// must equal the column count, except when the grid is 0x0, in which
+    /// case the row establishes the column count. On error the grid is left
+    /// unmodified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(2, 2, 0);
+    /// grid.insert_row(1, vec![1, 1]).unwrap();
+    ///
+    /// assert_eq!(Some(&1), grid.get((0, 1)));
+    /// assert_eq!(Some(&0), grid.get((0, 2)));
+    /// ```
+    pub fn insert_row(&mut self, index: usize, row: Vec<T>) -> Result<(), GridError> {
+        if index > self.row_count {
+            return Err(GridError::RowOutOfBounds {
+                row: index,
+                row_count: self.row_count,
+            });
+        }
+
+        if self.col_count == 0 && self.row_count == 0 {
+            self.col_count = row.len();
+        }
+        self.check_row_len(row.len())?;
+
+        let start = self.col_count * index;
+        self.data.splice(start..start, row);
+        self.row_count += 1;
+
+        Ok(())
+    }
+
+    /// Remove the bottom row of the grid and return it.
+    ///
+    /// The column count is kept even when the last row is removed, so rows
+    /// pushed afterwards must still match it.
+    pub fn pop_row(&mut self) -> Option<Vec<T>> {
+        if self.row_count == 0 {
+            return None;
+        }
+
+        let start = self.data.len() - self.col_count;
+        self.row_count -= 1;
+
+        Some(self.data.split_off(start))
+    }
+
+    /// Remove row `index`, moving later rows up, and return its cells.
+    ///
+    /// Returns `None` and leaves the grid unmodified if the row is out of
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(2, 3, 0);
+    /// grid.set((0, 1), 1);
+    ///
+    /// assert_eq!(Some(vec![1, 0]), grid.remove_row(1));
+    /// assert_eq!(2, grid.row_count());
+    /// assert_eq!(None, grid.remove_row(2));
+    /// ```
+    pub fn remove_row(&mut self, index: usize) -> Option<Vec<T>> {
+        if index >= self.row_count {
+            return None;
+        }
+
+        let range = self.row_range(index);
+        self.row_count -= 1;
+
+        Some(self.data.drain(range).collect())
+    }
+
+    /// Remove row `index` and return its cells, moving the bottom row into
+    /// its place.
+    ///
+    /// This does not preserve row order, but takes time proportional to the
+    /// column count wherever the removed row is. Returns `None` and leaves
+    /// the grid unmodified if the row is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(1, 3, 0);
+    /// grid.set((0, 2), 2);
+    ///
+    /// assert_eq!(Some(vec![0]), grid.swap_remove_row(0));
+    /// assert_eq!(Some(&2), grid.get((0, 0)));
+    /// ```
+    pub fn swap_remove_row(&mut self, index: usize) -> Option<Vec<T>> {
+        if index >= self.row_count {
+            return None;
+        }
+
+        let last = self.row_count - 1;
+        if index != last {
+            self.swap_rows(index, last);
+        }
+
+        self.pop_row()
+    }
+
+    /// Remove a range of rows, yielding each removed row as a `Vec`.
+    ///
+    /// As with `Vec::drain`, the whole range is removed when the returned
+    /// iterator is dropped, whether or not it was fully consumed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range starts after it ends or ends past the last row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(2, 4, 0);
+    /// grid.set((0, 1), 1);
+    /// let drained: Vec<_> = grid.drain_rows(1..3).collect();
+    ///
+    /// assert_eq!(vec![vec![1, 0], vec![0, 0]], drained);
+    /// assert_eq!(2, grid.row_count());
+    /// ```
+    pub fn drain_rows<R>(&mut self, range: R) -> DrainRows<'_, T>
+    where
+        R: RangeBounds<usize>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&row) => row,
+            Bound::Excluded(&row) => row + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&row) => row + 1,
+            Bound::Excluded(&row) => row,
+            Bound::Unbounded => self.row_count,
+        };
+
+        assert!(start <= end, "row range starts at {} but ends at {}", start, end);
+        assert!(
+            end <= self.row_count,
+            "row range ends at {} but the grid has {} rows",
+            end,
+            self.row_count
+        );
+
+        let cells = self.data.drain(self.col_count * start..self.col_count * end);
+
+        DrainRows::new(cells, self.col_count, &mut self.row_count, start, end)
+    }
+
+    //////////////////////////////////
+    // Adding & Removing Columns
+    //////////////////////////////////
+
+    /// Append a column to the right side of the grid.
+    ///
+    /// The column's length must equal the row count, except when the grid
+    /// is 0x0, in which case the column establishes the row count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(1, 2, 0);
+    /// grid.push_col(vec![1, 2]).unwrap();
+    ///
+    /// assert_eq!(Some(&1), grid.get((1, 0)));
+    /// assert_eq!(Some(&2), grid.get((1, 1)));
+    /// ```
+    pub fn push_col(&mut self, col: Vec<T>) -> Result<(), GridError> {
+        self.insert_col(self.col_count, col)
+    }
+
+    /// Insert a column so that it becomes column `index`, moving later
+    /// columns right.
+    ///
+    /// An `index` equal to the column count appends the column. The
+    /// column's length must equal the row count, except when the grid is
+    /// 0x0, in which case the column establishes the row count. On error the
+    /// grid is left unmodified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(2, 2, 0);
+    /// grid.insert_col(0, vec![1, 2]).unwrap();
+    ///
+    /// assert_eq!(Some(&2), grid.get((0, 1)));
+    /// assert_eq!(Some(&0), grid.get((2, 1)));
+    /// ```
+    pub fn insert_col(&mut self, index: usize, col: Vec<T>) -> Result<(), GridError> {
+        if index > self.col_count {
+            return Err(GridError::ColOutOfBounds {
+                col: index,
+                col_count: self.col_count,
+            });
+        }
+
+        if self.col_count == 0 && self.row_count == 0 {
+            self.row_count = col.len();
+        }
+        self.check_col_len(col.len())?;
+
+        let tail = self.col_count - index;
+        let mut old = core::mem::take(&mut self.data).into_iter();
+        self.data.reserve_exact(old.len() + col.len());
+
+        for cell in col {
+            self.data.extend(old.by_ref().take(index));
+            self.data.push(cell);
+            self.data.extend(old.by_ref().take(tail));
+        }
+        self.col_count += 1;
+
+        Ok(())
+    }
+
+    /// Remove the rightmost column of the grid and return it.
+    ///
+    /// The row count is kept even when the last column is removed.
+    pub fn pop_col(&mut self) -> Option<Vec<T>> {
+        self.remove_col(self.col_count.checked_sub(1)?)
+    }
+
+    /// Remove column `index`, moving later columns left, and return its
+    /// cells from top to bottom.
+    ///
+    /// Returns `None` and leaves the grid unmodified if the column is out of
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 2, 0);
+    /// grid.set((1, 1), 1);
+    ///
+    /// assert_eq!(Some(vec![0, 1]), grid.remove_col(1));
+    /// assert_eq!(2, grid.col_count());
+    /// assert_eq!(None, grid.remove_col(2));
+    /// ```
+    pub fn remove_col(&mut self, index: usize) -> Option<Vec<T>> {
+        if index >= self.col_count {
+            return None;
+        }
+
+        // Compact the kept cells forward in one pass. The removed cells
+        // collect in the gap between the write and read indices, and each
+        // swap moves the gap's front cell to its back, so `gap` tracks
+        // which row every gap cell came from, front to back.
+        let width = self.col_count;
+        let mut gap = VecDeque::with_capacity(self.row_count);
+        let mut write = 0;
+        for read in 0..self.data.len() {
+            if read % width == index {
+                gap.push_back(read / width);
+            } else {
+                self.data.swap(write, read);
+                gap.rotate_left(usize::from(!gap.is_empty()));
+                write += 1;
+            }
+        }
+
+        // The gap now fills the tail; put its cells back in row order.
+        let mut col: Vec<_> = gap.into_iter().zip(self.data.drain(write..)).collect();
+        col.sort_unstable_by_key(|&(row, _)| row);
+        self.col_count -= 1;
+
+        Some(col.into_iter().map(|(_, cell)| cell).collect())
+    }
+
+    //////////////////////////////////
+    // Retaining Rows & Columns
+    //////////////////////////////////
+
+    /// Keep only the rows for which `keep` returns `true`, preserving their
+    /// order.
+    ///
+    /// `keep` is called once per row, in order, with the row's original
+    /// index and contents before any rows are removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(2, 3, 0);
+    /// grid.set((1, 1), 1);
+    /// grid.retain_rows(|_, row| row.iter().any(|&c| c != 0));
+    ///
+    /// assert_eq!((2, 1), (grid.col_count(), grid.row_count()));
+    /// ```
+    pub fn retain_rows<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(usize, &[T]) -> bool,
+    {
+        let kept: Vec<bool> = (0..self.row_count)
+            .map(|row| keep(row, &self.data[self.row_range(row)]))
+            .collect();
+        let width = self.col_count;
+        let mut i = 0;
+
+        self.data.retain(|_| {
+            let retained = kept[i / width];
+            i += 1;
+            retained
+        });
+        self.row_count = kept.iter().filter(|&&k| k).count();
+    }
+
+    /// Keep only the columns for which `keep` returns `true`, preserving
+    /// their order.
+    ///
+    /// `keep` is called once per column, in order, with the column's
+    /// original index and an iterator over its cells before any columns are
+    /// removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 2, 0);
+    /// grid.set((1, 1), 1);
+    /// grid.retain_cols(|_, mut col| col.any(|&c| c != 0));
+    ///
+    /// assert_eq!((1, 2), (grid.col_count(), grid.row_count()));
+    /// ```
+    pub fn retain_cols<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(usize, ColumnIter<'_, T>) -> bool,
+    {
+        let kept: Vec<bool> = (0..self.col_count)
+            .map(|col| keep(col, ColumnIter::new(&self.data, col, self.col_count)))
+            .collect();
+        let width = self.col_count;
+        let mut i = 0;
+
+        self.data.retain(|_| {
+            let retained = kept[i % width];
+            i += 1;
+            retained
+        });
+        self.col_count = kept.iter().filter(|&&k| k).count();
+    }
+
+    //////////////////////////////////
+    // Splitting & Joining
+    //////////////////////////////////
+
+    /// Split the grid into the rows before `row` and the rows from `row`
+    /// onward.
+    ///
+    /// Both halves keep the original column count, so splitting at 0 or at
+    /// the row count gives one grid with no rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` is greater than the row count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let (top, bottom) = Grid::new(2, 5, 0).split_at_row(2);
+    ///
+    /// assert_eq!((2, 2), (top.col_count(), top.row_count()));
+    /// assert_eq!((2, 3), (bottom.col_count(), bottom.row_count()));
+    /// ```
+    pub fn split_at_row(mut self, row: usize) -> (Self, Self) {
+        assert!(
+            row <= self.row_count,
+            "cannot split at row {} of a grid with {} rows",
+            row,
+            self.row_count
+        );
+
+        let bottom = Self {
+            col_count: self.col_count,
+            row_count: self.row_count - row,
+            data: self.data.split_off(self.col_count * row),
+        };
+        self.row_count = row;
+
+        (self, bottom)
+    }
+
+    /// Split the grid into the columns before `col` and the columns from
+    /// `col` onward.
+    ///
+    /// Both halves keep the original row count, so splitting at 0 or at the
+    /// column count gives one grid with no columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `col` is greater than the column count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let (left, right) = Grid::new(5, 2, 0).split_at_col(2);
+    ///
+    /// assert_eq!((2, 2), (left.col_count(), left.row_count()));
+    /// assert_eq!((3, 2), (right.col_count(), right.row_count()));
+    /// ```
+    pub fn split_at_col(self, col: usize) -> (Self, Self) {
+        assert!(
+            col <= self.col_count,
+            "cannot split at column {} of a grid with {} columns",
+            col,
+            self.col_count
+        );
+
+        let right_cols = self.col_count - col;
+        let mut left = Vec::with_capacity(col * self.row_count);
+        let mut right = Vec::with_capacity(right_cols * self.row_count);
+        let mut cells = self.data.into_iter();
+
+        for _ in 0..self.row_count {
+            left.extend(cells.by_ref().take(col));
+            right.extend(cells.by_ref().take(right_cols));
+        }
+
+        (
+            Self {
+                col_count: col,
+                row_count: self.row_count,
+                data: left,
+            },
+            Self {
+                col_count: right_cols,
+                row_count: self.row_count,
+                data: right,
+            },
+        )
+    }
+
+    /// Split the grid at its center into `[nw, ne, sw, se]` quadrants.
+    ///
+    /// When a dimension is odd, the extra row goes to the southern quadrants
+    /// and the extra column to the eastern ones.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let [nw, _, _, se] = Grid::new(3, 5, 0).split_into_quadrants();
+    ///
+    /// assert_eq!((1, 2), (nw.col_count(), nw.row_count()));
+    /// assert_eq!((2, 3), (se.col_count(), se.row_count()));
+    /// ```
+    pub fn split_into_quadrants(self) -> [Self; 4] {
+        let (mid_col, mid_row) = (self.col_count / 2, self.row_count / 2);
+        let (north, south) = self.split_at_row(mid_row);
+        let (nw, ne) = north.split_at_col(mid_col);
+        let (sw, se) = south.split_at_col(mid_col);
+
+        [nw, ne, sw, se]
+    }
+
+    /// Place `right` to the right of this grid, consuming both.
+    ///
+    /// Both grids must have the same row count. Unlike `concat_horizontal`,
+    /// cells are moved rather than cloned.
+    pub fn into_concat_horizontal(self, right: Self) -> Result<Self, GridError> {
+        self.check_same_rows(&right)?;
+
+        let col_count = self.col_count + right.col_count;
+        let mut data = Vec::with_capacity(col_count * self.row_count);
+        let mut left_cells = self.data.into_iter();
+        let mut right_cells = right.data.into_iter();
+
+        for _ in 0..self.row_count {
+            data.extend(left_cells.by_ref().take(self.col_count));
+            data.extend(right_cells.by_ref().take(right.col_count));
+        }
+
+        Ok(Self {
+            col_count,
+            row_count: self.row_count,
+            data,
+        })
+    }
+
+    /// Place `bottom` below this grid, consuming both.
+    ///
+    /// Both grids must have the same column count. This appends `bottom`'s
+    /// buffer to this grid's, without touching individual cells.
+    pub fn into_concat_vertical(mut self, mut bottom: Self) -> Result<Self, GridError> {
+        self.check_same_cols(&bottom)?;
+
+        self.data.append(&mut bottom.data);
+        self.row_count += bottom.row_count;
+
+        Ok(self)
+    }
+
+    //////////////////////////////////
+    // Nested Vecs
+    //////////////////////////////////
+
+    /// Create a grid from a list of rows, moving the cells.
+    ///
+    /// An empty list gives an empty grid.
+    ///
+    /// # Errors
+    ///
+    /// Fails with `GridError::LengthMismatch` if a row's length differs
+    /// from the first row's.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6]]).unwrap();
+    ///
+    /// assert_eq!((3, 2), (grid.col_count(), grid.row_count()));
+    /// assert_eq!(Some(&4), grid.get((0, 1)));
+    /// assert!(Grid::from_rows(vec![vec![1, 2], vec![3]]).is_err());
+    /// ```
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, GridError> {
+        let col_count = rows.first().map_or(0, Vec::len);
+        let row_count = rows.len();
+        let mut data = Vec::with_capacity(col_count * row_count);
+
+        for row in rows {
+            if row.len() != col_count {
+                return Err(GridError::LengthMismatch {
+                    expected: col_count,
+                    found: row.len(),
+                });
+            }
+
+            data.extend(row);
+        }
+
+        Ok(Self {
+            col_count,
+            row_count,
+            data,
+        })
+    }
+
+    /// Convert the grid into a list of its rows, moving the cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(2, 2, 0);
+    /// grid.set((1, 0), 1);
+    ///
+    /// assert_eq!(vec![vec![0, 1], vec![0, 0]], grid.to_rows());
+    /// ```
+    pub fn to_rows(self) -> Vec<Vec<T>> {
+        let col_count = self.col_count;
+        let mut cells = self.data.into_iter();
+
+        (0..self.row_count)
+            .map(|_| cells.by_ref().take(col_count).collect())
+            .collect()
+    }
+
+    /// Convert the grid into a list of its columns, moving the cells.
+    ///
+    /// A grid with no columns gives an empty list, whatever its row count.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(2, 2, 0);
+    /// grid.set((1, 0), 1);
+    ///
+    /// assert_eq!(vec![vec![0, 0], vec![1, 0]], grid.to_columns());
+    /// ```
+    pub fn to_columns(self) -> Vec<Vec<T>> {
+        let mut columns: Vec<Vec<T>> = (0..self.col_count)
+            .map(|_| Vec::with_capacity(self.row_count))
+            .collect();
+
+        for (i, cell) in self.data.into_iter().enumerate() {
+            columns[i % self.col_count].push(cell);
+        }
+
+        columns
+    }
+
+    //////////////////////////////////
+    // Flat Storage
+    //////////////////////////////////
+
+    /// Create a grid from its cells in row-major order.
+    ///
+    /// # Errors
+    ///
+    /// Fails with `GridError::LengthMismatch` unless `data` holds exactly
+    /// `col_count * row_count` cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let grid = Grid::from_vec(3, 2, vec![0, 1, 2, 3, 4, 5]).unwrap();
+    ///
+    /// assert_eq!(Some(&5), grid.get((2, 1)));
+    /// assert!(Grid::from_vec(3, 2, vec![0; 5]).is_err());
+    /// ```
+    pub fn from_vec(col_count: usize, row_count: usize, data: Vec<T>) -> Result<Self, GridError> {
+        let expected = col_count.checked_mul(row_count);
+
+        if expected != Some(data.len()) {
+            return Err(GridError::LengthMismatch {
+                expected: expected.unwrap_or(usize::MAX),
+                found: data.len(),
+            });
+        }
+
+        Ok(Self {
+            col_count,
+            row_count,
+            data,
+        })
+    }
+
+    /// Borrow the cells in row-major order.
+    ///
+    /// The cell at `(col, row)` is at index `row * col_count + col`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 2, 0);
+    /// grid.set((1, 1), 7);
+    ///
+    /// assert_eq!(&[0, 0, 0, 0, 7, 0], grid.as_slice());
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Mutably borrow the cells in row-major order.
+    ///
+    /// The cell at `(col, row)` is at index `row * col_count + col`. The
+    /// slice cannot change the grid's length, so any reordering of it, such
+    /// as a sort, leaves a valid grid.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Convert the grid into its cells in row-major order.
+    ///
+    /// This is the inverse of `Grid::from_vec`.
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    //////////////////////////////////
+    // Sparse Cells
+    //////////////////////////////////
+
+    /// Lazily iterate over the cells that are not background, along with
+    /// their coordinates, in row-major order.
+    ///
+    /// This is the inverse of `Grid::from_sparse` when every background
+    /// cell holds the value passed there.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let grid = Grid::from_sparse(3, 3, 0, vec![((2, 0), 5), ((0, 2), 7)]);
+    /// let cells: Vec<_> = grid.to_sparse(|&cell| cell == 0).collect();
+    ///
+    /// assert_eq!(vec![((2, 0), &5), ((0, 2), &7)], cells);
+    /// ```
+    pub fn to_sparse<F>(
+        &self,
+        is_background: F,
+    ) -> impl Iterator<Item = (Coord, &T)> + use<'_, T, F>
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.indexed_iter().filter(move |(_, cell)| !is_background(cell))
+    }
+
+    /// Lazily iterate over the cells that differ from `background`, along
+    /// with their coordinates, in row-major order.
+    pub fn to_sparse_except<'a>(
+        &'a self,
+        background: &'a T,
+    ) -> impl Iterator<Item = (Coord, &'a T)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.to_sparse(move |cell| cell == background)
+    }
+}
+
+impl<T> From<Grid<T>> for Vec<Vec<T>> {
+    fn from(grid: Grid<T>) -> Self {
+        grid.to_rows()
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Comparing with Nested Rows
+//////////////////////////////////////////////////////////////////////////////
+
+impl<T> Grid<T> {
+    /// Compare the grid with a list of rows, shape first.
+    ///
+    /// A grid with no rows equals an empty list whatever its column count,
+    /// so every grid equals its own `Grid::clone_rows`.
+    fn eq_rows<U, R>(&self, rows: &[R]) -> bool
+    where
+        T: PartialEq<U>,
+        R: AsRef<[U]>,
+    {
+        rows.len() == self.row_count
+            && rows.iter().enumerate().all(|(row, cells)| {
+                let cells = cells.as_ref();
+                cells.len() == self.col_count && self.data[self.row_range(row)] == *cells
+            })
+    }
+}
+
+/// Compare with rows written as nested `Vec`s, so tests can write
+/// `assert_eq!(grid, vec![vec![1, 2], vec![3, 4]])`.
+///
+/// Grids never equal ragged rows.
+impl<T, U> PartialEq<Vec<Vec<U>>> for Grid<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &Vec<Vec<U>>) -> bool {
+        self.eq_rows(other)
+    }
+}
+
+impl<T, U> PartialEq<Grid<U>> for Vec<Vec<T>>
+where
+    U: PartialEq<T>,
+{
+    fn eq(&self, other: &Grid<U>) -> bool {
+        other.eq_rows(self)
+    }
+}
+
+impl<'a, T, U> PartialEq<&'a [&'a [U]]> for Grid<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &&'a [&'a [U]]) -> bool {
+        self.eq_rows(other)
+    }
+}
+
+impl<'a, T, U> PartialEq<Grid<U>> for &'a [&'a [T]]
+where
+    U: PartialEq<T>,
+{
+    fn eq(&self, other: &Grid<U>) -> bool {
+        other.eq_rows(self)
+    }
+}
+
+impl<T, U, const C: usize, const R: usize> PartialEq<[[U; C]; R]> for Grid<T>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &[[U; C]; R]) -> bool {
+        self.eq_rows(other)
+    }
+}
+
+impl<T, U, const C: usize, const R: usize> PartialEq<Grid<U>> for [[T; C]; R]
+where
+    U: PartialEq<T>,
+{
+    fn eq(&self, other: &Grid<U>) -> bool {
+        other.eq_rows(self)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Extending
+//////////////////////////////////////////////////////////////////////////////
+
+/// Write each value to its coordinate, in order, so later writes to the
+/// same cell win.
+///
+/// Out-of-bounds coordinates are skipped, as with `Grid::set`.
+///
+/// # Examples
+///
+/// ```
+/// use gridd::Grid;
+///
+/// let mut grid = Grid::new(3, 2, 0);
+/// grid.extend(vec![((0, 0), 1), ((2, 1), 2), ((9, 9), 3)]);
+///
+/// assert_eq!(grid, vec![vec![1, 0, 0], vec![0, 0, 2]]);
+/// ```
+impl<T> Extend<(Coord, T)> for Grid<T> {
+    fn extend<I>(&mut self, updates: I)
+    where
+        I: IntoIterator<Item = (Coord, T)>,
+    {
+        for (coord, value) in updates {
+            if let Some(cell) = self.get_mut(coord) {
+                *cell = value;
+            }
+        }
+    }
+}
+
+/// Append each row to the bottom of the grid, as with `Grid::push_row`.
+///
+/// # Panics
+///
+/// Panics if a row's length differs from the column count. Rows before it
+/// have already been appended.
+///
+/// # Examples
+///
+/// ```
+/// use gridd::Grid;
+///
+/// let mut grid = Grid::new(0, 0, ' ');
+/// grid.extend("ab\ncd".lines().map(|line| line.chars().collect::<Vec<_>>()));
+///
+/// assert_eq!(grid, [['a', 'b'], ['c', 'd']]);
+/// ```
+impl<T> Extend<Vec<T>> for Grid<T> {
+    fn extend<I>(&mut self, rows: I)
+    where
+        I: IntoIterator<Item = Vec<T>>,
+    {
+        for row in rows {
+            if let Err(err) = self.push_row(row) {
+                panic!("cannot extend a grid with a row: {}", err);
+            }
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Labeled Regions
+//////////////////////////////////////////////////////////////////////////////
+
+/// Size and shape measurements of one labeled region.
+///
+/// Produced by `Grid::region_metrics`.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct RegionMetrics {
+    /// The label shared by every cell of the region.
+    pub label: u32,
+    /// The number of cells in the region.
+    pub area: usize,
+    /// The number of cell edges between the region and a different label
+    /// or the grid boundary.
+    pub perimeter: usize,
+    /// The smallest rectangle containing every cell of the region.
+    pub bounds: Rect,
+}
+
+impl<T> Grid<T> {
+    /// Measure every region of the grid, given a grid of the same size
+    /// labeling each cell with its region.
+    ///
+    /// A region is all the cells sharing one label. Regions are returned in
+    /// increasing label order. Each edge between two differently-labeled
+    /// cells counts toward the perimeter of both regions, and edges around
+    /// holes count as well as outer edges.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GridError::DimensionMismatch` if `labels` is not the same
+    /// size as the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Grid, Rect};
+    ///
+    /// let garden: Grid<char> = "AAB\nAAB".parse().unwrap();
+    /// let labels = garden.map(|&plant| plant as u32);
+    ///
+    /// let metrics = garden.region_metrics(&labels).unwrap();
+    /// assert_eq!((4, 8), (metrics[0].area, metrics[0].perimeter));
+    /// assert_eq!((2, 6), (metrics[1].area, metrics[1].perimeter));
+    /// assert_eq!(Rect::new((2, 0), 1, 2), metrics[1].bounds);
+    /// ```
+    pub fn region_metrics(&self, labels: &Grid<u32>) -> Result<Vec<RegionMetrics>, GridError> {
+        self.check_same_dims(labels)?;
+
+        // Per label: area, perimeter, top-left corner, bottom-right corner.
+        let mut regions: BTreeMap<u32, (usize, usize, Coord, Coord)> = BTreeMap::new();
+
+        for ((col, row), &label) in labels.indexed_iter() {
+            let fenced = [Offset::NORTH, Offset::EAST, Offset::SOUTH, Offset::WEST]
+                .iter()
+                .filter(|&&vec| labels.rget((col, row), vec) != Some(&label))
+                .count();
+
+            let (area, perimeter, min, max) = regions
+                .entry(label)
+                .or_insert((0, 0, (col, row), (col, row)));
+            *area += 1;
+            *perimeter += fenced;
+            *min = (min.0.min(col), min.1.min(row));
+            *max = (max.0.max(col), max.1.max(row));
+        }
+
+        Ok(regions
+            .into_iter()
+            .map(|(label, (area, perimeter, min, max))| RegionMetrics {
+                label,
+                area,
+                perimeter,
+                bounds: Rect::new(min, max.0 - min.0 + 1, max.1 - min.1 + 1),
+            })
+            .collect())
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    /// Build a grid whose cells hold their own flat index.
+    pub(crate) fn labeled(col_count: usize, row_count: usize) -> Grid<usize> {
+        Grid {
+            col_count,
+            row_count,
+            data: (0..col_count * row_count).collect(),
+        }
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut grid = Grid::new(1, 1, 'a');
+        let value = grid.get_mut((0, 0)).unwrap();
+
+        assert_eq!(&'a', value);
+        *value = 'b';
+        assert_eq!(&'b', value);
+    }
+
+    #[test]
+    fn test_get_set() {
+        let mut grid = Grid::new(5, 5, 'a');
+
+        assert_eq!(Some(&'a'), grid.get((2, 3)));
+        assert_eq!(Some(&'a'), grid.get((3, 3)));
+        assert_eq!(Some(&'a'), grid.get((3, 4)));
+
+        grid.set((2, 3), 'b');
+        grid.set((3, 3), 'c');
+        grid.set((3, 4), 'd');
+
+        assert_eq!(Some(&'b'), grid.get((2, 3)));
         assert_eq!(Some(&'c'), grid.get((3, 3)));
         assert_eq!(Some(&'d'), grid.get((3, 4)));
     }
 
     #[test]
-    fn test_rget() {
-        let mut grid = Grid::new(5, 5, 'a');
+    fn test_rget() {
+        let mut grid = Grid::new(5, 5, 'a');
+
+        assert_eq!(Some(&'a'), grid.get((2, 3)));
+
+        grid.set((2, 3), 'b');
+
+        assert_eq!(Some(&'b'), grid.rget((2, 4), Offset::NORTH));
+        assert_eq!(Some(&mut 'b'), grid.rget_mut((2, 4), Offset::NORTH));
+    }
+
+    #[test]
+    fn test_rset() {
+        let mut grid = Grid::new(5, 5, 'a');
+
+        assert_eq!(Some(&'a'), grid.get((2, 3)));
+
+        grid.rset((1, 1), Offset::from((1, 2)), 'b');
+
+        assert_eq!(Some(&'b'), grid.rget((2, 4), Offset::NORTH));
+        assert_eq!(Some(&mut 'b'), grid.rget_mut((2, 4), Offset::NORTH));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let src_col = 3;
+        let src_row = 4;
+
+        let mut grid = Grid::new(src_col, src_row, (0, 0));
+
+        for i in 0..src_col {
+            for j in 0..src_row {
+                grid.set((i, j), (i, j));
+            }
+        }
+
+        let tgrid = grid.transpose();
+
+        assert_eq!(4, tgrid.col_count());
+        assert_eq!(3, tgrid.row_count());
+
+        for i in 0..src_col {
+            for j in 0..src_row {
+                assert_eq!(tgrid.get((j, i)), grid.get((i, j)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_transpose_non_copy() {
+        let grid = Grid {
+            col_count: 3,
+            row_count: 2,
+            data: ["a", "b", "c", "d", "e", "f"]
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+        };
+
+        let tgrid = grid.transpose();
+
+        assert_eq!(2, tgrid.col_count());
+        assert_eq!(3, tgrid.row_count());
+        assert_eq!(vec!["a", "d", "b", "e", "c", "f"], tgrid.data);
+        assert_eq!(tgrid, grid.clone().into_transpose());
+        assert_eq!(grid, tgrid.transpose());
+    }
+
+    #[test]
+    fn test_transpose_zero_dimension() {
+        let grid: Grid<u8> = Grid::new(0, 5, 0);
+
+        let tgrid = grid.transpose();
+        assert_eq!(5, tgrid.col_count());
+        assert_eq!(0, tgrid.row_count());
+        assert_eq!(tgrid, grid.clone().into_transpose());
+
+        assert_eq!(grid, tgrid.transpose());
+        assert_eq!(grid, tgrid.into_transpose());
+    }
+
+    #[test]
+    fn test_into_transpose() {
+        for &(cols, rows) in &[(1, 1), (1, 4), (4, 1), (3, 5), (6, 4)] {
+            let grid = labeled(cols, rows);
+            let tgrid = grid.clone().into_transpose();
+
+            assert_eq!(grid.transpose(), tgrid);
+            assert_eq!(grid, tgrid.into_transpose());
+        }
+    }
+
+    #[test]
+    fn test_transpose_into() {
+        let grid = labeled(5, 3);
+        let mut dest = Grid::new(3, 5, 0);
+        let ptr = dest.data.as_ptr();
+        let capacity = dest.data.capacity();
+
+        grid.transpose_into(&mut dest).unwrap();
+
+        assert_eq!(grid.transpose(), dest);
+        assert_eq!(ptr, dest.data.as_ptr());
+        assert_eq!(capacity, dest.data.capacity());
+    }
+
+    #[test]
+    fn test_transpose_into_mismatch() {
+        let grid = labeled(5, 3);
+        let mut dest = Grid::new(5, 3, 0);
+
+        assert_eq!(
+            Err(GridError::DimensionMismatch {
+                expected: (3, 5),
+                found: (5, 3)
+            }),
+            grid.transpose_into(&mut dest)
+        );
+        assert_eq!(Grid::new(5, 3, 0), dest);
+    }
+
+    /// Row-by-row reference transposition for checking the tiled versions.
+    fn naive_transpose(grid: &Grid<usize>) -> Grid<usize> {
+        let mut data = Vec::with_capacity(grid.data.len());
+
+        for col in 0..grid.col_count {
+            for row in 0..grid.row_count {
+                data.push(grid.data[col + grid.col_count * row]);
+            }
+        }
+
+        Grid {
+            col_count: grid.row_count,
+            row_count: grid.col_count,
+            data,
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn prop_transpose_matches_naive(cols in 0usize..100, rows in 0usize..100) {
+            let grid = labeled(cols, rows);
+            let expected = naive_transpose(&grid);
+
+            prop_assert_eq!(&expected, &grid.transpose());
+
+            let mut dest = Grid::new(rows, cols, 0);
+            grid.transpose_into(&mut dest).unwrap();
+            prop_assert_eq!(&expected, &dest);
+        }
+
+        #[test]
+        fn prop_transpose_in_place_matches_naive(side in 0usize..100) {
+            let mut grid = labeled(side, side);
+            let expected = naive_transpose(&grid);
+
+            grid.transpose_in_place().unwrap();
+            prop_assert_eq!(expected, grid);
+        }
+    }
+
+    #[test]
+    fn test_transpose_in_place() {
+        for side in 0..6 {
+            let mut grid = labeled(side, side);
+
+            grid.transpose_in_place().unwrap();
+            assert_eq!(labeled(side, side).transpose(), grid);
+
+            grid.transpose_in_place().unwrap();
+            assert_eq!(labeled(side, side), grid);
+        }
+
+        let mut grid = Grid::square(1, 'a');
+        grid.transpose_in_place().unwrap();
+        assert_eq!(Grid::square(1, 'a'), grid);
+    }
+
+    #[test]
+    fn test_transpose_in_place_not_square() {
+        let mut grid = labeled(3, 2);
+
+        assert_eq!(
+            Err(GridError::NotSquare {
+                col_count: 3,
+                row_count: 2
+            }),
+            grid.transpose_in_place()
+        );
+        assert_eq!(labeled(3, 2), grid);
+    }
+
+    #[test]
+    fn test_rotate_cw() {
+        // 0 1
+        // 2 3
+        // 4 5
+        let grid = labeled(2, 3);
+        let rotated = grid.rotate_cw();
+
+        // 4 2 0
+        // 5 3 1
+        assert_eq!(3, rotated.col_count());
+        assert_eq!(2, rotated.row_count());
+        assert_eq!(vec![4, 2, 0, 5, 3, 1], rotated.data);
+        assert_eq!(Some(&0), rotated.get((2, 0)));
+    }
+
+    #[test]
+    fn test_rotate_ccw() {
+        let grid = labeled(2, 3);
+        let rotated = grid.rotate_ccw();
+
+        // 1 3 5
+        // 0 2 4
+        assert_eq!(3, rotated.col_count());
+        assert_eq!(2, rotated.row_count());
+        assert_eq!(vec![1, 3, 5, 0, 2, 4], rotated.data);
+        assert_eq!(Some(&0), rotated.get((0, 1)));
+    }
+
+    #[test]
+    fn test_rotate_round_trip() {
+        let grid = labeled(2, 3);
+
+        let mut rotated = grid.clone();
+        for _ in 0..4 {
+            rotated = rotated.rotate_cw();
+        }
+        assert_eq!(grid, rotated);
+
+        assert_eq!(grid, grid.rotate_cw().rotate_ccw());
+        assert_eq!(grid, grid.rotate_ccw().rotate_cw());
+    }
+
+    #[test]
+    fn test_rotate_180() {
+        for &(cols, rows) in &[(2, 3), (4, 2), (3, 3), (0, 2)] {
+            let grid = labeled(cols, rows);
+            let rotated = grid.rotate_180();
+
+            assert_eq!(grid.rotate_cw().rotate_cw(), rotated);
+
+            for row in 0..rows {
+                for col in 0..cols {
+                    assert_eq!(
+                        grid.get((col, row)),
+                        rotated.get((cols - 1 - col, rows - 1 - row))
+                    );
+                }
+            }
+
+            let mut in_place = grid.clone();
+            in_place.rotate_180_in_place();
+            assert_eq!(rotated, in_place);
+        }
+    }
+
+    #[test]
+    fn test_flip_horizontal() {
+        // a b c
+        // d e f
+        let grid = Grid {
+            col_count: 3,
+            row_count: 2,
+            data: vec!['a', 'b', 'c', 'd', 'e', 'f'],
+        };
+        let flipped = grid.flip_horizontal();
+
+        assert_eq!(vec!['c', 'b', 'a', 'f', 'e', 'd'], flipped.data);
+        assert_eq!(grid, flipped.flip_horizontal());
+
+        let mut in_place = grid.clone();
+        in_place.flip_horizontal_in_place();
+        assert_eq!(flipped, in_place);
+        in_place.flip_horizontal_in_place();
+        assert_eq!(grid, in_place);
+    }
+
+    #[test]
+    fn test_flip_horizontal_even() {
+        let grid = labeled(4, 2);
+        let flipped = grid.flip_horizontal();
+
+        assert_eq!(vec![3, 2, 1, 0, 7, 6, 5, 4], flipped.data);
+        assert_eq!(grid, flipped.flip_horizontal());
+    }
+
+    #[test]
+    fn test_flip_vertical() {
+        // a b
+        // c d
+        // e f
+        let grid = Grid {
+            col_count: 2,
+            row_count: 3,
+            data: vec!['a', 'b', 'c', 'd', 'e', 'f'],
+        };
+        let flipped = grid.flip_vertical();
+
+        assert_eq!(vec!['e', 'f', 'c', 'd', 'a', 'b'], flipped.data);
+        assert_eq!(grid, flipped.flip_vertical());
+
+        let mut in_place = grid.clone();
+        in_place.flip_vertical_in_place();
+        assert_eq!(flipped, in_place);
+        in_place.flip_vertical_in_place();
+        assert_eq!(grid, in_place);
+    }
+
+    #[test]
+    fn test_flips_match_permutations() {
+        let grid = labeled(3, 4);
+
+        let mut permuted = grid.clone();
+        permuted.permute_rows(&[3, 2, 1, 0]).unwrap();
+        assert_eq!(grid.flip_vertical(), permuted);
+
+        let mut permuted = grid.clone();
+        permuted.permute_cols(&[2, 1, 0]).unwrap();
+        assert_eq!(grid.flip_horizontal(), permuted);
+    }
+
+    #[test]
+    fn test_flips_compose_to_rotate_180() {
+        for &(cols, rows) in &[(2, 3), (4, 4), (5, 1)] {
+            let grid = labeled(cols, rows);
+
+            assert_eq!(grid.rotate_180(), grid.flip_horizontal().flip_vertical());
+        }
+    }
+
+    #[test]
+    fn test_rotate_180_center() {
+        let mut grid = labeled(5, 3);
+
+        grid.rotate_180_in_place();
+        assert_eq!(Some(&7), grid.get((2, 1)));
+        assert_eq!(Some(&14), grid.get((0, 0)));
+    }
+
+    #[test]
+    fn test_upscale() {
+        // 0 1
+        // 2 3
+        let grid = labeled(2, 2);
+        let scaled = grid.upscale(3, 2);
+
+        assert_eq!(6, scaled.col_count());
+        assert_eq!(4, scaled.row_count());
+        assert_eq!(
+            vec![
+                0, 0, 0, 1, 1, 1,
+                0, 0, 0, 1, 1, 1,
+                2, 2, 2, 3, 3, 3,
+                2, 2, 2, 3, 3, 3,
+            ],
+            scaled.data
+        );
+
+        for row in 0..4 {
+            for col in 0..6 {
+                assert_eq!(grid.get((col / 3, row / 2)), scaled.get((col, row)));
+            }
+        }
+
+        assert_eq!(grid, grid.upscale(1, 1));
+    }
+
+    #[test]
+    #[should_panic(expected = "upscale factors must be non-zero")]
+    fn test_upscale_zero_factor() {
+        labeled(2, 2).upscale(0, 1);
+    }
+
+    #[test]
+    fn test_resample_down() {
+        let grid = labeled(4, 4);
+        let resampled = grid.resample(3, 3);
+
+        assert_eq!(vec![0, 2, 3, 8, 10, 11, 12, 14, 15], resampled.data);
+    }
+
+    #[test]
+    fn test_resample_up() {
+        let grid = labeled(4, 4);
+        let resampled = grid.resample(7, 5);
+
+        let cols = [0, 0, 1, 2, 2, 3, 3];
+        let rows = [0, 1, 2, 2, 3];
+
+        assert_eq!(7, resampled.col_count());
+        assert_eq!(5, resampled.row_count());
+        for (row, &src_row) in rows.iter().enumerate() {
+            for (col, &src_col) in cols.iter().enumerate() {
+                assert_eq!(grid.get((src_col, src_row)), resampled.get((col, row)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_resample_identity_and_empty() {
+        let grid = labeled(5, 3);
+
+        assert_eq!(grid, grid.resample(5, 3));
+
+        let empty = grid.resample(0, 4);
+        assert_eq!((0, 4), (empty.col_count(), empty.row_count()));
+        assert!(empty.data.is_empty());
+    }
+
+    proptest! {
+        #[test]
+        fn prop_resample_round_trip_keeps_corners(
+            cols in 1usize..12,
+            rows in 1usize..12,
+            extra_cols in 0usize..20,
+            extra_rows in 0usize..20,
+        ) {
+            let grid = labeled(cols, rows);
+            let round_trip = grid
+                .resample(cols + extra_cols, rows + extra_rows)
+                .resample(cols, rows);
+
+            for &corner in &[(0, 0), (cols - 1, 0), (0, rows - 1), (cols - 1, rows - 1)] {
+                prop_assert_eq!(grid.get(corner), round_trip.get(corner));
+            }
+        }
+    }
+
+    #[test]
+    fn test_subgrid() {
+        //  0  1  2  3
+        //  4  5  6  7
+        //  8  9 10 11
+        let grid = labeled(4, 3);
+
+        let interior = grid.subgrid(Rect::new((1, 1), 2, 1)).unwrap();
+        assert_eq!(vec![5, 6], interior.data);
+
+        let corner = grid.subgrid(((2, 1), (2, 2))).unwrap();
+        assert_eq!((2, 2), (corner.col_count(), corner.row_count()));
+        assert_eq!(vec![6, 7, 10, 11], corner.data);
+
+        assert_eq!(grid, grid.subgrid(((0, 0), (4, 3))).unwrap());
+    }
+
+    #[test]
+    fn test_subgrid_out_of_bounds() {
+        let grid = labeled(4, 3);
+
+        assert_eq!(None, grid.subgrid(((3, 1), (2, 2))));
+        assert_eq!(None, grid.subgrid(((5, 5), (1, 1))));
+
+        let clipped = grid.subgrid_clipped(((3, 1), (2, 2)));
+        assert_eq!((1, 2), (clipped.col_count(), clipped.row_count()));
+        assert_eq!(vec![7, 11], clipped.data);
+
+        let outside = grid.subgrid_clipped(((5, 5), (1, 1)));
+        assert_eq!((0, 0), (outside.col_count(), outside.row_count()));
+    }
+
+    #[test]
+    fn test_subgrid_zero_area() {
+        let grid = labeled(4, 3);
+
+        let empty = grid.subgrid(((1, 1), (0, 2))).unwrap();
+        assert_eq!((0, 2), (empty.col_count(), empty.row_count()));
+        assert!(empty.data.is_empty());
+
+        let empty = grid.subgrid_clipped(((1, 1), (3, 0)));
+        assert_eq!((3, 0), (empty.col_count(), empty.row_count()));
+    }
+
+    /// Pad a labeled 3x3 grid by two cells on every side.
+    fn pad_labeled(mode: PadMode<usize>) -> Grid<usize> {
+        labeled(3, 3).pad(2, 2, 2, 2, mode).unwrap()
+    }
+
+    #[test]
+    fn test_pad_constant() {
+        let padded = pad_labeled(PadMode::Constant(9));
+
+        assert_eq!((7, 7), (padded.col_count(), padded.row_count()));
+        assert_eq!(vec![9; 7], padded.data[0..7].to_vec());
+        assert_eq!(vec![9, 9, 3, 4, 5, 9, 9], padded.data[21..28].to_vec());
+        assert_eq!(Some(&9), padded.get((6, 6)));
+    }
+
+    #[test]
+    fn test_pad_edge() {
+        let padded = pad_labeled(PadMode::Edge);
+
+        assert_eq!(vec![0, 0, 0, 1, 2, 2, 2], padded.data[0..7].to_vec());
+        assert_eq!(vec![3, 3, 3, 4, 5, 5, 5], padded.data[21..28].to_vec());
+        assert_eq!(vec![6, 6, 6, 7, 8, 8, 8], padded.data[42..49].to_vec());
+    }
+
+    #[test]
+    fn test_pad_reflect() {
+        let padded = pad_labeled(PadMode::Reflect);
+
+        assert_eq!(vec![4, 3, 3, 4, 5, 5, 4], padded.data[0..7].to_vec());
+        assert_eq!(vec![1, 0, 0, 1, 2, 2, 1], padded.data[7..14].to_vec());
+        assert_eq!(vec![7, 6, 6, 7, 8, 8, 7], padded.data[35..42].to_vec());
+        assert_eq!(vec![4, 3, 3, 4, 5, 5, 4], padded.data[42..49].to_vec());
+    }
+
+    #[test]
+    fn test_pad_wrap() {
+        let padded = pad_labeled(PadMode::Wrap);
+
+        assert_eq!(vec![4, 5, 3, 4, 5, 3, 4], padded.data[0..7].to_vec());
+        assert_eq!(vec![7, 8, 6, 7, 8, 6, 7], padded.data[7..14].to_vec());
+        assert_eq!(vec![1, 2, 0, 1, 2, 0, 1], padded.data[35..42].to_vec());
+        assert_eq!(vec![4, 5, 3, 4, 5, 3, 4], padded.data[42..49].to_vec());
+    }
+
+    #[test]
+    fn test_pad_asymmetric() {
+        let padded = labeled(2, 1).pad(0, 3, 1, 0, PadMode::Wrap).unwrap();
+
+        assert_eq!((5, 2), (padded.col_count(), padded.row_count()));
+        assert_eq!(vec![0, 1, 0, 1, 0, 0, 1, 0, 1, 0], padded.data);
+    }
+
+    #[test]
+    fn test_pad_empty() {
+        let empty: Grid<usize> = Grid::new(0, 0, 0);
+
+        assert_eq!(Grid::new(3, 2, 7), empty.pad(1, 2, 0, 2, PadMode::Constant(7)).unwrap());
+        assert_eq!(Err(GridError::EmptyGrid), empty.pad(1, 1, 1, 1, PadMode::Edge));
+        assert_eq!(Err(GridError::EmptyGrid), empty.pad(1, 1, 1, 1, PadMode::Reflect));
+        assert_eq!(Err(GridError::EmptyGrid), empty.pad(1, 1, 1, 1, PadMode::Wrap));
+    }
+
+    #[test]
+    fn test_resize_grow() {
+        let mut grid = labeled(2, 2);
+        grid.resize(4, 3, 9);
+
+        assert_eq!((4, 3), (grid.col_count(), grid.row_count()));
+        assert_eq!(vec![0, 1, 9, 9, 2, 3, 9, 9, 9, 9, 9, 9], grid.data);
+    }
+
+    #[test]
+    fn test_resize_shrink() {
+        let mut grid = labeled(4, 3);
+        grid.resize(2, 2, 9);
+
+        assert_eq!((2, 2), (grid.col_count(), grid.row_count()));
+        assert_eq!(vec![0, 1, 4, 5], grid.data);
+    }
+
+    #[test]
+    fn test_resize_mixed() {
+        let mut grid = labeled(3, 2);
+        grid.resize(2, 4, 9);
+
+        assert_eq!(vec![0, 1, 3, 4, 9, 9, 9, 9], grid.data);
+
+        let mut grid = labeled(2, 3);
+        grid.resize(3, 1, 9);
+
+        assert_eq!(vec![0, 1, 9], grid.data);
+    }
+
+    #[test]
+    fn test_resize_drops_truncated() {
+        use std::rc::Rc;
+
+        let shared = Rc::new(());
+        let mut grid = Grid::new(4, 4, Rc::clone(&shared));
+        assert_eq!(17, Rc::strong_count(&shared));
+
+        grid.resize(2, 3, Rc::clone(&shared));
+        assert_eq!(7, Rc::strong_count(&shared));
+
+        grid.resize(3, 3, Rc::clone(&shared));
+        assert_eq!(10, Rc::strong_count(&shared));
+    }
+
+    #[test]
+    fn test_ensure_contains() {
+        let mut grid = labeled(2, 2);
+        grid.ensure_contains((1, 1), 9);
+        assert_eq!(labeled(2, 2), grid);
+
+        grid.ensure_contains((3, 0), 9);
+        assert_eq!((4, 2), (grid.col_count(), grid.row_count()));
+        assert_eq!(vec![0, 1, 9, 9, 2, 3, 9, 9], grid.data);
+
+        grid.ensure_contains((0, 2), 8);
+        assert_eq!((4, 3), (grid.col_count(), grid.row_count()));
+        assert_eq!(vec![0, 1, 9, 9, 2, 3, 9, 9, 8, 8, 8, 8], grid.data);
+
+        let mut grid = labeled(2, 1);
+        grid.ensure_contains((2, 1), 7);
+        assert_eq!(vec![0, 1, 7, 7, 7, 7], grid.data);
+    }
+
+    #[test]
+    fn test_ensure_contains_amortized() {
+        let mut grid = Grid::new(0, 0, 0);
+        let mut reallocations = 0;
+
+        for row in 0..1000 {
+            let capacity = grid.data.capacity();
+            grid.ensure_contains((0, row), 0);
+            if grid.data.capacity() != capacity {
+                reallocations += 1;
+            }
+        }
+
+        assert!(reallocations <= 11);
+    }
+
+    #[test]
+    #[should_panic(expected = "grid dimension overflows usize")]
+    fn test_ensure_contains_overflow() {
+        Grid::new(0, 0, 0).ensure_contains((usize::MAX, 0), 0);
+    }
+
+    #[test]
+    fn test_indexed_iter() {
+        let grid = labeled(3, 2);
+
+        for ((col, row), &cell) in grid.indexed_iter() {
+            assert_eq!(col + 3 * row, cell);
+        }
+        assert_eq!(6, grid.indexed_iter().count());
+        assert_eq!(0, Grid::new(0, 4, 0).indexed_iter().count());
+    }
+
+    #[test]
+    fn test_region_eq() {
+        let grid = labeled(4, 3);
+        let mut copy = Grid::new(6, 5, 0);
+        copy.view_mut(Rect::new((2, 1), 4, 3))
+            .unwrap()
+            .iter_mut()
+            .zip(grid.iter())
+            .for_each(|(dst, &src)| *dst = src);
+
+        assert_eq!(Ok(true), grid.region_eq(grid.bounds(), &copy, (2, 1)));
+        assert_eq!(Ok(true), grid.region_eq(Rect::new((1, 1), 2, 2), &copy, (3, 2)));
+        assert_eq!(Ok(false), grid.region_eq(grid.bounds(), &copy, (1, 1)));
+
+        copy.set((5, 3), 99);
+        assert_eq!(Ok(false), grid.region_eq(grid.bounds(), &copy, (2, 1)));
+        assert_eq!(Ok(true), grid.region_eq(Rect::new((0, 0), 3, 3), &copy, (2, 1)));
+    }
+
+    #[test]
+    fn test_region_eq_same_grid() {
+        let mut grid = Grid::new(6, 2, 0);
+        grid.set((1, 1), 5);
+        grid.set((4, 1), 5);
+
+        let tile = Rect::new((0, 0), 3, 2);
+        assert_eq!(Ok(true), grid.region_eq(tile, &grid, (3, 0)));
+        assert_eq!(Ok(false), grid.region_eq(tile, &grid, (2, 0)));
+        assert_eq!(Ok(true), grid.region_eq(Rect::new((6, 2), 0, 0), &grid, (0, 0)));
+    }
+
+    #[test]
+    fn test_region_eq_out_of_bounds() {
+        let grid = labeled(4, 3);
+
+        assert_eq!(
+            Err(GridError::ColOutOfBounds { col: 4, col_count: 4 }),
+            grid.region_eq(Rect::new((2, 0), 3, 1), &grid, (0, 0))
+        );
+        assert_eq!(
+            Err(GridError::RowOutOfBounds { row: 3, row_count: 3 }),
+            grid.region_eq(Rect::new((0, 0), 2, 2), &grid, (0, 2))
+        );
+    }
+
+    #[test]
+    fn test_get_batch() {
+        let grid = labeled(3, 2);
+
+        assert_eq!(
+            [Some(&5), None, Some(&0), None, Some(&5)],
+            grid.get_batch([(2, 1), (3, 0), (0, 0), (0, 2), (2, 1)])
+        );
+        assert_eq!([None; 0], grid.get_batch([]));
+    }
+
+    #[test]
+    fn test_get_iter() {
+        let grid = labeled(3, 2);
+        let coords = vec![(1, 0), (9, 9), (1, 1), (1, 0)];
+
+        assert_eq!(
+            vec![Some(&1), None, Some(&4), Some(&1)],
+            grid.get_iter(coords).collect::<Vec<_>>()
+        );
+        assert_eq!(0, grid.get_iter(std::iter::empty()).count());
+    }
+
+    #[test]
+    fn test_first_mismatch() {
+        let grid = labeled(4, 3);
+        let mut other = grid.clone();
+        assert_eq!(None, grid.first_mismatch(&other, |a, b| a == b));
+
+        other.set((3, 2), 0);
+        other.set((1, 2), 0);
+        assert_eq!(Some((1, 2)), grid.first_mismatch(&other, |a, b| a == b));
+        assert_eq!(None, grid.first_mismatch(&other, |_, _| true));
+        assert_eq!(Some((4, 0)), grid.first_mismatch(&labeled(5, 3), |_, _| true));
+        assert_eq!(Some((0, 0)), grid.first_mismatch(&Grid::new(0, 0, 0), |_, _| true));
+    }
+
+    #[test]
+    fn test_cast_widening() {
+        let grid = labeled(4, 3).map(|&cell| cell as u8 * 20);
+        let wide: Grid<u32> = grid.clone().cast();
+
+        assert_eq!((4, 3), (wide.col_count(), wide.row_count()));
+        assert_eq!(grid.map(|&cell| u32::from(cell)), wide);
+        assert_eq!(Grid::new(3, 0, 0.0f64), Grid::new(3, 0, 0.0f32).cast());
+    }
+
+    #[test]
+    fn test_try_cast() {
+        let mut grid = labeled(4, 3).map(|&cell| cell as i64);
+        let narrow: Grid<u8> = grid.clone().try_cast().unwrap();
+        assert_eq!((4, 3), (narrow.col_count(), narrow.row_count()));
+        assert_eq!(grid, narrow.cast());
+
+        // The first failure in row-major order is reported.
+        grid.set((3, 2), -5);
+        grid.set((2, 1), 300);
+        assert_eq!((2, 1), grid.try_cast::<u8>().unwrap_err().0);
+    }
+
+    #[test]
+    fn test_cross() {
+        let grid = labeled(5, 4);
+        let cells: Vec<_> = grid.cross((3, 2)).collect();
+
+        assert_eq!(5 + 4 - 1, cells.len());
+        for &((col, row), &cell) in &cells {
+            assert!(col == 3 || row == 2);
+            assert_eq!(col + 5 * row, cell);
+        }
+        assert_eq!(1, cells.iter().filter(|(coord, _)| *coord == (3, 2)).count());
+
+        assert_eq!(0, grid.cross((5, 0)).count());
+        assert_eq!(0, grid.cross((0, 4)).count());
+    }
+
+    #[test]
+    fn test_cross_thin() {
+        let row = labeled(6, 1);
+        let col = labeled(1, 6);
+
+        assert!(row.cross((2, 0)).map(|(_, &c)| c).eq(0..6));
+        assert!(col.cross((0, 4)).map(|(_, &c)| c).eq(0..6));
+        assert_eq!(vec![((0, 0), &0)], labeled(1, 1).cross((0, 0)).collect::<Vec<_>>());
+        assert_eq!(0, Grid::new(0, 3, 0).cross((0, 0)).count());
+    }
+
+    #[test]
+    fn test_cross_mut() {
+        let mut grid = Grid::new(4, 3, 0);
+
+        for (_, cell) in grid.cross_mut((1, 2)) {
+            *cell += 1;
+        }
+
+        for ((col, row), &cell) in grid.indexed_iter() {
+            assert_eq!(usize::from(col == 1 || row == 2), cell);
+        }
+        assert_eq!(0, grid.cross_mut((4, 0)).count());
+    }
+
+    #[test]
+    fn test_find_value() {
+        let mut grid = Grid::new(4, 3, 0);
+        assert_eq!(None, grid.find_value(&1));
+
+        grid.set((2, 1), 1);
+        assert_eq!(Some((2, 1)), grid.find_value(&1));
+
+        // Row-major order: (3, 0) comes before (0, 2) and (2, 1).
+        grid.set((0, 2), 1);
+        grid.set((3, 0), 1);
+        assert_eq!(Some((3, 0)), grid.find_value(&1));
+    }
+
+    #[test]
+    fn test_find() {
+        let grid = labeled(4, 3);
+        let mut visited = 0;
+
+        let found = grid.find(|(col, row), &cell| {
+            assert_eq!(col + 4 * row, cell);
+            visited += 1;
+            cell > 5
+        });
+
+        assert_eq!(Some(((2, 1), &6)), found);
+        assert_eq!(7, visited);
+        assert_eq!(None, grid.find(|_, &cell| cell > 11));
+    }
+
+    #[test]
+    fn test_positions() {
+        let grid = labeled(5, 4);
+        let expected: Vec<_> = grid
+            .indexed_iter()
+            .filter(|&(_, &cell)| cell % 3 == 0)
+            .map(|(coord, _)| coord)
+            .collect();
+
+        assert_eq!(expected, grid.positions(|_, &cell| cell % 3 == 0).collect::<Vec<_>>());
+        assert_eq!(0, grid.positions(|_, &cell| cell > 100).count());
+    }
+
+    #[test]
+    fn test_positions_is_lazy() {
+        let grid = labeled(5, 4);
+        let mut calls = 0;
+
+        let first = grid
+            .positions(|(col, _), _| {
+                calls += 1;
+                col == 2
+            })
+            .next();
+
+        assert_eq!(Some((2, 0)), first);
+        assert_eq!(3, calls);
+    }
+
+    #[test]
+    fn test_positions_of() {
+        let mut grid = Grid::new(3, 3, String::from("-"));
+        assert_eq!(0, grid.positions_of(&String::from("x")).count());
+
+        grid.set((1, 2), String::from("x"));
+        grid.set((0, 1), String::from("x"));
+        grid.set((2, 1), String::from("x"));
+
+        let needle = String::from("x");
+        assert_eq!(
+            vec![(0, 1), (2, 1), (1, 2)],
+            grid.positions_of(&needle).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_extrema_ties() {
+        let mut grid = Grid::new(3, 3, 1);
+        grid.set((2, 0), 5);
+        grid.set((0, 2), 5);
+
+        assert_eq!(Some(((2, 0), &5)), grid.max_cell());
+        assert_eq!(Some(((0, 0), &1)), grid.min_cell());
+        assert_eq!(Some(((2, 0), &5)), grid.max_by_key(|_, &c| c));
+        assert_eq!(Some(((0, 0), &1)), grid.min_by_key(|_, &c| c));
+    }
+
+    #[test]
+    fn test_extrema_by_coord() {
+        let grid = labeled(4, 3);
+
+        // Distance from (2, 1); the nearest cell is itself.
+        let dist = |(col, row): Coord, _: &usize| col.abs_diff(2) + row.abs_diff(1);
+        assert_eq!(Some(((2, 1), &6)), grid.min_by_key(dist));
+        assert_eq!(Some(((3, 0), &3)), grid.max_by_key(|(col, row), _| col.max(row)));
+    }
+
+    #[test]
+    fn test_extrema_empty() {
+        let grid: Grid<usize> = Grid::new(0, 5, 0);
+
+        assert_eq!(None, grid.max_cell());
+        assert_eq!(None, grid.min_by_key(|_, &c| c));
+    }
+
+    #[test]
+    fn test_find_subgrid() {
+        let grid = labeled(5, 4);
+        let pattern = grid.view(Rect::new((2, 1), 2, 3)).unwrap().to_grid();
+
+        assert_eq!(Some((2, 1)), grid.find_subgrid(&pattern));
+        assert_eq!(vec![(2, 1)], grid.find_subgrids(&pattern).collect::<Vec<_>>());
+        assert_eq!(Some((0, 0)), grid.find_subgrid(&grid));
+
+        let mut missing = pattern.clone();
+        missing.set((1, 2), 99);
+        assert_eq!(None, grid.find_subgrid(&missing));
+        assert_eq!(None, grid.find_subgrid(&labeled(6, 1)));
+    }
+
+    #[test]
+    fn test_find_subgrids_overlapping() {
+        let grid = Grid::new(4, 3, 'x');
+        let found: Vec<_> = grid.find_subgrids(&Grid::new(3, 2, 'x')).collect();
+
+        assert_eq!(vec![(0, 0), (1, 0), (0, 1), (1, 1)], found);
+        assert_eq!(Some((0, 0)), grid.find_subgrid(&Grid::new(0, 0, 'x')));
+    }
+
+    #[test]
+    fn test_find_subgrids_empty_pattern() {
+        let grid = labeled(3, 3);
+        let found = |pattern: &Grid<usize>| grid.find_subgrids(pattern).collect::<Vec<_>>();
+
+        assert_eq!(vec![(0, 0)], found(&Grid::new(0, 0, 0)));
+        assert_eq!(vec![(0, 0)], found(&Grid::new(0, 2, 0)));
+        assert_eq!(vec![(0, 0)], found(&Grid::new(3, 0, 0)));
+        assert_eq!(1, grid.find_subgrids_masked(&Grid::new(0, 0, None)).count());
+
+        // Taller or wider than the grid, so never a match.
+        assert_eq!(None, grid.find_subgrid(&Grid::new(0, 5, 0)));
+        assert_eq!(None, grid.find_subgrid(&Grid::new(5, 0, 0)));
+        assert_eq!(None, labeled(3, 1).find_subgrid(&Grid::new(0, 2, 0)));
+        assert_eq!(0, grid.find_subgrids_masked(&Grid::new(4, 0, None)).count());
+
+        let empty: Grid<usize> = Grid::new(0, 3, 0);
+        assert_eq!(None, empty.find_subgrid(&Grid::new(0, 0, 0)));
+        assert_eq!(None, empty.find_subgrid(&Grid::new(0, 1, 0)));
+    }
+
+    #[test]
+    fn test_find_subgrid_masked() {
+        let mut grid = Grid::new(5, 3, 0);
+        grid.set((1, 0), 1);
+        grid.set((3, 2), 1);
+        grid.set((2, 2), 7);
+
+        // A 1 with anything to its left, above a 0.
+        let mut pattern = Grid::new(2, 2, None);
+        pattern.set((1, 0), Some(1));
+        pattern.set((1, 1), Some(0));
+
+        assert_eq!(Some((0, 0)), grid.find_subgrid_masked(&pattern));
+        assert_eq!(1, grid.find_subgrids_masked(&pattern).count());
+
+        // All wildcards match at every position.
+        assert_eq!(4 * 2, grid.find_subgrids_masked(&Grid::new(2, 2, None)).count());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_value_counts() {
+        let grid = labeled(5, 4).map(|&cell| cell % 3);
+        let counts = grid.value_counts();
+
+        assert_eq!(20, counts.values().sum::<usize>());
+        assert_eq!(
+            vec![(0, 7), (1, 7), (2, 6)],
+            grid.value_counts_ordered().into_iter().collect::<Vec<_>>()
+        );
+        assert_eq!(counts, grid.value_counts_ordered().into_iter().collect());
+        assert_eq!((0..3).collect::<HashSet<_>>(), grid.unique_values());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_value_counts_degenerate() {
+        let empty: Grid<u8> = Grid::new(0, 3, 0);
+        assert!(empty.value_counts().is_empty());
+        assert!(empty.unique_values().is_empty());
+
+        let single = Grid::new(4, 4, "wall");
+        assert_eq!(vec![("wall", 16)], single.value_counts().into_iter().collect::<Vec<_>>());
+        assert_eq!(1, single.unique_values().len());
+    }
+
+    #[test]
+    fn test_integer_reductions() {
+        let grid = labeled(3, 2).map(|&cell| cell as i32 - 2);
+
+        assert_eq!(3, grid.sum());
+        assert_eq!(0, grid.product());
+        assert_eq!(120, labeled(5, 1).map(|&cell| cell + 1).product());
+        assert_eq!(Some(-2), grid.min_value());
+        assert_eq!(Some(3), grid.max_value());
+        assert_eq!(Some(0.5), grid.mean());
+    }
+
+    #[test]
+    fn test_float_reductions() {
+        let mut grid = Grid::new(2, 2, 1.5);
+        grid.set((1, 0), -4.0);
+        grid.set((0, 1), f64::NAN);
+
+        assert!(grid.sum().is_nan());
+        assert_eq!(Some(-4.0), grid.min_value());
+        assert_eq!(Some(1.5), grid.max_value());
+
+        grid.set((0, 1), 5.0);
+        assert_eq!(4.0, grid.sum());
+        assert_eq!(Some(1.0), grid.mean());
+        assert_eq!(Some(5.0), grid.max_value());
+    }
+
+    #[test]
+    fn test_reductions_empty() {
+        let grid: Grid<i64> = Grid::new(0, 4, 7);
+
+        assert_eq!(0, grid.sum());
+        assert_eq!(1, grid.product());
+        assert_eq!(None, grid.min_value());
+        assert_eq!(None, grid.max_value());
+        assert_eq!(None, Grid::new(3, 0, 1u8).mean());
+        assert_eq!(None, Grid::new(2, 2, f64::NAN).max_value());
+    }
+
+    #[test]
+    fn test_row_and_col_sums() {
+        let grid = labeled(4, 3);
+        let row_sums = grid.row_sums();
+        let col_sums = grid.col_sums();
+
+        assert_eq!(vec![6, 22, 38], row_sums);
+        assert_eq!(vec![12, 15, 18, 21], col_sums);
+        assert_eq!(grid.sum(), row_sums.iter().sum::<usize>());
+        assert_eq!(grid.sum(), col_sums.iter().sum::<usize>());
+        assert_eq!(col_sums, grid.transpose().row_sums());
+    }
+
+    #[test]
+    fn test_row_and_col_sums_empty() {
+        let no_cols: Grid<f32> = Grid::new(0, 2, 1.0);
+        let no_rows: Grid<f32> = Grid::new(3, 0, 1.0);
+
+        assert_eq!(vec![0.0, 0.0], no_cols.row_sums());
+        assert!(no_cols.col_sums().is_empty());
+        assert!(no_rows.row_sums().is_empty());
+        assert_eq!(vec![0.0; 3], no_rows.col_sums());
+    }
+
+    #[test]
+    fn test_fold_rows_max() {
+        let grid = labeled(4, 3).map(|&cell| (cell * 7) % 5);
+
+        assert_eq!(
+            vec![4, 4, 3],
+            grid.fold_rows(|| 0, |best, _, &cell| best.max(cell))
+        );
+        assert_eq!(
+            grid.row_sums(),
+            grid.fold_rows(|| 0, |sum, _, &cell| sum + cell)
+        );
+    }
+
+    #[test]
+    fn test_fold_cols_argmin() {
+        let mut grid = Grid::new(3, 4, 9);
+        grid.set((0, 2), 1);
+        grid.set((1, 1), 1);
+        grid.set((1, 3), 0);
+
+        // The first row holding each column's minimum.
+        let argmin = grid.fold_cols(
+            || (usize::MAX, 0),
+            |best, (_, row), &cell| if cell < best.0 { (cell, row) } else { best },
+        );
+        assert_eq!(vec![(1, 2), (0, 3), (9, 0)], argmin);
+        assert_eq!(grid.col_sums(), grid.fold_cols(|| 0, |sum, _, &cell| sum + cell));
+    }
+
+    #[test]
+    fn test_fold_empty() {
+        let no_cols = Grid::new(0, 2, 1);
+        let no_rows = Grid::new(2, 0, 1);
+
+        assert_eq!(vec![0, 0], no_cols.fold_rows(|| 0, |n, _, _| n + 1));
+        assert!(no_cols.fold_cols(|| 0, |n, _, _: &i32| n + 1).is_empty());
+        assert!(no_rows.fold_rows(|| 0, |n, _, _: &i32| n + 1).is_empty());
+        assert_eq!(vec![0, 0], no_rows.fold_cols(|| 0, |n, _, _| n + 1));
+    }
+
+    #[test]
+    fn test_cumsum() {
+        let grid = labeled(3, 4);
+
+        assert_eq!(
+            vec![0, 1, 3, 3, 7, 12, 6, 13, 21, 9, 19, 30],
+            grid.cumsum_rows().data
+        );
+        assert_eq!(
+            vec![0, 1, 2, 3, 5, 7, 9, 12, 15, 18, 22, 26],
+            grid.cumsum_cols().data
+        );
+
+        // The last column of each running total is the row's sum.
+        let last = grid.cumsum_rows().fold_rows(|| 0, |_, _, &cell| cell);
+        assert_eq!(grid.row_sums(), last);
+    }
+
+    #[test]
+    fn test_cumsum_in_place() {
+        let grid = labeled(5, 3).map(|&cell| cell as f64 * 0.5);
+
+        let mut rows = grid.clone();
+        rows.cumsum_rows_in_place();
+        assert_eq!(grid.cumsum_rows(), rows);
+
+        let mut cols = grid.clone();
+        cols.cumsum_cols_in_place();
+        assert_eq!(grid.cumsum_cols(), cols);
+    }
+
+    #[test]
+    fn test_cumsum_single_row() {
+        let grid = labeled(4, 1);
+
+        assert_eq!(vec![0, 1, 3, 6], grid.cumsum_rows().data);
+        assert_eq!(grid, grid.cumsum_cols());
+        assert_eq!(Grid::new(0, 3, 0), Grid::new(0, 3, 0).cumsum_cols());
+    }
+
+    #[test]
+    fn test_mean_no_overflow() {
+        let grid = Grid::new(100, 100, u32::MAX);
+
+        assert_eq!(Some(f64::from(u32::MAX)), grid.mean());
+    }
+
+    #[test]
+    fn test_bounding_box() {
+        let mut grid = Grid::new(5, 4, 0);
+        assert_eq!(None, grid.bounding_box(|&c| c != 0));
+
+        grid.set((3, 2), 1);
+        assert_eq!(Some(Rect::new((3, 2), 1, 1)), grid.bounding_box(|&c| c != 0));
+
+        grid.set((0, 0), 1);
+        grid.set((4, 3), 1);
+        assert_eq!(Some(grid.bounds()), grid.bounding_box(|&c| c != 0));
+    }
+
+    #[test]
+    fn test_bounding_box_l_shape() {
+        let mut grid = Grid::new(6, 6, '.');
+        for row in 1..5 {
+            grid.set((1, row), '#');
+        }
+        for col in 1..4 {
+            grid.set((col, 4), '#');
+        }
+
+        assert_eq!(Some(Rect::new((1, 1), 3, 4)), grid.bounding_box(|&c| c == '#'));
+    }
+
+    #[test]
+    fn test_from_blocks() {
+        let mut blocks = Grid::new(2, 2, Grid::new(0, 0, 0));
+        blocks.set((0, 0), labeled(1, 2));
+        blocks.set((1, 0), Grid::new(2, 2, 7));
+        blocks.set((0, 1), Grid::new(1, 1, 8));
+        blocks.set((1, 1), Grid::new(2, 1, 9));
+        let grid = Grid::from_blocks(&blocks).unwrap();
+
+        assert_eq!((3, 3), (grid.col_count(), grid.row_count()));
+        assert_eq!(vec![0, 7, 7, 1, 7, 7, 8, 9, 9], grid.data);
+    }
+
+    #[test]
+    fn test_from_blocks_incompatible() {
+        let mut blocks = Grid::new(2, 2, Grid::new(2, 2, 0));
+        blocks.set((1, 1), Grid::new(2, 3, 0));
+
+        assert_eq!(
+            Err(GridError::IncompatibleBlock { block: (1, 1) }),
+            Grid::from_blocks(&blocks)
+        );
+
+        blocks.set((1, 1), Grid::new(2, 2, 0));
+        blocks.set((0, 1), Grid::new(1, 2, 0));
+        assert_eq!(
+            Err(GridError::IncompatibleBlock { block: (0, 1) }),
+            Grid::from_blocks(&blocks)
+        );
+    }
+
+    #[test]
+    fn test_from_blocks_single() {
+        let blocks = Grid::new(1, 1, labeled(3, 2));
+
+        assert_eq!(Ok(labeled(3, 2)), Grid::from_blocks(&blocks));
+        assert_eq!(
+            Ok(Grid::new(0, 0, 0)),
+            Grid::from_blocks(&Grid::new(0, 0, labeled(1, 1)))
+        );
+    }
+
+    #[test]
+    fn test_from_blocks_empty() {
+        let empty = Ok(Grid::new(0, 0, 0));
+
+        assert_eq!(empty, Grid::from_blocks(&Grid::new(3, 0, labeled(2, 2))));
+        assert_eq!(empty, Grid::from_blocks(&Grid::new(0, 3, labeled(2, 2))));
+    }
+
+    #[test]
+    fn test_trim_touching_edge() {
+        let mut grid = Grid::new(5, 4, 0);
+        grid.set((4, 1), 1);
+        grid.set((2, 2), 2);
+        let (trimmed, offset) = grid.trim(|&c| c == 0);
+
+        assert_eq!((3, 2), (trimmed.col_count(), trimmed.row_count()));
+        assert_eq!(vec![0, 0, 1, 2, 0, 0], trimmed.data);
+        assert_eq!(Offset { col_offset: 2, row_offset: 1 }, offset);
+    }
+
+    #[test]
+    fn test_trim_single_cell() {
+        let mut grid = Grid::new(3, 3, 0);
+        grid.set((0, 2), 7);
+        let (trimmed, offset) = grid.trim(|&c| c == 0);
+
+        assert_eq!(Grid::new(1, 1, 7), trimmed);
+        assert_eq!(Offset { col_offset: 0, row_offset: 2 }, offset);
+    }
+
+    #[test]
+    fn test_trim_all_background() {
+        let (trimmed, offset) = Grid::new(3, 2, 0).trim(|&c| c == 0);
+
+        assert_eq!(Grid::new(0, 0, 0), trimmed);
+        assert_eq!(Offset::default(), offset);
+    }
+
+    #[test]
+    fn test_rect_intersection() {
+        let a = Rect::new((1, 1), 3, 3);
+
+        assert_eq!(a, a.intersection(Rect::new((0, 0), 10, 10)));
+        assert_eq!(Rect::new((3, 2), 1, 2), a.intersection(Rect::new((3, 2), 4, 4)));
+        assert!(a.intersection(Rect::new((4, 0), 2, 2)).is_empty());
+        assert!(a.contains((3, 3)));
+        assert!(!a.contains((4, 3)));
+    }
+
+    #[test]
+    fn test_downsample_max() {
+        //  0  1  2  3
+        //  4  5  6  7
+        //  8  9 10 11
+        // 12 13 14 15
+        let grid = labeled(4, 4);
+        let pooled = grid.downsample(2, 2, |block| *block.iter().max().unwrap());
+
+        assert_eq!(2, pooled.col_count());
+        assert_eq!(2, pooled.row_count());
+        assert_eq!(vec![5, 7, 13, 15], pooled.data);
+    }
+
+    #[test]
+    fn test_downsample_mean() {
+        let grid = Grid {
+            col_count: 4,
+            row_count: 2,
+            data: vec![1.0, 3.0, 2.0, 2.0, 5.0, 7.0, 2.0, 6.0],
+        };
+        let averaged = grid.downsample(2, 2, |block| {
+            block.iter().sum::<f64>() / (block.col_count() * block.row_count()) as f64
+        });
+
+        assert_eq!(vec![4.0, 3.0], averaged.data);
+    }
+
+    #[test]
+    fn test_downsample_ragged() {
+        let grid = labeled(5, 3);
+        let blocks = grid.downsample(2, 2, |block| {
+            (block.col_count(), block.row_count(), block.iter().sum::<usize>())
+        });
+
+        assert_eq!(3, blocks.col_count());
+        assert_eq!(2, blocks.row_count());
+        assert_eq!(
+            vec![
+                (2, 2, 12),
+                (2, 2, 20),
+                (1, 2, 13),
+                (2, 1, 21),
+                (2, 1, 25),
+                (1, 1, 14),
+            ],
+            blocks.data
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "downsample factors must be non-zero")]
+    fn test_downsample_zero_factor() {
+        labeled(2, 2).downsample(1, 0, |_| ());
+    }
+
+    #[test]
+    fn test_rotate_row() {
+        let mut grid = labeled(4, 2);
+
+        grid.rotate_row(1, 1).unwrap();
+        assert_eq!(vec![0, 1, 2, 3, 7, 4, 5, 6], grid.data);
+
+        grid.rotate_row(1, -1).unwrap();
+        assert_eq!(labeled(4, 2), grid);
+
+        grid.rotate_row(0, 9).unwrap();
+        assert_eq!(vec![3, 0, 1, 2, 4, 5, 6, 7], grid.data);
+
+        assert_eq!(
+            Err(GridError::RowOutOfBounds { row: 2, row_count: 2 }),
+            grid.rotate_row(2, 1)
+        );
+    }
+
+    #[test]
+    fn test_rotate_col() {
+        let mut grid = labeled(2, 4);
+
+        grid.rotate_col(1, 1).unwrap();
+        assert_eq!(vec![0, 7, 2, 1, 4, 3, 6, 5], grid.data);
+
+        grid.rotate_col(1, -1).unwrap();
+        assert_eq!(labeled(2, 4), grid);
+
+        grid.rotate_col(0, -9).unwrap();
+        assert_eq!(vec![2, 1, 4, 3, 6, 5, 0, 7], grid.data);
+
+        assert_eq!(
+            Err(GridError::ColOutOfBounds { col: 2, col_count: 2 }),
+            grid.rotate_col(2, 1)
+        );
+    }
+
+    #[test]
+    fn test_rotate_col_single_row() {
+        let mut grid = labeled(3, 1);
+
+        grid.rotate_col(1, 5).unwrap();
+        grid.rotate_col(2, -3).unwrap();
+
+        assert_eq!(labeled(3, 1), grid);
+    }
+
+    #[test]
+    fn test_roll() {
+        let mut grid = labeled(3, 3);
+
+        grid.roll(Offset::from((1, 2)));
+        assert_eq!(vec![5, 3, 4, 8, 6, 7, 2, 0, 1], grid.data);
+        assert_eq!(Some(&0), grid.get((1, 2)));
+        assert_eq!(Some(&8), grid.get((0, 1)));
+
+        grid.roll(Offset::from((-1, -2)));
+        assert_eq!(labeled(3, 3), grid);
+    }
+
+    #[test]
+    fn test_roll_identity() {
+        let mut grid = labeled(4, 3);
+
+        grid.roll(Offset::from((4, 3)));
+        assert_eq!(labeled(4, 3), grid);
+
+        grid.roll(Offset::from((-8, 9)));
+        assert_eq!(labeled(4, 3), grid);
+
+        grid.roll(Offset::from((1, 0)));
+        grid.roll(Offset::from((-1, 0)));
+        assert_eq!(labeled(4, 3), grid);
+    }
+
+    #[test]
+    fn test_shift() {
+        let mut grid = labeled(4, 3);
+
+        grid.shift(Offset::from((1, 1)), 99);
+        assert_eq!(
+            vec![99, 99, 99, 99, 99, 0, 1, 2, 99, 4, 5, 6],
+            grid.data
+        );
+
+        let mut grid = labeled(4, 3);
+
+        grid.shift(Offset::from((-2, -1)), 99);
+        assert_eq!(
+            vec![6, 7, 99, 99, 10, 11, 99, 99, 99, 99, 99, 99],
+            grid.data
+        );
+    }
+
+    #[test]
+    fn test_shift_overlarge() {
+        let mut grid = labeled(4, 3);
+        grid.shift(Offset::from((0, 3)), 99);
+        assert_eq!(Grid::new(4, 3, 99), grid);
+
+        let mut grid = labeled(4, 3);
+        grid.shift(Offset::from((-7, 1)), 99);
+        assert_eq!(Grid::new(4, 3, 99), grid);
+    }
+
+    #[test]
+    fn test_sort_rows_by_key() {
+        let mut grid = Grid {
+            col_count: 3,
+            row_count: 4,
+            data: vec![3, 0, 0, 1, 9, 9, 2, 1, 1, 0, 5, 0],
+        };
+
+        grid.sort_rows_by_key(|row| row[0]);
+        assert_eq!(vec![0, 5, 0, 1, 9, 9, 2, 1, 1, 3, 0, 0], grid.data);
+
+        grid.sort_rows_by_key(|row| row.iter().sum::<i32>());
+        assert_eq!(vec![3, 0, 0, 2, 1, 1, 0, 5, 0, 1, 9, 9], grid.data);
+    }
+
+    #[test]
+    fn test_sort_rows_stable() {
+        let mut grid = Grid {
+            col_count: 2,
+            row_count: 4,
+            data: vec![1, 0, 0, 1, 1, 2, 0, 3],
+        };
+
+        grid.sort_rows_by(|a, b| a[0].cmp(&b[0]));
+        assert_eq!(vec![0, 1, 0, 3, 1, 0, 1, 2], grid.data);
+
+        grid.sort_rows_by(|a, b| b[0].cmp(&a[0]));
+        assert_eq!(vec![1, 0, 1, 2, 0, 1, 0, 3], grid.data);
+    }
+
+    #[test]
+    fn test_permute_rows() {
+        let mut grid = labeled(2, 4);
+
+        grid.permute_rows(&[0, 1, 2, 3]).unwrap();
+        assert_eq!(labeled(2, 4), grid);
+
+        grid.permute_rows(&[3, 2, 1, 0]).unwrap();
+        assert_eq!(vec![6, 7, 4, 5, 2, 3, 0, 1], grid.data);
+
+        let mut grid = labeled(2, 4);
+
+        grid.permute_rows(&[1, 3, 0, 2]).unwrap();
+        assert_eq!(vec![2, 3, 6, 7, 0, 1, 4, 5], grid.data);
+    }
+
+    #[test]
+    fn test_permute_cols() {
+        let mut grid = labeled(3, 2);
+
+        grid.permute_cols(&[0, 1, 2]).unwrap();
+        assert_eq!(labeled(3, 2), grid);
+
+        grid.permute_cols(&[2, 1, 0]).unwrap();
+        assert_eq!(vec![2, 1, 0, 5, 4, 3], grid.data);
+
+        let mut grid = labeled(3, 2);
+
+        grid.permute_cols(&[1, 2, 0]).unwrap();
+        assert_eq!(vec![1, 2, 0, 4, 5, 3], grid.data);
+    }
+
+    #[test]
+    fn test_permute_invalid() {
+        let mut grid = labeled(3, 2);
+        let err = GridError::InvalidPermutation { len: 3 };
+
+        assert_eq!(Err(err.clone()), grid.permute_cols(&[0, 0, 1]));
+        assert_eq!(Err(err.clone()), grid.permute_cols(&[0, 1, 3]));
+        assert_eq!(Err(err), grid.permute_cols(&[0, 1]));
+        assert_eq!(
+            Err(GridError::InvalidPermutation { len: 2 }),
+            grid.permute_rows(&[1, 1])
+        );
+        assert_eq!(labeled(3, 2), grid);
+    }
+
+    #[test]
+    fn test_swap_regions_adjacent() {
+        let mut grid = labeled(4, 3);
+        grid.swap_regions(Rect::new((1, 0), 1, 2), Rect::new((2, 0), 1, 2))
+            .unwrap();
+
+        assert_eq!(vec![0, 2, 1, 3, 4, 6, 5, 7, 8, 9, 10, 11], grid.data);
+    }
+
+    #[test]
+    fn test_swap_regions_far_apart() {
+        let mut grid = labeled(5, 4);
+        let a = Rect::new((0, 0), 2, 2);
+        let b = Rect::new((3, 2), 2, 2);
+        grid.swap_regions(a, b).unwrap();
+
+        let original = labeled(5, 4);
+        assert_eq!(Ok(true), grid.region_eq(a, &original, b.origin));
+        assert_eq!(Ok(true), grid.region_eq(b, &original, a.origin));
+
+        for ((col, row), &cell) in grid.indexed_iter() {
+            if !a.contains((col, row)) && !b.contains((col, row)) {
+                assert_eq!(col + 5 * row, cell);
+            }
+        }
+
+        // Swapping back restores the grid.
+        grid.swap_regions(b, a).unwrap();
+        assert_eq!(original, grid);
+    }
+
+    #[test]
+    fn test_swap_regions_invalid() {
+        let mut grid = labeled(4, 4);
+
+        assert_eq!(
+            Err(GridError::RegionsOverlap {
+                overlap: Rect::new((1, 1), 1, 2)
+            }),
+            grid.swap_regions(Rect::new((0, 0), 2, 3), Rect::new((1, 1), 2, 3))
+        );
+        assert_eq!(
+            Err(GridError::DimensionMismatch {
+                expected: (2, 1),
+                found: (1, 2)
+            }),
+            grid.swap_regions(Rect::new((0, 0), 2, 1), Rect::new((3, 2), 1, 2))
+        );
+        assert_eq!(
+            Err(GridError::ColOutOfBounds { col: 4, col_count: 4 }),
+            grid.swap_regions(Rect::new((0, 0), 2, 2), Rect::new((3, 0), 2, 2))
+        );
+        assert_eq!(labeled(4, 4), grid);
+    }
+
+    #[test]
+    fn test_push_row() {
+        let mut grid = labeled(2, 1);
+        grid.push_row(vec![7, 8]).unwrap();
+
+        assert_eq!((2, 2), (grid.col_count(), grid.row_count()));
+        assert_eq!(vec![0, 1, 7, 8], grid.data);
+    }
+
+    #[test]
+    fn test_push_row_empty_sets_width() {
+        let mut grid = Grid::new(0, 0, 0);
+        grid.push_row(vec![1, 2, 3]).unwrap();
+
+        assert_eq!((3, 1), (grid.col_count(), grid.row_count()));
+        assert_eq!(vec![1, 2, 3], grid.data);
+    }
+
+    #[test]
+    fn test_push_row_length_mismatch() {
+        let mut grid = labeled(2, 2);
+
+        assert_eq!(
+            Err(GridError::LengthMismatch {
+                expected: 2,
+                found: 3
+            }),
+            grid.push_row(vec![1, 2, 3])
+        );
+        assert_eq!(labeled(2, 2), grid);
+    }
+
+    #[test]
+    fn test_pop_row() {
+        let mut grid = labeled(2, 2);
+
+        assert_eq!(Some(vec![2, 3]), grid.pop_row());
+        assert_eq!(Some(vec![0, 1]), grid.pop_row());
+        assert_eq!(None, grid.pop_row());
+        assert_eq!((2, 0), (grid.col_count(), grid.row_count()));
+        assert!(grid.push_row(vec![1]).is_err());
+        assert!(grid.push_row(vec![1, 2]).is_ok());
+    }
+
+    #[test]
+    fn test_reserve_rows() {
+        let mut grid = labeled(3, 2);
+        grid.reserve_rows(100);
+        let capacity = grid.capacity_rows();
+
+        assert!(capacity >= 102);
+        for _ in 0..100 {
+            grid.push_row(vec![0; 3]).unwrap();
+            assert_eq!(capacity, grid.capacity_rows());
+        }
+
+        grid.drain_rows(2..);
+        grid.shrink_to_fit();
+        assert!(grid.capacity_rows() < capacity);
+    }
+
+    #[test]
+    fn test_capacity_rows_zero_width() {
+        let grid: Grid<usize> = Grid::new(0, 3, 0);
+
+        assert_eq!(usize::MAX, grid.capacity_rows());
+    }
+
+    #[test]
+    fn test_insert_row() {
+        let mut grid = labeled(2, 2);
+
+        grid.insert_row(0, vec![7, 7]).unwrap();
+        assert_eq!(vec![7, 7, 0, 1, 2, 3], grid.data);
+
+        grid.insert_row(2, vec![8, 8]).unwrap();
+        assert_eq!(vec![7, 7, 0, 1, 8, 8, 2, 3], grid.data);
+
+        grid.insert_row(4, vec![9, 9]).unwrap();
+        assert_eq!(vec![7, 7, 0, 1, 8, 8, 2, 3, 9, 9], grid.data);
+        assert_eq!(5, grid.row_count());
+    }
+
+    #[test]
+    fn test_insert_row_invalid() {
+        let mut grid = labeled(2, 2);
 
-        assert_eq!(Some(&'a'), grid.get((2, 3)));
+        assert_eq!(
+            Err(GridError::RowOutOfBounds {
+                row: 3,
+                row_count: 2
+            }),
+            grid.insert_row(3, vec![7, 7])
+        );
+        assert_eq!(
+            Err(GridError::LengthMismatch {
+                expected: 2,
+                found: 1
+            }),
+            grid.insert_row(1, vec![7])
+        );
+        assert_eq!(labeled(2, 2), grid);
+    }
 
-        grid.set((2, 3), 'b');
+    #[test]
+    fn test_remove_row() {
+        let mut grid = labeled(2, 4);
 
-        assert_eq!(Some(&'b'), grid.rget((2, 4), Offset::NORTH));
-        assert_eq!(Some(&mut 'b'), grid.rget_mut((2, 4), Offset::NORTH));
+        assert_eq!(Some(vec![0, 1]), grid.remove_row(0));
+        assert_eq!(vec![2, 3, 4, 5, 6, 7], grid.data);
+
+        assert_eq!(Some(vec![4, 5]), grid.remove_row(1));
+        assert_eq!(vec![2, 3, 6, 7], grid.data);
+
+        assert_eq!(Some(vec![6, 7]), grid.remove_row(1));
+        assert_eq!(vec![2, 3], grid.data);
+        assert_eq!((2, 1), (grid.col_count(), grid.row_count()));
     }
 
     #[test]
-    fn test_rset() {
-        let mut grid = Grid::new(5, 5, 'a');
+    fn test_remove_row_single_and_invalid() {
+        let mut grid = labeled(3, 1);
 
-        assert_eq!(Some(&'a'), grid.get((2, 3)));
+        assert_eq!(None, grid.remove_row(1));
+        assert_eq!(labeled(3, 1), grid);
+        assert_eq!(Some(vec![0, 1, 2]), grid.remove_row(0));
+        assert_eq!((3, 0), (grid.col_count(), grid.row_count()));
+        assert_eq!(None, grid.remove_row(0));
+    }
 
-        grid.rset((1, 1), Offset::from((1, 2)), 'b');
+    #[test]
+    fn test_swap_remove_row() {
+        let mut grid = labeled(2, 4);
 
-        assert_eq!(Some(&'b'), grid.rget((2, 4), Offset::NORTH));
-        assert_eq!(Some(&mut 'b'), grid.rget_mut((2, 4), Offset::NORTH));
+        assert_eq!(Some(vec![2, 3]), grid.swap_remove_row(1));
+        assert_eq!(vec![0, 1, 6, 7, 4, 5], grid.data);
+
+        assert_eq!(Some(vec![4, 5]), grid.swap_remove_row(2));
+        assert_eq!(vec![0, 1, 6, 7], grid.data);
+
+        assert_eq!(None, grid.swap_remove_row(2));
+        assert_eq!(2, grid.row_count());
     }
 
     #[test]
-    fn test_transpose() {
-        let src_col = 3;
-        let src_row = 4;
+    fn test_swap_remove_row_single() {
+        let mut grid = labeled(3, 1);
 
-        let mut grid = Grid::new(src_col, src_row, (0, 0));
+        assert_eq!(Some(vec![0, 1, 2]), grid.swap_remove_row(0));
+        assert_eq!((3, 0), (grid.col_count(), grid.row_count()));
+    }
 
-        for i in 0..src_col {
-            for j in 0..src_row {
-                grid.set((i, j), (i, j));
-            }
+    #[test]
+    fn test_drain_rows() {
+        let mut grid = labeled(2, 4);
+        let drained: Vec<_> = grid.drain_rows(1..3).collect();
+
+        assert_eq!(vec![vec![2, 3], vec![4, 5]], drained);
+        assert_eq!((2, 2), (grid.col_count(), grid.row_count()));
+        assert_eq!(vec![0, 1, 6, 7], grid.data);
+    }
+
+    #[test]
+    fn test_drain_rows_dropped_early() {
+        let mut grid = labeled(2, 5);
+        let mut drain = grid.drain_rows(1..=3);
+
+        assert_eq!(3, drain.len());
+        assert_eq!(Some(vec![6, 7]), drain.next_back());
+        assert_eq!(Some(vec![2, 3]), drain.next());
+        drop(drain);
+
+        assert_eq!((2, 2), (grid.col_count(), grid.row_count()));
+        assert_eq!(vec![0, 1, 8, 9], grid.data);
+    }
+
+    #[test]
+    fn test_drain_rows_empty_range() {
+        let mut grid = labeled(2, 3);
+
+        assert_eq!(0, grid.drain_rows(2..2).count());
+        assert_eq!(labeled(2, 3), grid);
+        assert_eq!(3, grid.drain_rows(..).count());
+        assert_eq!((2, 0), (grid.col_count(), grid.row_count()));
+    }
+
+    #[test]
+    fn test_drain_rows_leaked() {
+        let mut grid = labeled(2, 4);
+        std::mem::forget(grid.drain_rows(1..3));
+
+        assert_eq!(1, grid.row_count());
+        assert_eq!(vec![0, 1], grid.data);
+    }
+
+    #[test]
+    fn test_retain_rows() {
+        let mut grid = labeled(2, 4);
+        grid.retain_rows(|_, _| true);
+        assert_eq!(labeled(2, 4), grid);
+
+        let mut seen = Vec::new();
+        grid.retain_rows(|row, cells| {
+            seen.push((row, cells[0]));
+            row % 2 == 1
+        });
+        assert_eq!(vec![(0, 0), (1, 2), (2, 4), (3, 6)], seen);
+        assert_eq!((2, 2), (grid.col_count(), grid.row_count()));
+        assert_eq!(vec![2, 3, 6, 7], grid.data);
+
+        grid.retain_rows(|_, _| false);
+        assert_eq!((2, 0), (grid.col_count(), grid.row_count()));
+        assert!(grid.data.is_empty());
+    }
+
+    #[test]
+    fn test_retain_cols() {
+        let mut grid = labeled(4, 2);
+        grid.retain_cols(|_, _| true);
+        assert_eq!(labeled(4, 2), grid);
+
+        let mut seen = Vec::new();
+        grid.retain_cols(|col, cells| {
+            seen.push((col, cells.copied().collect::<Vec<_>>()));
+            col % 2 == 0
+        });
+        assert_eq!(vec![0, 4], seen[0].1);
+        assert_eq!(vec![3, 7], seen[3].1);
+        assert_eq!(vec![0, 1, 2, 3], seen.iter().map(|s| s.0).collect::<Vec<_>>());
+        assert_eq!((2, 2), (grid.col_count(), grid.row_count()));
+        assert_eq!(vec![0, 2, 4, 6], grid.data);
+
+        grid.retain_cols(|_, _| false);
+        assert_eq!((0, 2), (grid.col_count(), grid.row_count()));
+        assert!(grid.data.is_empty());
+    }
+
+    #[test]
+    fn test_split_at_row() {
+        let (top, bottom) = labeled(2, 3).split_at_row(1);
+
+        assert_eq!((2, 1), (top.col_count(), top.row_count()));
+        assert_eq!(vec![0, 1], top.data);
+        assert_eq!((2, 2), (bottom.col_count(), bottom.row_count()));
+        assert_eq!(vec![2, 3, 4, 5], bottom.data);
+    }
+
+    #[test]
+    fn test_split_at_row_edges() {
+        let (top, bottom) = labeled(2, 3).split_at_row(0);
+        assert_eq!((2, 0), (top.col_count(), top.row_count()));
+        assert_eq!(labeled(2, 3), bottom);
+
+        let (top, bottom) = labeled(2, 3).split_at_row(3);
+        assert_eq!(labeled(2, 3), top);
+        assert_eq!((2, 0), (bottom.col_count(), bottom.row_count()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_at_row_out_of_bounds() {
+        labeled(2, 3).split_at_row(4);
+    }
+
+    #[test]
+    fn test_split_at_col() {
+        let (left, right) = labeled(3, 2).split_at_col(1);
+
+        assert_eq!((1, 2), (left.col_count(), left.row_count()));
+        assert_eq!(vec![0, 3], left.data);
+        assert_eq!((2, 2), (right.col_count(), right.row_count()));
+        assert_eq!(vec![1, 2, 4, 5], right.data);
+    }
+
+    #[test]
+    fn test_split_at_col_edges() {
+        let (left, right) = labeled(3, 2).split_at_col(0);
+        assert_eq!((0, 2), (left.col_count(), left.row_count()));
+        assert_eq!(labeled(3, 2), right);
+
+        let (left, right) = labeled(3, 2).split_at_col(3);
+        assert_eq!(labeled(3, 2), left);
+        assert_eq!((0, 2), (right.col_count(), right.row_count()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_at_col_out_of_bounds() {
+        labeled(3, 2).split_at_col(4);
+    }
+
+    #[test]
+    fn test_concat_horizontal() {
+        let left = labeled(1, 2);
+        let right = labeled(3, 2);
+        let expected = vec![0, 0, 1, 2, 1, 3, 4, 5];
+
+        assert_eq!(expected, left.concat_horizontal(&right).unwrap().data);
+        assert_eq!(expected, left.into_concat_horizontal(right).unwrap().data);
+    }
+
+    #[test]
+    fn test_concat_horizontal_mismatch() {
+        let err = GridError::DimensionMismatch {
+            expected: (2, 3),
+            found: (2, 2),
+        };
+
+        assert_eq!(Err(err.clone()), labeled(1, 3).concat_horizontal(&labeled(2, 2)));
+        assert_eq!(Err(err), labeled(1, 3).into_concat_horizontal(labeled(2, 2)));
+    }
+
+    #[test]
+    fn test_concat_horizontal_empty() {
+        let empty = Grid::new(0, 2, 0);
+
+        assert_eq!(Ok(labeled(3, 2)), labeled(3, 2).concat_horizontal(&empty));
+        assert_eq!(Ok(labeled(3, 2)), empty.into_concat_horizontal(labeled(3, 2)));
+    }
+
+    #[test]
+    fn test_split_at_col_round_trip() {
+        for col in 0..=4 {
+            let (left, right) = labeled(4, 3).split_at_col(col);
+
+            assert_eq!(Ok(labeled(4, 3)), left.concat_horizontal(&right));
         }
+    }
 
-        let tgrid = grid.transpose();
+    #[test]
+    fn test_concat_vertical() {
+        let top = labeled(2, 1);
+        let bottom = labeled(2, 3);
+        let grid = top.concat_vertical(&bottom).unwrap();
 
-        assert_eq!(4, tgrid.col_count());
-        assert_eq!(3, tgrid.row_count());
+        assert_eq!((2, 4), (grid.col_count(), grid.row_count()));
+        for ((col, row), cell) in bottom.indexed_iter() {
+            assert_eq!(Some(cell), grid.get((col, row + 1)));
+        }
+        assert_eq!(Ok(grid), top.into_concat_vertical(bottom));
+    }
 
-        for i in 0..src_col {
-            for j in 0..src_row {
-                assert_eq!(tgrid.get((j, i)), grid.get((i, j)));
+    #[test]
+    fn test_concat_vertical_mismatch() {
+        let err = GridError::DimensionMismatch {
+            expected: (3, 2),
+            found: (2, 2),
+        };
+
+        assert_eq!(Err(err.clone()), labeled(3, 1).concat_vertical(&labeled(2, 2)));
+        assert_eq!(Err(err), labeled(3, 1).into_concat_vertical(labeled(2, 2)));
+    }
+
+    #[test]
+    fn test_concat_vertical_empty() {
+        let empty = Grid::new(3, 0, 0);
+
+        assert_eq!(Ok(labeled(3, 2)), labeled(3, 2).concat_vertical(&empty));
+        assert_eq!(Ok(labeled(3, 2)), empty.into_concat_vertical(labeled(3, 2)));
+    }
+
+    #[test]
+    fn test_split_at_row_round_trip() {
+        for row in 0..=3 {
+            let (top, bottom) = labeled(4, 3).split_at_row(row);
+
+            assert_eq!(Ok(labeled(4, 3)), top.into_concat_vertical(bottom));
+        }
+    }
+
+    /// Check that every cell of a labeled grid lands in exactly one quadrant
+    /// at the right local coordinate.
+    fn check_quadrants(col_count: usize, row_count: usize) {
+        let quadrants = labeled(col_count, row_count).split_into_quadrants();
+        let (mid_col, mid_row) = (col_count / 2, row_count / 2);
+        let origins = [(0, 0), (mid_col, 0), (0, mid_row), (mid_col, mid_row)];
+        let mut seen = vec![0; col_count * row_count];
+
+        for (quadrant, origin) in quadrants.iter().zip(origins.iter()) {
+            for ((col, row), &cell) in quadrant.indexed_iter() {
+                assert_eq!(cell, origin.0 + col + col_count * (origin.1 + row));
+                seen[cell] += 1;
             }
         }
+
+        assert!(seen.iter().all(|&count| count == 1));
+    }
+
+    #[test]
+    fn test_split_into_quadrants_even() {
+        let [nw, ne, sw, se] = labeled(4, 2).split_into_quadrants();
+
+        assert_eq!(vec![0, 1], nw.data);
+        assert_eq!(vec![2, 3], ne.data);
+        assert_eq!(vec![4, 5], sw.data);
+        assert_eq!(vec![6, 7], se.data);
+        check_quadrants(4, 6);
+    }
+
+    #[test]
+    fn test_split_into_quadrants_odd() {
+        let [nw, ne, sw, se] = labeled(3, 3).split_into_quadrants();
+
+        assert_eq!((1, 1), (nw.col_count(), nw.row_count()));
+        assert_eq!((2, 1), (ne.col_count(), ne.row_count()));
+        assert_eq!((1, 2), (sw.col_count(), sw.row_count()));
+        assert_eq!((2, 2), (se.col_count(), se.row_count()));
+        check_quadrants(5, 7);
+    }
+
+    #[test]
+    fn test_split_into_quadrants_thin() {
+        let [nw, ne, sw, se] = labeled(1, 4).split_into_quadrants();
+
+        assert_eq!((0, 2), (nw.col_count(), nw.row_count()));
+        assert_eq!(vec![0, 1], ne.data);
+        assert_eq!((0, 2), (sw.col_count(), sw.row_count()));
+        assert_eq!(vec![2, 3], se.data);
+        check_quadrants(1, 5);
+        check_quadrants(0, 0);
+    }
+
+    #[test]
+    fn test_to_rows_and_columns() {
+        let grid = labeled(3, 2);
+
+        assert_eq!(vec![vec![0, 1, 2], vec![3, 4, 5]], grid.clone_rows());
+        assert_eq!(vec![vec![0, 3], vec![1, 4], vec![2, 5]], grid.clone_columns());
+        assert_eq!(grid.clone_rows(), grid.clone().to_rows());
+        assert_eq!(grid.clone_columns(), grid.clone().to_columns());
+        assert_eq!(grid.clone_rows(), Vec::<Vec<usize>>::from(grid));
+
+        let no_cols: Grid<usize> = Grid::new(0, 2, 0);
+        assert_eq!(vec![Vec::<usize>::new(); 2], no_cols.clone_rows());
+        assert_eq!(no_cols.clone_rows(), no_cols.clone().to_rows());
+        assert!(no_cols.to_columns().is_empty());
+    }
+
+    #[test]
+    fn test_to_rows_without_clone() {
+        #[derive(Debug, PartialEq)]
+        struct Token(usize);
+
+        let tokens = || labeled(2, 2).map(|&cell| Token(cell));
+        assert_eq!(vec![vec![Token(0), Token(1)], vec![Token(2), Token(3)]], tokens().to_rows());
+        let columns = tokens().to_columns();
+        assert_eq!(vec![vec![Token(0), Token(2)], vec![Token(1), Token(3)]], columns);
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let grid = labeled(4, 3);
+
+        assert_eq!(Ok(grid.clone()), Grid::from_rows(grid.clone().to_rows()));
+        assert_eq!(Ok(Grid::new(0, 0, 0)), Grid::<u8>::from_rows(Vec::new()));
+        assert_eq!(
+            Err(GridError::LengthMismatch {
+                expected: 2,
+                found: 3
+            }),
+            Grid::from_rows(vec![vec![1, 2], vec![3, 4], vec![5, 6, 7]])
+        );
+    }
+
+    #[test]
+    fn test_eq_nested_vecs() {
+        let grid = labeled(2, 2);
+
+        assert_eq!(grid, vec![vec![0, 1], vec![2, 3]]);
+        assert_eq!(vec![vec![0, 1], vec![2, 3]], grid);
+        assert_ne!(grid, vec![vec![0, 1], vec![2, 4]]);
+        assert_ne!(grid, vec![vec![0, 1, 2, 3]]);
+        assert_ne!(grid, vec![vec![0], vec![1], vec![2], vec![3]]);
+        assert_ne!(grid, vec![vec![0, 1], vec![2, 3], vec![]]);
+        assert_ne!(grid, vec![vec![0, 1], vec![2]]);
+        assert_ne!(vec![vec![0, 1, 2], vec![3]], labeled(2, 2));
+
+        assert_eq!(Grid::new(3, 0, 0), Vec::<Vec<usize>>::new());
+        assert_eq!(Grid::new(0, 2, 0), vec![Vec::<usize>::new(); 2]);
+    }
+
+    #[test]
+    fn test_eq_nested_slices() {
+        let grid = labeled(3, 2);
+        let rows: &[&[usize]] = &[&[0, 1, 2], &[3, 4, 5]];
+        let ragged: &[&[usize]] = &[&[0, 1, 2], &[3, 4]];
+
+        assert_eq!(grid, rows);
+        assert_eq!(rows, grid);
+        assert_ne!(grid, ragged);
+        assert_ne!(ragged, grid);
+    }
+
+    #[test]
+    fn test_eq_arrays() {
+        let grid = labeled(3, 2);
+
+        assert_eq!(grid, [[0, 1, 2], [3, 4, 5]]);
+        assert_eq!([[0, 1, 2], [3, 4, 5]], grid);
+        assert_ne!(grid, [[0, 1, 2], [3, 4, 6]]);
+        assert_ne!(grid, [[0, 1], [2, 3], [4, 5]]);
+        assert_ne!(grid, [[0, 1, 2, 3, 4, 5]]);
+        assert_eq!(grid.map(|&cell| cell as f64), [[0.0, 1.0, 2.0], [3.0, 4.0, 5.0]]);
+    }
+
+    #[test]
+    fn test_extend_updates() {
+        let mut grid = Grid::new(3, 2, 0);
+        grid.extend(vec![((1, 0), 1), ((2, 1), 2), ((1, 0), 3)]);
+        assert_eq!(grid, [[0, 3, 0], [0, 0, 2]]);
+
+        let mut copy = Grid::new(3, 2, 0);
+        copy.extend(grid.indexed_iter().filter(|(_, &cell)| cell > 2).map(|(c, &v)| (c, v)));
+        assert_eq!(copy, [[0, 3, 0], [0, 0, 0]]);
+    }
+
+    #[test]
+    fn test_extend_out_of_bounds() {
+        let mut grid = Grid::new(2, 2, 0);
+        grid.extend(vec![((2, 0), 1), ((0, 2), 2), ((1, 1), 3), ((usize::MAX, 0), 4)]);
+
+        assert_eq!(grid, [[0, 0], [0, 3]]);
+    }
+
+    #[test]
+    fn test_extend_rows() {
+        let mut grid = Grid::new(0, 0, 0);
+        grid.extend("1 2 3\n4 5 6\n7 8 9".lines().map(|line| {
+            line.split(' ').map(|n| n.parse().unwrap()).collect::<Vec<u32>>()
+        }));
+        assert_eq!(grid, [[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+
+        grid.extend(Vec::<Vec<u32>>::new());
+        assert_eq!(3, grid.row_count());
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot extend a grid with a row")]
+    fn test_extend_ragged_rows() {
+        let mut grid = labeled(2, 1);
+        grid.extend(vec![vec![1, 2], vec![3]]);
+    }
+
+    #[test]
+    fn test_sparse_round_trip() {
+        let mut grid = Grid::new(5, 4, '.');
+        grid.set((4, 0), '#');
+        grid.set((0, 1), '@');
+        grid.set((2, 3), '#');
+
+        let sparse = grid.to_sparse_except(&'.');
+        let cells: Vec<(Coord, char)> = sparse.map(|(c, &v)| (c, v)).collect();
+        assert_eq!(vec![((4, 0), '#'), ((0, 1), '@'), ((2, 3), '#')], cells);
+        assert_eq!(grid, Grid::from_sparse(5, 4, '.', cells));
+    }
+
+    #[test]
+    fn test_sparse_background_only() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(0, grid.to_sparse(|&cell| cell == 0).count());
+        assert_eq!(0, grid.to_sparse_except(&0).count());
+        assert_eq!(9, grid.to_sparse_except(&1).count());
+    }
+
+    #[test]
+    fn test_sparse_order() {
+        let grid = labeled(4, 3);
+        let evens: Vec<Coord> = grid.to_sparse(|&cell| cell % 2 == 1).map(|(c, _)| c).collect();
+
+        assert_eq!(vec![(0, 0), (2, 0), (0, 1), (2, 1), (0, 2), (2, 2)], evens);
+        assert_eq!(grid, Grid::from_sparse(4, 3, 99, grid.indexed_iter().map(|(c, &v)| (c, v))));
+
+        let later_wins = Grid::from_sparse(2, 1, 0, vec![((1, 0), 1), ((5, 5), 9), ((1, 0), 2)]);
+        assert_eq!(later_wins, [[0, 2]]);
+    }
+
+    #[test]
+    fn test_flat_slices() {
+        let mut grid = labeled(4, 3);
+
+        for &coord in &[(0, 0), (3, 0), (1, 1), (0, 2), (3, 2)] {
+            assert_eq!(grid.get(coord), grid.as_slice().get(grid.flat_index(coord)));
+        }
+
+        grid.as_mut_slice()[6] = 60;
+        assert_eq!(Some(&60), grid.get((2, 1)));
+
+        grid.as_mut_slice().reverse();
+        assert_eq!(Some(&11), grid.get((0, 0)));
+        assert_eq!(Some(&0), grid.get((3, 2)));
+    }
+
+    #[test]
+    fn test_into_and_from_vec() {
+        let grid = labeled(5, 3);
+        let data = grid.clone().into_vec();
+
+        assert_eq!(15, data.len());
+        assert_eq!(Ok(grid), Grid::from_vec(5, 3, data));
+        assert_eq!(Ok(Grid::new(0, 4, 0)), Grid::from_vec(0, 4, Vec::new()));
+        assert_eq!(
+            Err(GridError::LengthMismatch {
+                expected: usize::MAX,
+                found: 0
+            }),
+            Grid::<u8>::from_vec(usize::MAX, 2, Vec::new())
+        );
+    }
+
+    #[test]
+    fn test_push_pop_col_round_trip() {
+        let mut grid = labeled(2, 3);
+        grid.push_col(vec![7, 8, 9]).unwrap();
+
+        assert_eq!((3, 3), (grid.col_count(), grid.row_count()));
+        assert_eq!(vec![0, 1, 7, 2, 3, 8, 4, 5, 9], grid.data);
+        assert_eq!(Some(vec![7, 8, 9]), grid.pop_col());
+        assert_eq!(labeled(2, 3), grid);
+    }
+
+    #[test]
+    fn test_push_pop_col_single_row() {
+        let mut grid = labeled(3, 1);
+        grid.push_col(vec![9]).unwrap();
+
+        assert_eq!(vec![0, 1, 2, 9], grid.data);
+        assert_eq!(Some(vec![9]), grid.pop_col());
+        assert_eq!(Some(vec![2]), grid.pop_col());
+        assert_eq!(vec![0, 1], grid.data);
+    }
+
+    #[test]
+    fn test_push_col_empty_and_mismatch() {
+        let mut grid = Grid::new(0, 0, 0);
+        grid.push_col(vec![1, 2]).unwrap();
+        assert_eq!((1, 2), (grid.col_count(), grid.row_count()));
+
+        assert_eq!(
+            Err(GridError::LengthMismatch {
+                expected: 2,
+                found: 1
+            }),
+            grid.push_col(vec![3])
+        );
+        assert_eq!(vec![1, 2], grid.data);
+
+        assert_eq!(Some(vec![1, 2]), grid.pop_col());
+        assert_eq!(None, grid.pop_col());
+        assert_eq!((0, 2), (grid.col_count(), grid.row_count()));
+    }
+
+    #[test]
+    fn test_remove_col() {
+        let mut grid = labeled(3, 2);
+
+        assert_eq!(Some(vec![0, 3]), grid.remove_col(0));
+        assert_eq!(vec![1, 2, 4, 5], grid.data);
+
+        assert_eq!(Some(vec![2, 5]), grid.remove_col(1));
+        assert_eq!(vec![1, 4], grid.data);
+        assert_eq!((1, 2), (grid.col_count(), grid.row_count()));
+
+        assert_eq!(None, grid.remove_col(1));
+        assert_eq!(vec![1, 4], grid.data);
+    }
+
+    #[test]
+    fn test_remove_col_keeps_order() {
+        let mut grid = labeled(4, 5);
+        let kept: Vec<_> = grid.data.iter().copied().filter(|cell| cell % 4 != 2).collect();
+
+        assert_eq!(Some(vec![2, 6, 10, 14, 18]), grid.remove_col(2));
+        assert_eq!(kept, grid.data);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_remove_col_matches_naive(
+            cols in 1usize..20,
+            rows in 0usize..20,
+            index in 0usize..20,
+        ) {
+            let index = index % cols;
+            let mut grid = labeled(cols, rows);
+            let (col, kept): (Vec<_>, Vec<_>) =
+                grid.data.iter().partition(|&&cell| cell % cols == index);
+
+            prop_assert_eq!(Some(col), grid.remove_col(index));
+            prop_assert_eq!(kept, grid.data);
+        }
+    }
+
+    #[test]
+    fn test_remove_col_to_zero_width() {
+        let mut grid = labeled(1, 3);
+
+        assert_eq!(Some(vec![0, 1, 2]), grid.remove_col(0));
+        assert_eq!((0, 3), (grid.col_count(), grid.row_count()));
+        assert!(!grid.contains((0, 0)));
+        assert_eq!(None, grid.get((0, 1)));
+        assert_eq!(None, grid.remove_col(0));
+    }
+
+    #[test]
+    fn test_insert_col() {
+        let mut grid = labeled(3, 2);
+
+        grid.insert_col(0, vec![7, 8]).unwrap();
+        assert_eq!(vec![7, 0, 1, 2, 8, 3, 4, 5], grid.data);
+
+        grid.insert_col(4, vec![9, 9]).unwrap();
+        assert_eq!(vec![7, 0, 1, 2, 9, 8, 3, 4, 5, 9], grid.data);
+        assert_eq!((5, 2), (grid.col_count(), grid.row_count()));
+    }
+
+    #[test]
+    fn test_insert_col_invalid() {
+        let mut grid = labeled(3, 2);
+
+        assert_eq!(
+            Err(GridError::ColOutOfBounds {
+                col: 4,
+                col_count: 3
+            }),
+            grid.insert_col(4, vec![7, 7])
+        );
+        assert!(grid.insert_col(1, vec![7, 7, 7]).is_err());
+        assert_eq!(labeled(3, 2), grid);
+    }
+
+    #[test]
+    fn test_insert_col_zero_rows() {
+        let mut grid: Grid<usize> = Grid::new(3, 0, 0);
+        grid.insert_col(1, Vec::new()).unwrap();
+
+        assert_eq!((4, 0), (grid.col_count(), grid.row_count()));
+        assert!(grid.insert_col(0, vec![1]).is_err());
+    }
+
+    #[test]
+    fn test_region_metrics_blocks() {
+        let mut labels = Grid::new(5, 4, 0);
+        labels.set((3, 1), 7);
+        labels.view_mut(Rect::new((0, 2), 2, 2)).unwrap().fill(3);
+
+        let metrics = Grid::new(5, 4, ()).region_metrics(&labels).unwrap();
+        assert_eq!(vec![0, 3, 7], metrics.iter().map(|m| m.label).collect::<Vec<_>>());
+
+        // A lone interior cell, and a 2x2 block in the corner.
+        assert_eq!(
+            RegionMetrics {
+                label: 7,
+                area: 1,
+                perimeter: 4,
+                bounds: Rect::new((3, 1), 1, 1),
+            },
+            metrics[2]
+        );
+        assert_eq!((4, 8), (metrics[1].area, metrics[1].perimeter));
+        assert_eq!(Rect::new((0, 2), 2, 2), metrics[1].bounds);
+
+        // The 14 grid boundary edges the block doesn't touch, plus 4 around
+        // the lone cell and 4 along the block's inner sides.
+        assert_eq!((15, 22), (metrics[0].area, metrics[0].perimeter));
+        assert_eq!(labels.bounds(), metrics[0].bounds);
+    }
+
+    #[test]
+    fn test_region_metrics_hole() {
+        let mut labels = Grid::new(3, 3, 1);
+        labels.set((1, 1), 2);
+
+        let metrics = labeled(3, 3).region_metrics(&labels).unwrap();
+        assert_eq!((8, 12 + 4), (metrics[0].area, metrics[0].perimeter));
+        assert_eq!((1, 4), (metrics[1].area, metrics[1].perimeter));
+        assert_eq!(Ok(Vec::new()), Grid::new(0, 0, 0).region_metrics(&Grid::new(0, 0, 0)));
+    }
+
+    #[test]
+    fn test_region_metrics_mismatch() {
+        assert_eq!(
+            Err(GridError::DimensionMismatch {
+                expected: (3, 2),
+                found: (2, 3),
+            }),
+            labeled(3, 2).region_metrics(&Grid::new(2, 3, 0))
+        );
     }
 }
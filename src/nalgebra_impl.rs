@@ -0,0 +1,116 @@
+//! Conversions to and from `nalgebra` matrices, behind the `nalgebra`
+//! feature.
+//!
+//! Grid rows become matrix rows and grid columns become matrix columns, so
+//! the cell at `(col, row)` of a grid is entry `(row, col)` of the matrix.
+//! `nalgebra` stores matrices column by column, so converting reorders the
+//! cells rather than reinterpreting the buffer.
+
+use crate::Grid;
+use alloc::vec::Vec;
+use nalgebra::{DMatrix, Scalar};
+
+impl<T> Grid<T>
+where
+    T: Scalar,
+{
+    /// Create a grid from a matrix, with entry `(row, col)` becoming cell
+    /// `(col, row)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    /// use nalgebra::DMatrix;
+    ///
+    /// let m = DMatrix::from_row_slice(2, 3, &[1, 2, 3, 4, 5, 6]);
+    /// let grid = Grid::from_dmatrix(m);
+    ///
+    /// assert_eq!((3, 2), (grid.col_count(), grid.row_count()));
+    /// assert_eq!(Some(&6), grid.get((2, 1)));
+    /// ```
+    pub fn from_dmatrix(m: DMatrix<T>) -> Self {
+        let (row_count, col_count) = m.shape();
+        let mut data = Vec::with_capacity(col_count * row_count);
+
+        for row in 0..row_count {
+            for col in 0..col_count {
+                data.push(m[(row, col)].clone());
+            }
+        }
+
+        Self {
+            col_count,
+            row_count,
+            data,
+        }
+    }
+
+    /// Copy the grid into a matrix, with cell `(col, row)` becoming entry
+    /// `(row, col)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 2, 0.0);
+    /// grid.set((2, 1), 1.5);
+    ///
+    /// let m = grid.to_dmatrix();
+    /// assert_eq!((2, 3), m.shape());
+    /// assert_eq!(1.5, m[(1, 2)]);
+    /// ```
+    pub fn to_dmatrix(&self) -> DMatrix<T> {
+        DMatrix::from_row_slice(self.row_count, self.col_count, &self.data)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::labeled;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_orientation() {
+        let grid = labeled(3, 2);
+        let m = grid.to_dmatrix();
+
+        assert_eq!(DMatrix::from_row_slice(2, 3, &[0, 1, 2, 3, 4, 5]), m);
+        assert_eq!(&[0, 3, 1, 4, 2, 5], m.as_slice());
+        assert_eq!(grid.transpose().to_dmatrix(), m.transpose());
+    }
+
+    #[test]
+    fn test_empty() {
+        let grid: Grid<i32> = Grid::new(0, 3, 0);
+
+        assert_eq!((3, 0), grid.to_dmatrix().shape());
+        assert_eq!(grid, Grid::from_dmatrix(grid.to_dmatrix()));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_entries_match(
+            (rows, cols, values) in (0usize..8, 0usize..8).prop_flat_map(|(rows, cols)| {
+                (Just(rows), Just(cols), prop::collection::vec(any::<i64>(), rows * cols))
+            }),
+        ) {
+            let m = DMatrix::from_vec(rows, cols, values);
+            let grid = Grid::from_dmatrix(m.clone());
+
+            prop_assert_eq!((cols, rows), (grid.col_count(), grid.row_count()));
+            for row in 0..rows {
+                for col in 0..cols {
+                    prop_assert_eq!(Some(&m[(row, col)]), grid.get((col, row)));
+                }
+            }
+            prop_assert_eq!(m, grid.to_dmatrix());
+        }
+    }
+}
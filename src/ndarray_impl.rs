@@ -0,0 +1,126 @@
+//! Conversions to and from `ndarray`, behind the `ndarray` feature.
+//!
+//! `ndarray` indexes a two-dimensional array as `[row, col]`, while `Grid`
+//! uses `(col, row)`. The shape of the array for a grid is therefore
+//! `(row_count, col_count)`, and the cell at `(col, row)` of a grid is at
+//! `[row, col]` of the matching array. Both store rows contiguously, so
+//! views are zero-copy.
+
+use crate::Grid;
+use ndarray::{Array2, ArrayView2, ArrayViewMut2};
+
+impl<T> Grid<T> {
+    /// Create a grid from an array indexed `[row, col]`, moving the cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    /// use ndarray::array;
+    ///
+    /// let grid = Grid::from_array2(array![[1, 2, 3], [4, 5, 6]]);
+    ///
+    /// assert_eq!((3, 2), (grid.col_count(), grid.row_count()));
+    /// assert_eq!(Some(&6), grid.get((2, 1)));
+    /// ```
+    pub fn from_array2(arr: Array2<T>) -> Self {
+        let (row_count, col_count) = arr.dim();
+
+        Self {
+            col_count,
+            row_count,
+            data: arr.into_iter().collect(),
+        }
+    }
+
+    /// Convert the grid into an array indexed `[row, col]`, reusing its
+    /// buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 2, 0);
+    /// grid.set((2, 1), 7);
+    ///
+    /// let arr = grid.into_array2();
+    /// assert_eq!((2, 3), arr.dim());
+    /// assert_eq!(7, arr[[1, 2]]);
+    /// ```
+    pub fn into_array2(self) -> Array2<T> {
+        Array2::from_shape_vec((self.row_count, self.col_count), self.data)
+            .expect("a grid's buffer matches its shape")
+    }
+
+    /// Borrow the grid as an array view indexed `[row, col]`, without
+    /// copying.
+    pub fn as_array_view(&self) -> ArrayView2<'_, T> {
+        ArrayView2::from_shape((self.row_count, self.col_count), &self.data)
+            .expect("a grid's buffer matches its shape")
+    }
+
+    /// Mutably borrow the grid as an array view indexed `[row, col]`,
+    /// without copying.
+    pub fn as_array_view_mut(&mut self) -> ArrayViewMut2<'_, T> {
+        ArrayViewMut2::from_shape((self.row_count, self.col_count), &mut self.data)
+            .expect("a grid's buffer matches its shape")
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::labeled;
+    use ndarray::{array, s};
+
+    #[test]
+    fn test_views_match_get() {
+        let mut grid = labeled(5, 3);
+        let view = grid.as_array_view();
+
+        assert_eq!((3, 5), view.dim());
+        for &(col, row) in &[(0, 0), (4, 0), (2, 1), (0, 2), (4, 2)] {
+            assert_eq!(grid.get((col, row)), Some(&view[[row, col]]));
+        }
+        assert_eq!(grid.as_slice().as_ptr(), view.as_ptr());
+
+        grid.as_array_view_mut()[[2, 1]] = 99;
+        assert_eq!(Some(&99), grid.get((1, 2)));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let grid = labeled(4, 3);
+        let arr = grid.clone().into_array2();
+
+        assert_eq!(array![[0, 1, 2, 3], [4, 5, 6, 7], [8, 9, 10, 11]], arr);
+        assert_eq!(grid, Grid::from_array2(arr));
+
+        let empty: Grid<u8> = Grid::new(0, 4, 0);
+        assert_eq!(empty, Grid::from_array2(empty.clone().into_array2()));
+    }
+
+    #[test]
+    fn test_from_non_standard_layout() {
+        let arr = array![[1, 2, 3], [4, 5, 6]];
+
+        let transposed = Grid::from_array2(arr.clone().reversed_axes());
+        assert_eq!(transposed, [[1, 4], [2, 5], [3, 6]]);
+
+        let sliced = Grid::from_array2(arr.slice(s![.., 1..]).to_owned());
+        assert_eq!(sliced, [[2, 3], [5, 6]]);
+    }
+
+    #[test]
+    fn test_transpose_matches_ndarray() {
+        let grid = labeled(5, 2);
+
+        assert_eq!(grid.as_array_view().t(), grid.transpose().as_array_view());
+        assert_eq!(grid.as_array_view().t().to_owned(), grid.transpose().into_array2());
+    }
+}
@@ -0,0 +1,438 @@
+//! Grids addressed by signed coordinates around a movable origin.
+
+use crate::{Coord, Grid, Offset};
+use core::convert::TryFrom;
+use core::mem;
+
+/// A signed (column, row) coordinate.
+pub type SignedCoord = (i64, i64);
+
+/// A grid whose cells are addressed by signed coordinates.
+///
+/// The grid covers a rectangle whose top-left cell is at `origin`, so
+/// negative coordinates are as usable as positive ones. This suits data
+/// that spreads out from a center, such as a growing cellular automaton
+/// or the world chunks around a player.
+///
+/// # Examples
+///
+/// ```
+/// use gridd::OffsetGrid;
+///
+/// let mut grid = OffsetGrid::new((-2, -1), 5, 3, '.');
+/// grid.set((-2, -1), '#');
+/// grid.set((0, 0), '@');
+///
+/// assert_eq!(Some(&'#'), grid.get((-2, -1)));
+/// assert_eq!(Some(&'@'), grid.get((0, 0)));
+/// assert_eq!(None, grid.get((-3, 0)));
+/// assert_eq!(Some(&'@'), grid.as_grid().get((2, 1)));
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct OffsetGrid<T> {
+    origin: SignedCoord,
+    grid: Grid<T>,
+}
+
+impl<T> OffsetGrid<T> {
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Create a grid whose top-left cell is at `origin`, with every cell
+    /// set to `default`.
+    pub fn new(origin: SignedCoord, col_count: usize, row_count: usize, default: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_grid(Grid::new(col_count, row_count, default), origin)
+    }
+
+    /// Place a grid so that its `(0, 0)` cell is at `origin`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a cell's coordinate would not fit in an `i64`.
+    pub fn from_grid(grid: Grid<T>, origin: SignedCoord) -> Self {
+        assert!(
+            fits(origin.0, grid.col_count()) && fits(origin.1, grid.row_count()),
+            "grid extends past i64::MAX from origin {:?}",
+            origin
+        );
+
+        Self { origin, grid }
+    }
+
+    /// Split the grid into its cells, as a plain grid, and its origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Grid, OffsetGrid};
+    ///
+    /// let mut grid = OffsetGrid::new((0, 0), 1, 1, 0);
+    /// grid.ensure_contains((-1, 0), 0);
+    /// grid.set((-1, 0), 7);
+    ///
+    /// let (cells, origin) = grid.into_parts();
+    /// assert_eq!((-1, 0), origin);
+    /// assert_eq!(Grid::from_rows(vec![vec![7, 0]]).unwrap(), cells);
+    /// ```
+    pub fn into_parts(self) -> (Grid<T>, SignedCoord) {
+        (self.grid, self.origin)
+    }
+
+    //////////////////////////////////
+    // Shape
+    //////////////////////////////////
+
+    /// Get the coordinate of the top-left cell.
+    pub fn origin(&self) -> SignedCoord {
+        self.origin
+    }
+
+    /// Get the column count.
+    pub fn col_count(&self) -> usize {
+        self.grid.col_count()
+    }
+
+    /// Get the row count.
+    pub fn row_count(&self) -> usize {
+        self.grid.row_count()
+    }
+
+    /// Borrow the cells as a plain grid, whose `(0, 0)` cell is at the
+    /// origin.
+    pub fn as_grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    /// Mutably borrow the cells as a plain grid, whose `(0, 0)` cell is at
+    /// the origin.
+    ///
+    /// Growing the plain grid so far that a cell's coordinate no longer fits
+    /// in an `i64` makes later iteration panic.
+    pub fn as_grid_mut(&mut self) -> &mut Grid<T> {
+        &mut self.grid
+    }
+
+    /// Determine if a signed coordinate is within bounds.
+    pub fn contains(&self, coord: SignedCoord) -> bool {
+        self.local(coord).is_some()
+    }
+
+    /// Convert a signed coordinate to an index into the plain grid, if it
+    /// is within bounds.
+    fn local(&self, (col, row): SignedCoord) -> Option<Coord> {
+        let col = usize::try_from(i128::from(col) - i128::from(self.origin.0)).ok()?;
+        let row = usize::try_from(i128::from(row) - i128::from(self.origin.1)).ok()?;
+
+        if self.grid.contains((col, row)) {
+            Some((col, row))
+        } else {
+            None
+        }
+    }
+
+    /// Convert an index into the plain grid to a signed coordinate.
+    fn signed(origin: SignedCoord, (col, row): Coord) -> SignedCoord {
+        let shift = |start: i64, index: usize| {
+            i64::try_from(i128::from(start) + index as i128)
+                .expect("every cell's coordinate fits in an i64")
+        };
+
+        (shift(origin.0, col), shift(origin.1, row))
+    }
+
+    //////////////////////////////////
+    // Access
+    //////////////////////////////////
+
+    /// Get an immutable reference to some cell.
+    pub fn get(&self, coord: SignedCoord) -> Option<&T> {
+        self.local(coord).and_then(|coord| self.grid.get(coord))
+    }
+
+    /// Get a mutable reference to some cell.
+    pub fn get_mut(&mut self, coord: SignedCoord) -> Option<&mut T> {
+        match self.local(coord) {
+            Some(coord) => self.grid.get_mut(coord),
+            _ => None,
+        }
+    }
+
+    /// Get an immutable reference to the cell with the given positional
+    /// relationship to the provided coordinate.
+    pub fn rget(&self, anchor: SignedCoord, vec: Offset) -> Option<&T> {
+        rcoord(anchor, vec).and_then(|coord| self.get(coord))
+    }
+
+    /// Get a mutable reference to the cell with the given positional
+    /// relationship to the provided coordinate.
+    pub fn rget_mut(&mut self, anchor: SignedCoord, vec: Offset) -> Option<&mut T> {
+        match rcoord(anchor, vec) {
+            Some(coord) => self.get_mut(coord),
+            _ => None,
+        }
+    }
+
+    /// Set a cell's value. Coordinates out of bounds are ignored.
+    pub fn set(&mut self, coord: SignedCoord, new_val: T) {
+        if let Some(coord) = self.local(coord) {
+            self.grid.set(coord, new_val);
+        }
+    }
+
+    //////////////////////////////////
+    // Iteration
+    //////////////////////////////////
+
+    /// Iterate over the grid's cells in row-major order, along with their
+    /// signed coordinates.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (SignedCoord, &T)> + '_ {
+        let origin = self.origin;
+
+        self.grid
+            .indexed_iter()
+            .map(move |(coord, cell)| (Self::signed(origin, coord), cell))
+    }
+
+    /// Iterate mutably over the grid's cells in row-major order, along with
+    /// their signed coordinates.
+    pub fn indexed_iter_mut(&mut self) -> impl Iterator<Item = (SignedCoord, &mut T)> + '_ {
+        let origin = self.origin;
+        let col_count = self.grid.col_count();
+
+        self.grid.iter_mut().enumerate().map(move |(index, cell)| {
+            (Self::signed(origin, (index % col_count, index / col_count)), cell)
+        })
+    }
+
+    //////////////////////////////////
+    // Resizing
+    //////////////////////////////////
+
+    /// Grow the grid just enough to contain a signed coordinate, filling new
+    /// cells with `fill`.
+    ///
+    /// Existing cells keep their signed coordinates. Growing left or up
+    /// moves the origin to the new top-left cell. An empty grid has no cells
+    /// to keep, so it becomes a single cell at the coordinate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the grown grid would need more than `usize::MAX` columns or
+    /// rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::OffsetGrid;
+    ///
+    /// let mut grid = OffsetGrid::new((0, 0), 2, 2, 1);
+    /// grid.ensure_contains((-3, 4), 0);
+    ///
+    /// assert_eq!((-3, 0), grid.origin());
+    /// assert_eq!((5, 5), (grid.col_count(), grid.row_count()));
+    /// assert_eq!(Some(&1), grid.get((1, 1)));
+    /// assert_eq!(Some(&0), grid.get((-3, 4)));
+    /// ```
+    pub fn ensure_contains(&mut self, (col, row): SignedCoord, fill: T)
+    where
+        T: Clone,
+    {
+        if self.contains((col, row)) {
+            return;
+        }
+
+        if self.grid.as_slice().is_empty() {
+            *self = Self::new((col, row), 1, 1, fill);
+            return;
+        }
+
+        // Work in i128, where an i64 plus a usize cannot overflow.
+        let (old_cols, old_rows) = (self.grid.col_count(), self.grid.row_count());
+        let (min_col, min_row) = (self.origin.0.min(col), self.origin.1.min(row));
+        let last_col = (i128::from(self.origin.0) + old_cols as i128 - 1).max(col.into());
+        let last_row = (i128::from(self.origin.1) + old_rows as i128 - 1).max(row.into());
+        let span = |min: i64, last: i128| {
+            usize::try_from(last - i128::from(min) + 1).expect("grid dimension overflows usize")
+        };
+        let (new_cols, new_rows) = (span(min_col, last_col), span(min_row, last_row));
+
+        // Every distance from the new top-left cell is within the new
+        // dimensions, so it fits in a usize.
+        let gap = |from: i64, min: i64| (i128::from(from) - i128::from(min)) as usize;
+        let (left, top) = (gap(self.origin.0, min_col), gap(self.origin.1, min_row));
+
+        // Without growth to the left or above, existing cells stay put.
+        if left == 0 && top == 0 {
+            self.grid.ensure_contains((gap(col, min_col), gap(row, min_row)), fill);
+            return;
+        }
+
+        let old = mem::replace(&mut self.grid, Grid::new(new_cols, new_rows, fill));
+
+        for (index, cell) in old.into_vec().into_iter().enumerate() {
+            self.grid.set((left + index % old_cols, top + index / old_cols), cell);
+        }
+        self.origin = (min_col, min_row);
+    }
+}
+
+/// Determine if `len` cells starting at `start` all have coordinates that
+/// fit in an `i64`.
+fn fits(start: i64, len: usize) -> bool {
+    i128::from(start) + len as i128 - 1 <= i128::from(i64::MAX)
+}
+
+/// Apply an offset to a signed coordinate, unless it overflows.
+fn rcoord((col, row): SignedCoord, vec: Offset) -> Option<SignedCoord> {
+    Some((
+        col.checked_add(i64::from(vec.col_offset))?,
+        row.checked_add(i64::from(vec.row_offset))?,
+    ))
+}
+
+impl<T> From<OffsetGrid<T>> for Grid<T> {
+    /// Discard the origin, keeping the cells.
+    fn from(grid: OffsetGrid<T>) -> Self {
+        grid.grid
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negative_coordinates() {
+        let mut grid = OffsetGrid::new((-5, -3), 4, 2, 0);
+        grid.set((-5, -3), 1);
+        grid.set((-2, -2), 2);
+        grid.set((-1, -2), 3);
+        *grid.get_mut((-4, -2)).unwrap() = 4;
+
+        assert!(grid.contains((-2, -2)));
+        assert!(!grid.contains((-1, -2)));
+        assert!(!grid.contains((-5, -1)));
+        assert_eq!(Some(&1), grid.get((-5, -3)));
+        assert_eq!(Some(&2), grid.get((-2, -2)));
+        assert_eq!(None, grid.get((-6, -3)));
+        assert_eq!(None, grid.get((i64::MIN, i64::MAX)));
+        assert_eq!(Some(&4), grid.rget((-5, -3), Offset::SOUTH + Offset::EAST));
+        assert_eq!(None, grid.rget((i64::MAX, -3), Offset::EAST));
+
+        *grid.rget_mut((-3, -3), Offset::SOUTH).unwrap() += 10;
+        let expected = Grid::from_rows(vec![vec![1, 0, 0, 0], vec![0, 4, 10, 2]]).unwrap();
+        assert_eq!(&expected, grid.as_grid());
+    }
+
+    #[test]
+    fn test_indexed_iter() {
+        let mut grid = OffsetGrid::from_grid(Grid::new(2, 2, 0), (-1, 3));
+        grid.indexed_iter_mut().for_each(|((col, row), cell)| *cell = col * 10 + row);
+
+        let cells: Vec<_> = grid.indexed_iter().map(|(coord, &cell)| (coord, cell)).collect();
+        assert_eq!(
+            vec![((-1, 3), -7), ((0, 3), 3), ((-1, 4), -6), ((0, 4), 4)],
+            cells
+        );
+    }
+
+    #[test]
+    fn test_growth_keeps_signed_coordinates() {
+        let mut grid = OffsetGrid::new((0, 0), 3, 2, '.');
+        grid.set((0, 0), 'a');
+        grid.set((2, 1), 'b');
+
+        grid.ensure_contains((4, 1), '.');
+        assert_eq!((0, 0), grid.origin());
+        assert_eq!((5, 2), (grid.col_count(), grid.row_count()));
+
+        grid.ensure_contains((-2, -3), '.');
+        grid.set((-2, -3), 'c');
+        grid.ensure_contains((1, -1), '.');
+
+        assert_eq!((-2, -3), grid.origin());
+        assert_eq!((7, 5), (grid.col_count(), grid.row_count()));
+        assert_eq!(Some(&'a'), grid.get((0, 0)));
+        assert_eq!(Some(&'b'), grid.get((2, 1)));
+        assert_eq!(Some(&'c'), grid.get((-2, -3)));
+        assert_eq!(3, grid.as_grid().iter().filter(|&&c| c != '.').count());
+
+        grid.ensure_contains((-4, 5), '.');
+        assert_eq!((-4, -3), grid.origin());
+        assert_eq!((9, 9), (grid.col_count(), grid.row_count()));
+        assert_eq!(Some(&'b'), grid.get((2, 1)));
+    }
+
+    #[test]
+    fn test_growth_from_empty() {
+        let mut grid = OffsetGrid::new((0, 0), 0, 0, 0);
+        grid.ensure_contains((-2, -1), 0);
+        grid.set((-2, -1), 9);
+
+        assert_eq!((-2, -1), grid.origin());
+        assert_eq!((1, 1), (grid.col_count(), grid.row_count()));
+        assert_eq!(Some(&9), grid.get((-2, -1)));
+
+        // A far-away origin is forgotten along with the empty grid's shape.
+        let mut grid = OffsetGrid::new((1_000_000, -1_000_000), 5, 0, 0);
+        grid.ensure_contains((3, 4), 1);
+        assert_eq!(((3, 4), 1, 1), (grid.origin(), grid.col_count(), grid.row_count()));
+    }
+
+    #[test]
+    fn test_extreme_coordinates() {
+        let mut grid = OffsetGrid::new((i64::MAX - 1, i64::MIN), 1, 1, 0);
+        grid.ensure_contains((i64::MAX, i64::MIN + 1), 5);
+        grid.set((i64::MAX, i64::MIN + 1), 7);
+
+        assert_eq!((i64::MAX - 1, i64::MIN), grid.origin());
+        assert_eq!((2, 2), (grid.col_count(), grid.row_count()));
+        assert_eq!(Some(&7), grid.get((i64::MAX, i64::MIN + 1)));
+        assert_eq!(None, grid.rget((i64::MAX, i64::MIN), Offset::EAST));
+
+        let corners: Vec<_> = grid.indexed_iter().map(|(coord, _)| coord).collect();
+        assert_eq!((i64::MAX, i64::MIN + 1), corners[3]);
+
+        grid.ensure_contains((i64::MAX - 3, i64::MIN), 5);
+        assert_eq!((i64::MAX - 3, i64::MIN), grid.origin());
+        assert_eq!(Some(&7), grid.get((i64::MAX, i64::MIN + 1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "grid dimension overflows usize")]
+    fn test_growth_too_wide() {
+        let mut grid = OffsetGrid::new((i64::MIN, 0), 1, 1, ());
+        grid.ensure_contains((i64::MAX, 0), ());
+    }
+
+    #[test]
+    #[should_panic(expected = "grid extends past i64::MAX")]
+    fn test_from_grid_past_max() {
+        OffsetGrid::from_grid(Grid::new(3, 1, 0), (i64::MAX - 1, 0));
+    }
+
+    #[test]
+    fn test_plain_grid_interop() {
+        let plain = Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        let mut grid = OffsetGrid::from_grid(plain.clone(), (-1, -1));
+
+        assert_eq!(Some(&1), grid.get((-1, -1)));
+        assert_eq!(Some(&4), grid.get((0, 0)));
+
+        grid.as_grid_mut().set((1, 0), 5);
+        assert_eq!(Some(&5), grid.get((0, -1)));
+
+        let (cells, origin) = grid.clone().into_parts();
+        assert_eq!((-1, -1), origin);
+        assert_eq!(cells, Grid::from(grid.clone()));
+        assert_eq!(grid, OffsetGrid::from_grid(cells, origin));
+    }
+}
@@ -0,0 +1,332 @@
+//! Parsing grids from text.
+
+use crate::{Coord, Grid, GridError};
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::fmt;
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+//////////////////////////////////////////////////////////////////////////////
+// Errors
+//////////////////////////////////////////////////////////////////////////////
+
+/// Errors returned when parsing a grid with `Grid::parse_with`.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum ParseError<E> {
+    /// The text was not a valid grid shape.
+    Grid(GridError),
+    /// The character at `coord` was rejected by the cell parser.
+    Cell { coord: Coord, error: E },
+}
+
+impl<E> From<GridError> for ParseError<E> {
+    fn from(err: GridError) -> Self {
+        ParseError::Grid(err)
+    }
+}
+
+impl<E> fmt::Display for ParseError<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Grid(err) => err.fmt(f),
+            ParseError::Cell { coord, error } => write!(
+                f,
+                "cannot parse the cell at ({}, {}): {}",
+                coord.0, coord.1, error
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E> Error for ParseError<E>
+where
+    E: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ParseError::Grid(err) => Some(err),
+            ParseError::Cell { error, .. } => Some(error),
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Parsing
+//////////////////////////////////////////////////////////////////////////////
+
+impl<T> Grid<T> {
+    /// Parse one cell per `char`, one row per line, mapping each character
+    /// and its coordinate through `f`.
+    ///
+    /// Lines are split as in the `FromStr` impl for `Grid<char>`.
+    ///
+    /// # Errors
+    ///
+    /// Fails with `ParseError::Grid` holding `GridError::RaggedLine` if a
+    /// line's length differs from the first line's, or with
+    /// `ParseError::Cell` for the first character `f` rejects.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Grid, ParseError};
+    ///
+    /// let digits = Grid::parse_with("12\n3x", |_, c| c.to_digit(10).ok_or(c));
+    ///
+    /// assert_eq!(
+    ///     Err(ParseError::Cell {
+    ///         coord: (1, 1),
+    ///         error: 'x',
+    ///     }),
+    ///     digits
+    /// );
+    /// ```
+    pub fn parse_with<E, F>(input: &str, mut f: F) -> Result<Self, ParseError<E>>
+    where
+        F: FnMut(Coord, char) -> Result<T, E>,
+    {
+        let mut col_count = 0;
+        let mut row_count = 0;
+        let mut data = Vec::new();
+
+        for (row, line) in input.lines().enumerate() {
+            let start = data.len();
+
+            for (col, c) in line.chars().enumerate() {
+                let cell = f((col, row), c).map_err(|error| ParseError::Cell {
+                    coord: (col, row),
+                    error,
+                })?;
+                data.push(cell);
+            }
+
+            let found = data.len() - start;
+            if row == 0 {
+                col_count = found;
+            } else if found != col_count {
+                return Err(GridError::RaggedLine {
+                    line: row + 1,
+                    expected: col_count,
+                    found,
+                }
+                .into());
+            }
+
+            row_count += 1;
+        }
+
+        Ok(Grid {
+            col_count,
+            row_count,
+            data,
+        })
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Clone,
+{
+    /// Parse one cell per `char`, one row per line, looking each character
+    /// up in `legend`.
+    ///
+    /// # Errors
+    ///
+    /// Fails as `Grid::parse_with` does, with the character itself as the
+    /// cell error if it is missing from `legend`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    /// use std::collections::HashMap;
+    ///
+    /// let legend: HashMap<char, bool> = vec![('#', true), ('.', false)].into_iter().collect();
+    /// let walls = Grid::parse_map("#.\n.#", &legend).unwrap();
+    ///
+    /// assert_eq!(Some(&true), walls.get((1, 1)));
+    /// assert_eq!(Some(&false), walls.get((0, 1)));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn parse_map(input: &str, legend: &HashMap<char, T>) -> Result<Self, ParseError<char>> {
+        Self::parse_with(input, |_, c| legend.get(&c).cloned().ok_or(c))
+    }
+}
+
+/// Parse one cell per `char`, one row per line.
+///
+/// Lines are split as by `str::lines`, so a trailing newline is ignored and
+/// a `\r` directly before a `\n` is stripped. A `\r` anywhere else is kept
+/// as a cell. Empty input gives an empty grid.
+///
+/// # Errors
+///
+/// Fails with `GridError::RaggedLine` if a line's length differs from the
+/// first line's.
+///
+/// # Examples
+///
+/// ```
+/// use gridd::Grid;
+///
+/// let maze: Grid<char> = "#.#\n...\n".parse().unwrap();
+///
+/// assert_eq!((3, 2), (maze.col_count(), maze.row_count()));
+/// assert_eq!(Some(&'#'), maze.get((2, 0)));
+/// assert_eq!("#.#\n...", format!("{:#}", maze));
+/// ```
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(s, |_, c| Ok::<_, Infallible>(c)).map_err(|err| match err {
+            ParseError::Grid(err) => err,
+            ParseError::Cell { error, .. } => match error {},
+        })
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Copy, Debug, Eq, PartialEq)]
+    enum Tile {
+        Open,
+        Wall,
+        Start,
+    }
+
+    fn tile(c: char) -> Result<Tile, String> {
+        match c {
+            '.' => Ok(Tile::Open),
+            '#' => Ok(Tile::Wall),
+            'S' => Ok(Tile::Start),
+            _ => Err(format!("unknown tile {:?}", c)),
+        }
+    }
+
+    #[test]
+    fn test_parse_chars() {
+        let grid: Grid<char> = "ab\ncd\nef".parse().unwrap();
+
+        assert_eq!((2, 3), (grid.col_count(), grid.row_count()));
+        assert_eq!(Some(&'a'), grid.get((0, 0)));
+        assert_eq!(Some(&'d'), grid.get((1, 1)));
+        assert_eq!(Some(&'e'), grid.get((0, 2)));
+        assert_eq!(grid, "ab\ncd\nef\n".parse::<Grid<char>>().unwrap());
+    }
+
+    #[test]
+    fn test_parse_ragged() {
+        let err = "abc\nabc\nab\nabc".parse::<Grid<char>>().unwrap_err();
+
+        assert_eq!(
+            GridError::RaggedLine {
+                line: 3,
+                expected: 3,
+                found: 2,
+            },
+            err
+        );
+        assert_eq!("line 3 has 2 cells, expected 3", err.to_string());
+    }
+
+    #[test]
+    fn test_parse_crlf() {
+        let grid: Grid<char> = "ab\r\ncd\r\n".parse().unwrap();
+
+        assert_eq!(grid, "ab\ncd".parse::<Grid<char>>().unwrap());
+
+        let lone: Grid<char> = "a\rb\n".parse().unwrap();
+        assert_eq!(Some(&'\r'), lone.get((1, 0)));
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let grid: Grid<char> = "".parse().unwrap();
+
+        assert_eq!((0, 0), (grid.col_count(), grid.row_count()));
+    }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let text = "#..#\n.##.\n#..#";
+        let grid: Grid<char> = text.parse().unwrap();
+
+        assert_eq!(text, format!("{:#}", grid));
+        assert_eq!(grid, format!("{:#}", grid).parse::<Grid<char>>().unwrap());
+    }
+
+    #[test]
+    fn test_parse_with_enum() {
+        let grid = Grid::parse_with("#S.\n#..", |_, c| tile(c)).unwrap();
+
+        assert_eq!((3, 2), (grid.col_count(), grid.row_count()));
+        assert_eq!(Some(&Tile::Start), grid.get((1, 0)));
+        assert_eq!(Some(&Tile::Wall), grid.get((0, 1)));
+        assert_eq!(Some(&Tile::Open), grid.get((2, 1)));
+
+        let mut seen = Vec::new();
+        Grid::parse_with("ab\ncd", |coord, c| {
+            seen.push((coord, c));
+            Ok::<_, ()>(c)
+        })
+        .unwrap();
+        assert_eq!(vec![((0, 0), 'a'), ((1, 0), 'b'), ((0, 1), 'c'), ((1, 1), 'd')], seen);
+    }
+
+    #[test]
+    fn test_parse_with_errors() {
+        let err = Grid::parse_with("#..\n#.?\n#..", |_, c| tile(c)).unwrap_err();
+
+        assert_eq!(
+            ParseError::Cell {
+                coord: (2, 1),
+                error: String::from("unknown tile '?'"),
+            },
+            err
+        );
+        assert_eq!("cannot parse the cell at (2, 1): unknown tile '?'", err.to_string());
+
+        let ragged = Grid::parse_with("#..\n#.", |_, c| tile(c)).unwrap_err();
+        assert_eq!(
+            ParseError::Grid(GridError::RaggedLine {
+                line: 2,
+                expected: 3,
+                found: 2,
+            }),
+            ragged
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_parse_map() {
+        let legend: HashMap<char, Tile> =
+            vec![('.', Tile::Open), ('#', Tile::Wall)].into_iter().collect();
+        let grid = Grid::parse_map("##\n.#\n", &legend).unwrap();
+
+        assert_eq!(Some(&Tile::Open), grid.get((0, 1)));
+        assert_eq!(Some(&Tile::Wall), grid.get((1, 1)));
+        assert_eq!(
+            Err(ParseError::Cell {
+                coord: (1, 0),
+                error: 'S',
+            }),
+            Grid::parse_map("#S", &legend)
+        );
+    }
+}
@@ -0,0 +1,118 @@
+//! Adapters for the `pathfinding` crate, behind the `pathfinding` feature.
+
+use crate::{Connectivity, Coord, Grid};
+
+impl<T> Grid<T> {
+    /// Create a successor function for `pathfinding`'s searches, such as
+    /// `astar`, `dijkstra`, and (ignoring costs) `bfs`.
+    ///
+    /// The function maps a coordinate to each adjacent cell, under
+    /// `connectivity`, that `cost` gives a cost for entering. Cells outside
+    /// the grid and cells where `cost` returns `None` are never successors.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Connectivity, Grid};
+    /// use pathfinding::prelude::astar;
+    ///
+    /// let maze: Grid<char> = "S.#.\n.##.\n...G".parse().unwrap();
+    /// let start = maze.find_value(&'S').unwrap();
+    /// let goal = maze.find_value(&'G').unwrap();
+    ///
+    /// let successors = maze.successors(Connectivity::Four, |_, &c| (c != '#').then_some(1));
+    /// let distance = |&(col, row): &(usize, usize)| {
+    ///     (goal.0.abs_diff(col) + goal.1.abs_diff(row)) as u32
+    /// };
+    /// let (path, cost) = astar(&start, successors, distance, |&c| c == goal).unwrap();
+    ///
+    /// assert_eq!(5, cost);
+    /// assert_eq!(vec![(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (3, 2)], path);
+    /// ```
+    pub fn successors<F>(
+        &self,
+        connectivity: Connectivity,
+        cost: F,
+    ) -> impl Fn(&Coord) -> Vec<(Coord, u32)> + use<'_, T, F>
+    where
+        F: Fn(Coord, &T) -> Option<u32>,
+    {
+        move |&from| {
+            connectivity
+                .offsets()
+                .iter()
+                .filter_map(|vec| {
+                    let to = vec.rcoord(from)?;
+                    let cell = self.get(to)?;
+
+                    cost(to, cell).map(|cost| (to, cost))
+                })
+                .collect()
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pathfinding::prelude::{bfs, dijkstra};
+
+    fn open(_: Coord, &c: &char) -> Option<u32> {
+        match c {
+            '#' => None,
+            '~' => Some(5),
+            _ => Some(1),
+        }
+    }
+
+    #[test]
+    fn test_successors() {
+        let maze: Grid<char> = "...\n.#.\n...".parse().unwrap();
+
+        let four = maze.successors(Connectivity::Four, open);
+        assert_eq!(vec![((1, 0), 1), ((0, 1), 1)], four(&(0, 0)));
+        assert!(four(&(1, 1)).iter().all(|&(coord, _)| coord != (1, 1)));
+        assert_eq!(4, four(&(1, 1)).len());
+
+        let eight = maze.successors(Connectivity::Eight, open);
+        assert_eq!(2, eight(&(0, 0)).len());
+        assert_eq!(4, eight(&(1, 0)).len());
+        assert!(eight(&(5, 5)).is_empty());
+    }
+
+    #[test]
+    fn test_weighted_search() {
+        let maze: Grid<char> = "S~~G\n.##.\n....".parse().unwrap();
+        let successors = maze.successors(Connectivity::Four, open);
+        let (path, cost) = dijkstra(&(0, 0), successors, |&c| c == (3, 0)).unwrap();
+
+        assert_eq!(7, cost);
+        assert_eq!(8, path.len());
+    }
+
+    #[test]
+    fn test_blocked_and_unreachable() {
+        let walled: Grid<char> = "S#.\n##.\n..G".parse().unwrap();
+        let four = walled.successors(Connectivity::Four, open);
+        assert!(four(&(0, 0)).is_empty());
+        assert_eq!(None, bfs(&(0, 0), |c| four(c).into_iter().map(|(c, _)| c), |&c| c == (2, 2)));
+
+        let eight = walled.successors(Connectivity::Eight, open);
+        let path = bfs(&(0, 0), |c| eight(c).into_iter().map(|(c, _)| c), |&c| c == (2, 2));
+        assert_eq!(None, path);
+
+        // A diagonal gap is only passable with eight-connectivity.
+        let gap: Grid<char> = "S#\n#G".parse().unwrap();
+        let four = gap.successors(Connectivity::Four, open);
+        let path = bfs(&(0, 0), |c| four(c).into_iter().map(|(c, _)| c), |&c| c == (1, 1));
+        assert_eq!(None, path);
+
+        let eight = gap.successors(Connectivity::Eight, open);
+        let path = bfs(&(0, 0), |c| eight(c).into_iter().map(|(c, _)| c), |&c| c == (1, 1));
+        assert_eq!(Some(vec![(0, 0), (1, 1)]), path);
+    }
+}
@@ -0,0 +1,143 @@
+//! Building `petgraph` graphs from grids, behind the `petgraph` feature.
+
+use crate::{Connectivity, Coord, Grid};
+use petgraph::graph::{NodeIndex, UnGraph};
+use std::collections::HashMap;
+
+impl<T> Grid<T> {
+    /// Build an undirected graph with a node for every cell and an edge
+    /// between each pair of adjacent cells that `edge_weight` gives a
+    /// weight.
+    ///
+    /// Node weights are the cells' coordinates, and the returned map gives
+    /// each coordinate's node. Nodes are added in row-major order, so a
+    /// cell's node index is also its index in `Grid::as_slice`. Returning
+    /// `None` from `edge_weight` leaves two cells unconnected, which is how
+    /// walls drop out of the graph. Each pair of cells is passed once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Connectivity, Grid};
+    ///
+    /// let mut maze = Grid::new(3, 1, '.');
+    /// maze.set((1, 0), '#');
+    ///
+    /// let (graph, nodes) = maze.to_graph(Connectivity::Four, |_, &a, _, &b| {
+    ///     if a == '#' || b == '#' {
+    ///         None
+    ///     } else {
+    ///         Some(1)
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(3, graph.node_count());
+    /// assert_eq!(0, graph.edge_count());
+    /// assert_eq!((2, 0), graph[nodes[&(2, 0)]]);
+    /// ```
+    pub fn to_graph<W, F>(
+        &self,
+        connectivity: Connectivity,
+        edge_weight: F,
+    ) -> (UnGraph<Coord, W>, HashMap<Coord, NodeIndex>)
+    where
+        F: Fn(Coord, &T, Coord, &T) -> Option<W>,
+    {
+        let mut graph = UnGraph::with_capacity(self.data.len(), 0);
+        let mut nodes = HashMap::with_capacity(self.data.len());
+
+        for (coord, _) in self.indexed_iter() {
+            nodes.insert(coord, graph.add_node(coord));
+        }
+
+        // Looking only east and south (and diagonally south) visits every
+        // adjacent pair exactly once.
+        let forward = connectivity
+            .offsets()
+            .iter()
+            .filter(|vec| vec.row_offset > 0 || (vec.row_offset == 0 && vec.col_offset > 0));
+
+        for (from, a) in self.indexed_iter() {
+            for &vec in forward.clone() {
+                let to = match vec.rcoord(from) {
+                    Some(to) if self.contains(to) => to,
+                    _ => continue,
+                };
+
+                if let Some(weight) = edge_weight(from, a, to, &self.data[self.flat_index(to)]) {
+                    graph.add_edge(nodes[&from], nodes[&to], weight);
+                }
+            }
+        }
+
+        (graph, nodes)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::labeled;
+    use petgraph::algo::{connected_components, dijkstra};
+
+    fn open<T>(_: Coord, _: &T, _: Coord, _: &T) -> Option<()> {
+        Some(())
+    }
+
+    fn passable(_: Coord, a: &char, _: Coord, b: &char) -> Option<u32> {
+        if *a == '#' || *b == '#' {
+            None
+        } else {
+            Some(1)
+        }
+    }
+
+    #[test]
+    fn test_open_grid_counts() {
+        let grid = labeled(4, 3);
+
+        let (four, nodes) = grid.to_graph(Connectivity::Four, open);
+        assert_eq!(12, four.node_count());
+        assert_eq!(3 * 3 + 4 * 2, four.edge_count());
+        assert_eq!(12, nodes.len());
+        for (coord, &cell) in grid.indexed_iter() {
+            assert_eq!(cell, nodes[&coord].index());
+            assert_eq!(coord, four[nodes[&coord]]);
+        }
+
+        let (eight, _) = grid.to_graph(Connectivity::Eight, open);
+        assert_eq!(17 + 2 * 3 * 2, eight.edge_count());
+    }
+
+    #[test]
+    fn test_wall_splits_components() {
+        let maze: Grid<char> = "..#..\n..#..\n..#..".parse().unwrap();
+        let (graph, _) = maze.to_graph(Connectivity::Four, passable);
+
+        // Two open halves, plus each wall cell on its own.
+        assert_eq!(2 + 3, connected_components(&graph));
+
+        let (diagonal, _) = maze.to_graph(Connectivity::Eight, passable);
+        assert_eq!(2 + 3, connected_components(&diagonal));
+    }
+
+    #[test]
+    fn test_weighted_shortest_path() {
+        let maze: Grid<char> = ".~~~.\n.###.\n...#.\n.#...".parse().unwrap();
+        let mud = |from, a: &char, to, b: &char| {
+            passable(from, a, to, b).map(|_| if *a == '~' || *b == '~' { 5 } else { 1 })
+        };
+        let (graph, nodes) = maze.to_graph(Connectivity::Four, mud);
+        let dist = dijkstra(&graph, nodes[&(0, 0)], None, |edge| *edge.weight());
+
+        // Eight dry steps around the bottom of the wall beat four muddy
+        // ones across the top.
+        assert_eq!(Some(&8), dist.get(&nodes[&(4, 2)]));
+        assert_eq!(Some(&10), dist.get(&nodes[&(2, 0)]));
+        assert_eq!(None, dist.get(&nodes[&(2, 1)]));
+    }
+}
@@ -0,0 +1,206 @@
+//! Byte views of grids of plain-old-data, behind the `bytemuck` feature.
+
+use crate::{Grid, GridError};
+use alloc::vec;
+use bytemuck::{Pod, PodCastError};
+use core::mem;
+
+impl<T> Grid<T>
+where
+    T: Pod,
+{
+    /// Borrow the cells as raw bytes, in row-major order, without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let grid = Grid::new(2, 1, 0x0102u16);
+    ///
+    /// assert_eq!(4, grid.as_bytes().len());
+    /// ```
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(&self.data)
+    }
+
+    /// Create a grid from the raw bytes of its cells, in row-major order.
+    ///
+    /// A `Grid` owns its cells, so the bytes are always copied. This means
+    /// `bytes` may have any alignment. To reuse an existing allocation
+    /// instead, reinterpret a grid in place with `Grid::cast_pod`.
+    ///
+    /// # Errors
+    ///
+    /// Fails with `GridError::LengthMismatch` if `bytes` is not exactly the
+    /// size of `col_count * row_count` cells. The lengths are in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let grid = Grid::new(3, 2, 7u32);
+    /// let copy = Grid::<u32>::from_bytes(3, 2, grid.as_bytes()).unwrap();
+    ///
+    /// assert_eq!(grid, copy);
+    /// assert!(Grid::<u32>::from_bytes(3, 2, &grid.as_bytes()[1..]).is_err());
+    /// ```
+    pub fn from_bytes(
+        col_count: usize,
+        row_count: usize,
+        bytes: &[u8],
+    ) -> Result<Self, GridError> {
+        let cell_count = col_count.checked_mul(row_count);
+        let expected = cell_count.and_then(|cells| cells.checked_mul(mem::size_of::<T>()));
+
+        match (cell_count, expected) {
+            (Some(cell_count), Some(expected)) if expected == bytes.len() => {
+                let data = if mem::size_of::<T>() == 0 {
+                    vec![T::zeroed(); cell_count]
+                } else {
+                    bytemuck::pod_collect_to_vec(bytes)
+                };
+
+                Ok(Grid {
+                    col_count,
+                    row_count,
+                    data,
+                })
+            }
+            _ => Err(GridError::LengthMismatch {
+                expected: expected.unwrap_or(usize::MAX),
+                found: bytes.len(),
+            }),
+        }
+    }
+
+    /// Reinterpret every cell as a `U` of the same size.
+    ///
+    /// The allocation is reused when `T` and `U` have the same alignment,
+    /// and the cells are copied otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Fails with `PodCastError::SizeMismatch` if `T` and `U` differ in
+    /// size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let bits = Grid::new(2, 2, 1.0f32).cast_pod::<u32>().unwrap();
+    ///
+    /// assert_eq!(Some(&0x3f80_0000), bits.get((1, 1)));
+    /// assert!(Grid::new(2, 2, 1.0f32).cast_pod::<u64>().is_err());
+    /// ```
+    pub fn cast_pod<U>(self) -> Result<Grid<U>, PodCastError>
+    where
+        U: Pod,
+    {
+        if mem::size_of::<T>() != mem::size_of::<U>() {
+            return Err(PodCastError::SizeMismatch);
+        }
+
+        let data = match bytemuck::allocation::try_cast_vec(self.data) {
+            Ok(data) => data,
+            Err((_, data)) if mem::size_of::<U>() == 0 => vec![U::zeroed(); data.len()],
+            Err((_, data)) => bytemuck::allocation::pod_collect_to_vec(&data),
+        };
+
+        Ok(Grid {
+            col_count: self.col_count,
+            row_count: self.row_count,
+            data,
+        })
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::labeled;
+    use bytemuck::Zeroable;
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+    struct Texel {
+        height: f32,
+        material: u16,
+        flags: u16,
+    }
+
+    fn texels() -> Grid<Texel> {
+        labeled(3, 2).map(|&cell| Texel {
+            height: cell as f32 * 0.5,
+            material: cell as u16,
+            flags: 1 << cell,
+        })
+    }
+
+    #[test]
+    fn test_u16_round_trip() {
+        let grid = labeled(4, 3).map(|&cell| cell as u16 * 1000);
+        let bytes = grid.as_bytes();
+
+        assert_eq!(24, bytes.len());
+        assert_eq!(&1000u16.to_ne_bytes(), &bytes[2..4]);
+        assert_eq!(grid, Grid::from_bytes(4, 3, bytes).unwrap());
+    }
+
+    #[test]
+    fn test_struct_round_trip() {
+        let grid = texels();
+        let copy = Grid::<Texel>::from_bytes(3, 2, grid.as_bytes()).unwrap();
+
+        assert_eq!(grid, copy);
+        for row in 0..2 {
+            for col in 0..3 {
+                assert_eq!(grid.get((col, row)), copy.get((col, row)));
+            }
+        }
+        assert_eq!(Some(&4), copy.get((1, 1)).map(|texel| &texel.material));
+    }
+
+    #[test]
+    fn test_from_bytes_alignment_and_size() {
+        let grid = texels();
+        let mut shifted = vec![0u8];
+        shifted.extend_from_slice(grid.as_bytes());
+
+        assert_eq!(grid, Grid::from_bytes(3, 2, &shifted[1..]).unwrap());
+        assert_eq!(
+            Err(GridError::LengthMismatch {
+                expected: 48,
+                found: 49,
+            }),
+            Grid::<Texel>::from_bytes(3, 2, &shifted)
+        );
+        assert_eq!(
+            Err(GridError::LengthMismatch {
+                expected: usize::MAX,
+                found: 0,
+            }),
+            Grid::<u8>::from_bytes(usize::MAX, 2, &[])
+        );
+    }
+
+    #[test]
+    fn test_cast_pod() {
+        let grid = labeled(2, 2).map(|&cell| cell as i32 - 2);
+        let cast = grid.clone().cast_pod::<u32>().unwrap();
+
+        assert_eq!(Some(&u32::MAX), cast.get((1, 0)));
+        assert_eq!(grid.as_bytes(), cast.as_bytes());
+
+        let realigned = Grid::new(2, 2, [1u8, 2, 3, 4]).cast_pod::<u32>().unwrap();
+        assert_eq!(Some(&u32::from_ne_bytes([1, 2, 3, 4])), realigned.get((1, 1)));
+
+        assert_eq!(Err(PodCastError::SizeMismatch), grid.cast_pod::<u8>());
+    }
+}
@@ -0,0 +1,295 @@
+//! Random grids with `rand`, behind the `rand` feature.
+
+use crate::{Coord, Grid};
+use rand::distr::{Distribution, StandardUniform};
+use rand::seq::SliceRandom;
+use rand::{Rng, RngExt};
+
+impl<T> Grid<T> {
+    /// Create a grid with every cell drawn from the standard distribution
+    /// for `T`, in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let seeds: Grid<u8> = Grid::random(4, 3, &mut StdRng::seed_from_u64(7));
+    ///
+    /// assert_eq!(seeds, Grid::random(4, 3, &mut StdRng::seed_from_u64(7)));
+    /// ```
+    pub fn random<R>(col_count: usize, row_count: usize, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+        StandardUniform: Distribution<T>,
+    {
+        Self::random_with(col_count, row_count, &StandardUniform, rng)
+    }
+
+    /// Create a grid with every cell drawn from `dist`, in row-major order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    /// use rand::distr::Bernoulli;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(1);
+    /// let walls = Grid::random_with(8, 8, &Bernoulli::new(0.0).unwrap(), &mut rng);
+    ///
+    /// assert_eq!(Grid::new(8, 8, false), walls);
+    /// ```
+    pub fn random_with<R, D>(col_count: usize, row_count: usize, dist: &D, rng: &mut R) -> Self
+    where
+        R: Rng + ?Sized,
+        D: Distribution<T>,
+    {
+        let len = col_count * row_count;
+
+        Self {
+            col_count,
+            row_count,
+            data: (0..len).map(|_| dist.sample(rng)).collect(),
+        }
+    }
+
+    /// Overwrite every cell with a value drawn from `dist`, in row-major
+    /// order, keeping the grid's allocation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    /// use rand::distr::Uniform;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut heights = Grid::new(16, 16, 0.0);
+    /// heights.fill_random(&Uniform::new(-1.0, 1.0).unwrap(), &mut StdRng::seed_from_u64(3));
+    ///
+    /// assert!(heights.iter().all(|h| (-1.0..1.0).contains(h)));
+    /// ```
+    pub fn fill_random<R, D>(&mut self, dist: &D, rng: &mut R)
+    where
+        R: Rng + ?Sized,
+        D: Distribution<T>,
+    {
+        for cell in &mut self.data {
+            *cell = dist.sample(rng);
+        }
+    }
+
+    /// Shuffle every cell of the grid in place.
+    ///
+    /// Cells move freely between rows and columns; the grid's shape is
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut deck = Grid::from_vec(4, 13, (0..52).collect()).unwrap();
+    /// deck.shuffle(&mut StdRng::seed_from_u64(11));
+    ///
+    /// assert_eq!((0..52).sum::<i32>(), deck.sum());
+    /// ```
+    pub fn shuffle<R>(&mut self, rng: &mut R)
+    where
+        R: Rng + ?Sized,
+    {
+        self.data.shuffle(rng);
+    }
+
+    /// Pick a uniformly random coordinate within the grid, or `None` if the
+    /// grid has no cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = StdRng::seed_from_u64(2);
+    /// let grid = Grid::new(5, 3, 0);
+    ///
+    /// assert!(grid.contains(grid.random_coord(&mut rng).unwrap()));
+    /// assert_eq!(None, Grid::new(0, 3, 0).random_coord(&mut rng));
+    /// ```
+    pub fn random_coord<R>(&self, rng: &mut R) -> Option<Coord>
+    where
+        R: Rng + ?Sized,
+    {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        Some((
+            rng.random_range(0..self.col_count),
+            rng.random_range(0..self.row_count),
+        ))
+    }
+
+    /// Pick a uniformly random coordinate among the cells matching `pred`,
+    /// or `None` if no cell matches.
+    ///
+    /// The grid is scanned once, without collecting the matching cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    /// use rand::rngs::StdRng;
+    /// use rand::SeedableRng;
+    ///
+    /// let board: Grid<char> = "X.O\nOX.\nXOX".parse().unwrap();
+    /// let empty = board.random_matching(&mut StdRng::seed_from_u64(8), |&c| c == '.');
+    ///
+    /// assert!(empty == Some((1, 0)) || empty == Some((2, 1)));
+    /// ```
+    pub fn random_matching<R, F>(&self, rng: &mut R, mut pred: F) -> Option<Coord>
+    where
+        R: Rng + ?Sized,
+        F: FnMut(&T) -> bool,
+    {
+        let mut chosen = None;
+        let mut seen = 0;
+
+        // Reservoir sampling: the n-th match replaces the pick with
+        // probability 1/n, leaving every match equally likely.
+        for (coord, cell) in self.indexed_iter() {
+            if pred(cell) {
+                seen += 1;
+                if rng.random_range(0..seen) == 0 {
+                    chosen = Some(coord);
+                }
+            }
+        }
+
+        chosen
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::labeled;
+    use rand::distr::{Bernoulli, Uniform};
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_seeded_determinism() {
+        let a: Grid<u32> = Grid::random(9, 7, &mut StdRng::seed_from_u64(42));
+        let b: Grid<u32> = Grid::random(9, 7, &mut StdRng::seed_from_u64(42));
+        let c: Grid<u32> = Grid::random(9, 7, &mut StdRng::seed_from_u64(43));
+
+        assert_eq!((9, 7), (a.col_count(), a.row_count()));
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+
+        let mut reused = Grid::new(9, 7, 0);
+        reused.fill_random(&StandardUniform, &mut StdRng::seed_from_u64(42));
+        assert_eq!(a, reused);
+    }
+
+    #[test]
+    fn test_bernoulli_density() {
+        let dist = Bernoulli::new(0.3).unwrap();
+        let grid = Grid::random_with(200, 200, &dist, &mut StdRng::seed_from_u64(5));
+        let density = grid.iter().filter(|&&cell| cell).count() as f64 / 40_000.0;
+
+        assert!((density - 0.3).abs() < 0.02, "density was {}", density);
+    }
+
+    #[test]
+    fn test_uniform_range() {
+        let dist = Uniform::new_inclusive(10u8, 20).unwrap();
+        let mut grid = Grid::random_with(50, 40, &dist, &mut StdRng::seed_from_u64(9));
+
+        assert!(grid.iter().all(|cell| (10..=20).contains(cell)));
+        assert_eq!(Some(10), grid.min_value());
+        assert_eq!(Some(20), grid.max_value());
+
+        let capacity = grid.capacity_rows();
+        grid.fill_random(&Uniform::new(0u8, 5).unwrap(), &mut StdRng::seed_from_u64(9));
+        assert!(grid.iter().all(|&cell| cell < 5));
+        assert_eq!(capacity, grid.capacity_rows());
+
+        let empty: Grid<u8> = Grid::random_with(0, 6, &dist, &mut StdRng::seed_from_u64(9));
+        assert_eq!((0, 6), (empty.col_count(), empty.row_count()));
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let original = labeled(9, 6);
+        let mut a = original.clone();
+        let mut b = original.clone();
+        a.shuffle(&mut StdRng::seed_from_u64(17));
+        b.shuffle(&mut StdRng::seed_from_u64(17));
+
+        assert_eq!(a, b);
+        assert_ne!(original, a);
+        assert_eq!((9, 6), (a.col_count(), a.row_count()));
+
+        let mut cells = a.into_vec();
+        cells.sort_unstable();
+        assert_eq!(original.into_vec(), cells);
+
+        let mut empty: Grid<u8> = Grid::new(0, 2, 0);
+        empty.shuffle(&mut StdRng::seed_from_u64(17));
+        assert_eq!(Grid::new(0, 2, 0), empty);
+    }
+
+    #[test]
+    fn test_random_coord_uniformity() {
+        let grid = Grid::new(4, 3, ());
+        let mut rng = StdRng::seed_from_u64(23);
+        let mut hits = Grid::new(4, 3, 0);
+
+        for _ in 0..12_000 {
+            let coord = grid.random_coord(&mut rng).unwrap();
+            *hits.get_mut(coord).unwrap() += 1;
+        }
+
+        // Each of the 12 cells expects 1000 hits.
+        assert!(hits.iter().all(|&n| (850..1150).contains(&n)), "{:?}", hits);
+        assert_eq!(None, Grid::new(3, 0, ()).random_coord(&mut rng));
+    }
+
+    #[test]
+    fn test_random_matching() {
+        let mut grid = labeled(10, 10);
+        let mut rng = StdRng::seed_from_u64(31);
+        let mut hits = Grid::new(10, 10, 0);
+
+        for _ in 0..5_000 {
+            let coord = grid.random_matching(&mut rng, |&cell| cell % 20 == 0).unwrap();
+            *hits.get_mut(coord).unwrap() += 1;
+        }
+
+        // Only the five multiples of 20 match, each expecting 1000 hits.
+        for ((col, row), &n) in hits.indexed_iter() {
+            if (col + row * 10) % 20 == 0 {
+                assert!((850..1150).contains(&n), "{} hits at {:?}", n, (col, row));
+            } else {
+                assert_eq!(0, n);
+            }
+        }
+
+        assert_eq!(None, grid.random_matching(&mut rng, |&cell| cell > 100));
+        grid.fill_random(&Uniform::new(0, 5).unwrap(), &mut rng);
+        assert_eq!(None, grid.random_matching(&mut rng, |&cell| cell >= 5));
+    }
+}
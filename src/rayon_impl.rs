@@ -0,0 +1,263 @@
+//! Parallel iteration with `rayon`, behind the `rayon` feature.
+
+use crate::{Coord, Grid};
+use rayon::iter::Either;
+use rayon::prelude::*;
+use std::iter;
+
+/// The number of cells handed to each task by the indexed parallel
+/// iterators.
+const CHUNK_CELLS: usize = 1024;
+
+/// Iterate over the coordinates of a grid with `col_count` columns in
+/// row-major order, starting from the cell at flat index `start`.
+///
+/// Only the starting coordinate is divided out; the rest are stepped to.
+fn coords_from(col_count: usize, start: usize) -> impl Iterator<Item = Coord> {
+    let (mut col, mut row) = (start % col_count, start / col_count);
+
+    iter::repeat_with(move || {
+        let coord = (col, row);
+
+        col += 1;
+        if col == col_count {
+            col = 0;
+            row += 1;
+        }
+
+        coord
+    })
+}
+
+impl<T> Grid<T>
+where
+    T: Sync,
+{
+    /// Iterate in parallel over the grid's cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    /// use rayon::prelude::*;
+    ///
+    /// let grid = Grid::new(300, 200, 2u64);
+    ///
+    /// assert_eq!(120_000, grid.par_iter().sum::<u64>());
+    /// ```
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = &T> + '_ {
+        self.data.par_iter()
+    }
+
+    /// Iterate in parallel over the grid's cells, along with their
+    /// coordinates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    /// use rayon::prelude::*;
+    ///
+    /// let grid = Grid::new(300, 200, 0);
+    /// let on_diagonal = grid.par_indexed_iter().filter(|&((col, row), _)| col == row).count();
+    ///
+    /// assert_eq!(200, on_diagonal);
+    /// ```
+    pub fn par_indexed_iter(&self) -> impl ParallelIterator<Item = (Coord, &T)> + '_ {
+        let col_count = self.col_count;
+
+        self.data
+            .par_chunks(CHUNK_CELLS)
+            .enumerate()
+            .flat_map_iter(move |(i, cells)| coords_from(col_count, i * CHUNK_CELLS).zip(cells))
+    }
+
+    /// Create a grid of the same shape by applying `f` to every cell and its
+    /// coordinates in parallel.
+    ///
+    /// This is the parallel counterpart of `Grid::map_indexed`. Cells are
+    /// written straight into the new grid's buffer, so no locking or
+    /// intermediate collection is involved.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let grid = Grid::new(300, 200, 1);
+    /// let scored = grid.par_map(|(col, row), &cell| cell + col * row);
+    ///
+    /// assert_eq!(Some(&(1 + 299 * 199)), scored.get((299, 199)));
+    /// ```
+    pub fn par_map<U, F>(&self, f: F) -> Grid<U>
+    where
+        U: Send,
+        F: Fn(Coord, &T) -> U + Sync,
+    {
+        let len = self.data.len();
+        let col_count = self.col_count;
+        let mut data = Vec::with_capacity(len);
+
+        data.spare_capacity_mut()[..len]
+            .par_chunks_mut(CHUNK_CELLS)
+            .zip(self.data.par_chunks(CHUNK_CELLS))
+            .enumerate()
+            .for_each(|(i, (out, cells))| {
+                let coords = coords_from(col_count, i * CHUNK_CELLS);
+
+                for ((slot, coord), cell) in out.iter_mut().zip(coords).zip(cells) {
+                    slot.write(f(coord, cell));
+                }
+            });
+
+        // SAFETY: the chunks above cover the first `len` slots, and each
+        // slot was written exactly once. If `f` panics, the panic reaches
+        // us before this point and the written values are merely leaked.
+        unsafe { data.set_len(len) };
+
+        Grid {
+            col_count: self.col_count,
+            row_count: self.row_count,
+            data,
+        }
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Send,
+{
+    /// Iterate mutably in parallel over the grid's cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut grid = Grid::new(300, 200, 1);
+    /// grid.par_iter_mut().for_each(|cell| *cell *= 3);
+    ///
+    /// assert_eq!(Grid::new(300, 200, 3), grid);
+    /// ```
+    pub fn par_iter_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut T> + '_ {
+        self.data.par_iter_mut()
+    }
+
+    /// Iterate in parallel over the grid's rows, top to bottom, as mutable
+    /// slices.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut grid = Grid::new(300, 200, 0);
+    /// grid.par_rows_mut().enumerate().for_each(|(row, cells)| cells.fill(row));
+    ///
+    /// assert_eq!(Some(&150), grid.get((299, 150)));
+    /// ```
+    pub fn par_rows_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut [T]> + '_ {
+        // Chunks of zero cells aren't allowed, so a grid without columns
+        // yields its (empty) rows directly.
+        if self.col_count == 0 {
+            let rows = (0..self.row_count).into_par_iter().map(|_| -> &mut [T] { &mut [] });
+
+            Either::Right(rows)
+        } else {
+            Either::Left(self.data.par_chunks_mut(self.col_count))
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::labeled;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_par_sum() {
+        let grid = labeled(97, 61);
+
+        assert_eq!(grid.iter().sum::<usize>(), grid.par_iter().sum::<usize>());
+        assert_eq!(grid.iter().count(), grid.par_iter().count());
+    }
+
+    #[test]
+    fn test_par_iter_mut() {
+        let mut serial = labeled(123, 45);
+        let mut parallel = serial.clone();
+
+        serial.iter_mut().for_each(|cell| *cell = *cell * 7 % 11);
+        parallel.par_iter_mut().for_each(|cell| *cell = *cell * 7 % 11);
+
+        assert_eq!(serial, parallel);
+    }
+
+    #[test]
+    fn test_par_indexed_iter_empty() {
+        assert_eq!(0, Grid::new(0, 5, 0).par_indexed_iter().count());
+        assert_eq!(0, Grid::new(5, 0, 0).par_indexed_iter().count());
+    }
+
+    #[test]
+    fn test_par_map_large() {
+        let grid = labeled(1500, 1100);
+        let mapped = grid.par_map(|(col, row), &cell| (cell, col, row));
+
+        assert_eq!((1500, 1100), (mapped.col_count(), mapped.row_count()));
+        for ((col, row), &cell) in mapped.indexed_iter() {
+            assert_eq!((col + row * 1500, col, row), cell);
+        }
+        assert_eq!(Grid::new(0, 3, ()), Grid::new(0, 3, 0).par_map(|_, _| ()));
+    }
+
+    #[test]
+    fn test_par_rows_mut() {
+        let mut grid = labeled(70, 40);
+        grid.par_rows_mut().enumerate().for_each(|(row, cells)| {
+            for cell in cells {
+                *cell += row * 1000;
+            }
+        });
+
+        for ((col, row), &cell) in grid.indexed_iter() {
+            assert_eq!(col + row * 70 + row * 1000, cell);
+        }
+
+        let mut empty: Grid<u8> = Grid::new(0, 4, 0);
+        assert_eq!(4, empty.par_rows_mut().count());
+        assert!(empty.par_rows_mut().all(|cells| cells.is_empty()));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_par_indexed_iter_matches_serial(cols in 0usize..90, rows in 0usize..90) {
+            let grid = labeled(cols, rows);
+            let parallel: Vec<_> = grid.par_indexed_iter().collect();
+            let serial: Vec<_> = grid.indexed_iter().collect();
+
+            prop_assert_eq!(serial, parallel);
+        }
+
+        #[test]
+        fn prop_par_map_matches_serial(
+            (cols, rows, values) in (0usize..90, 0usize..90).prop_flat_map(|(cols, rows)| {
+                (Just(cols), Just(rows), prop::collection::vec(any::<i32>(), cols * rows))
+            }),
+        ) {
+            let grid = Grid::from_vec(cols, rows, values).unwrap();
+            let score = |(col, row): Coord, &cell: &i32| {
+                i64::from(cell) * (col as i64 - row as i64)
+            };
+
+            prop_assert_eq!(grid.map_indexed(score), grid.par_map(score));
+        }
+    }
+}
@@ -0,0 +1,549 @@
+//! Rendering grids as text.
+
+use crate::{Coord, Grid};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use alloc::{format, vec};
+use core::fmt::{self, Alignment, Display};
+
+//////////////////////////////////////////////////////////////////////////////
+// Render Options
+//////////////////////////////////////////////////////////////////////////////
+
+/// Layout settings for `Grid::to_text`.
+///
+/// The defaults separate cells with a space and rows with a newline, with
+/// no alignment, headers, or border.
+///
+/// # Examples
+///
+/// ```
+/// use gridd::RenderOptions;
+/// use core::fmt::Alignment;
+///
+/// let opts = RenderOptions::new()
+///     .cell_separator(", ")
+///     .align(Alignment::Right)
+///     .headers(true);
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RenderOptions {
+    cell_separator: String,
+    row_separator: String,
+    align: Option<Alignment>,
+    headers: bool,
+    border: bool,
+}
+
+impl Default for RenderOptions {
+    fn default() -> Self {
+        Self {
+            cell_separator: String::from(" "),
+            row_separator: String::from("\n"),
+            align: None,
+            headers: false,
+            border: false,
+        }
+    }
+}
+
+impl RenderOptions {
+    /// Create the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the text placed between cells of a row.
+    pub fn cell_separator(mut self, sep: &str) -> Self {
+        self.cell_separator = String::from(sep);
+        self
+    }
+
+    /// Set the text placed between lines.
+    pub fn row_separator(mut self, sep: &str) -> Self {
+        self.row_separator = String::from(sep);
+        self
+    }
+
+    /// Pad every cell to the width of the widest cell in its column.
+    pub fn align(mut self, align: Alignment) -> Self {
+        self.align = Some(align);
+        self
+    }
+
+    /// Label columns along the top and rows down the left side with their
+    /// indices.
+    pub fn headers(mut self, headers: bool) -> Self {
+        self.headers = headers;
+        self
+    }
+
+    /// Draw an ASCII border around the text.
+    ///
+    /// Lines must be the same width for the border to close, so a border
+    /// left-aligns cells unless another alignment is set.
+    pub fn border(mut self, border: bool) -> Self {
+        self.border = border;
+        self
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Grid Integration
+//////////////////////////////////////////////////////////////////////////////
+
+impl<T> Grid<T> {
+    /// Write the grid to `out` one row per line, drawing each cell as
+    /// whatever `cell` returns for its coordinate and value.
+    ///
+    /// Rows are separated by `\n`, with nothing between cells. Output is
+    /// streamed, so no intermediate strings are built.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut floor = Grid::new(3, 2, false);
+    /// floor.set((2, 0), true);
+    ///
+    /// let mut out = String::new();
+    /// floor
+    ///     .render_with(&mut out, |coord, &wall| match (coord, wall) {
+    ///         ((0, 1), _) => '@',
+    ///         (_, true) => '#',
+    ///         (_, false) => '.',
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!("..#\n@..", out);
+    /// ```
+    pub fn render_with<W, D, F>(&self, out: &mut W, mut cell: F) -> fmt::Result
+    where
+        W: fmt::Write,
+        D: Display,
+        F: FnMut(Coord, &T) -> D,
+    {
+        for row in 0..self.row_count {
+            if row > 0 {
+                out.write_char('\n')?;
+            }
+
+            for (col, value) in self.data[self.row_range(row)].iter().enumerate() {
+                write!(out, "{}", cell((col, row), value))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Display,
+{
+    /// Render the grid as text, one line per row.
+    ///
+    /// Widths are measured in `char`s. Lines never end in padding unless
+    /// a border needs it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Grid, RenderOptions};
+    /// use core::fmt::Alignment;
+    ///
+    /// let mut grid = Grid::new(3, 2, 7);
+    /// grid.set((1, 0), 100);
+    ///
+    /// let opts = RenderOptions::new().align(Alignment::Right);
+    /// assert_eq!("7 100 7\n7   7 7", grid.to_text(&opts));
+    /// ```
+    pub fn to_text(&self, opts: &RenderOptions) -> String {
+        let mut lines: Vec<Vec<String>> = (0..self.row_count)
+            .map(|row| {
+                self.data[self.row_range(row)]
+                    .iter()
+                    .map(|cell| cell.to_string())
+                    .collect()
+            })
+            .collect();
+
+        if opts.headers {
+            for (row, line) in lines.iter_mut().enumerate() {
+                line.insert(0, row.to_string());
+            }
+
+            let mut header: Vec<String> = (0..self.col_count).map(|col| col.to_string()).collect();
+            header.insert(0, String::new());
+            lines.insert(0, header);
+        }
+
+        let align = match opts.align {
+            None if opts.border => Some(Alignment::Left),
+            align => align,
+        };
+        let col_count = lines.first().map_or(0, Vec::len);
+        let widths: Vec<usize> = match align {
+            Some(_) => (0..col_count)
+                .map(|col| lines.iter().map(|line| line[col].chars().count()).max().unwrap_or(0))
+                .collect(),
+            None => vec![0; col_count],
+        };
+        let sep_len = opts.cell_separator.chars().count();
+        let inner_width = widths.iter().sum::<usize>() + sep_len * col_count.saturating_sub(1);
+
+        let mut text: Vec<String> = lines
+            .iter()
+            .map(|line| {
+                let mut out = String::new();
+
+                for (col, (cell, &width)) in line.iter().zip(&widths).enumerate() {
+                    if col > 0 {
+                        out.push_str(&opts.cell_separator);
+                    }
+
+                    let is_last = col + 1 == line.len();
+                    push_padded(&mut out, cell, width, align, is_last && !opts.border);
+                }
+
+                if opts.border {
+                    format!("| {} |", out)
+                } else {
+                    out
+                }
+            })
+            .collect();
+
+        if opts.border {
+            let edge = format!("+{}+", "-".repeat(inner_width + 2));
+            text.insert(0, edge.clone());
+            text.push(edge);
+        }
+
+        text.join(&opts.row_separator)
+    }
+}
+
+/// Append `cell` padded to `width` chars, omitting any padding that would
+/// trail at the end of a line.
+fn push_padded(out: &mut String, cell: &str, width: usize, align: Option<Alignment>, last: bool) {
+    let gap = width.saturating_sub(cell.chars().count());
+    let (before, after) = match align {
+        None => (0, 0),
+        Some(Alignment::Left) => (0, gap),
+        Some(Alignment::Right) => (gap, 0),
+        Some(Alignment::Center) => (gap / 2, gap - gap / 2),
+    };
+
+    out.extend(core::iter::repeat_n(' ', before));
+    out.push_str(cell);
+
+    if !last {
+        out.extend(core::iter::repeat_n(' ', after));
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Boolean Grids
+//////////////////////////////////////////////////////////////////////////////
+
+/// Bits of the braille dots for each cell of a 2x4 block, indexed by
+/// `[row][col]`.
+const BRAILLE_DOTS: [[u32; 2]; 4] = [[0x01, 0x08], [0x02, 0x10], [0x04, 0x20], [0x40, 0x80]];
+
+impl Grid<bool> {
+    /// Render the grid with half-block characters, packing two rows into
+    /// each line of text.
+    ///
+    /// Cells past the last row of an odd-height grid are drawn as `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 3, false);
+    /// grid.set((0, 0), true);
+    /// grid.set((1, 1), true);
+    /// grid.set((2, 0), true);
+    /// grid.set((2, 1), true);
+    /// grid.set((2, 2), true);
+    ///
+    /// assert_eq!("▀▄█\n  ▀", grid.to_block_string());
+    /// ```
+    pub fn to_block_string(&self) -> String {
+        self.packed_lines(1, 2, |get| match (get(0, 0), get(0, 1)) {
+            (false, false) => ' ',
+            (true, false) => '▀',
+            (false, true) => '▄',
+            (true, true) => '█',
+        })
+    }
+
+    /// Render the grid with braille characters, packing each 2x4 block of
+    /// cells into one character.
+    ///
+    /// Cells past the last column or row are drawn as `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(3, 4, false);
+    /// grid.set((0, 0), true);
+    /// grid.set((1, 3), true);
+    /// grid.set((2, 1), true);
+    ///
+    /// assert_eq!("⢁⠂", grid.to_braille_string());
+    /// ```
+    pub fn to_braille_string(&self) -> String {
+        self.packed_lines(2, 4, |get| {
+            let mut bits = 0;
+
+            for (row, dots) in BRAILLE_DOTS.iter().enumerate() {
+                for (col, &dot) in dots.iter().enumerate() {
+                    if get(col, row) {
+                        bits |= dot;
+                    }
+                }
+            }
+
+            core::char::from_u32(0x2800 + bits).expect("braille patterns are valid chars")
+        })
+    }
+
+    /// Draw each `block_cols` by `block_rows` block of cells as one char,
+    /// one line per band of rows. `glyph` reads cells by their offset in the
+    /// block, seeing `false` beyond the grid's edges.
+    fn packed_lines<F>(&self, block_cols: usize, block_rows: usize, mut glyph: F) -> String
+    where
+        F: FnMut(&dyn Fn(usize, usize) -> bool) -> char,
+    {
+        let mut lines = Vec::new();
+
+        for top in (0..self.row_count).step_by(block_rows) {
+            let line: String = (0..self.col_count)
+                .step_by(block_cols)
+                .map(|left| {
+                    let get = |col, row| self.get((left + col, top + row)) == Some(&true);
+                    glyph(&get)
+                })
+                .collect();
+
+            lines.push(line);
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Write the grid one row per line, with cells separated by spaces, as
+/// `to_text` does with default options.
+///
+/// The alternate form, `{:#}`, leaves out the separators, which writes a
+/// grid of `char`s back out as the text it would be parsed from.
+impl<T> Display for Grid<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sep = if f.alternate() { "" } else { " " };
+
+        for row in 0..self.row_count {
+            if row > 0 {
+                writeln!(f)?;
+            }
+
+            for (col, cell) in self.data[self.row_range(row)].iter().enumerate() {
+                if col > 0 {
+                    f.write_str(sep)?;
+                }
+
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::labeled;
+
+    /// A grid mixing one- and three-digit numbers.
+    fn mixed() -> Grid<usize> {
+        let mut grid = labeled(3, 3);
+        grid.set((1, 1), 250);
+        grid.set((2, 2), 999);
+
+        grid
+    }
+
+    /// Assert that no line of `text` ends in a space.
+    fn assert_no_trailing_spaces(text: &str) {
+        for line in text.lines() {
+            assert_eq!(line.trim_end(), line, "trailing space in {:?}", line);
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let grid = labeled(3, 2);
+
+        assert_eq!("0 1 2\n3 4 5", grid.to_string());
+        assert_eq!("012\n345", format!("{:#}", grid));
+        assert_eq!(grid.to_string(), grid.to_text(&RenderOptions::new()));
+        assert_eq!("", Grid::new(0, 0, 0).to_string());
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    enum Tile {
+        Floor,
+        Wall,
+        Door,
+    }
+
+    fn glyph(tile: Tile) -> char {
+        match tile {
+            Tile::Floor => '.',
+            Tile::Wall => '#',
+            Tile::Door => '+',
+        }
+    }
+
+    fn room() -> Grid<Tile> {
+        let mut room = Grid::new(4, 3, Tile::Wall);
+        room.set((1, 1), Tile::Floor);
+        room.set((2, 1), Tile::Floor);
+        room.set((3, 1), Tile::Door);
+
+        room
+    }
+
+    #[test]
+    fn test_render_with() {
+        let mut out = String::new();
+        room().render_with(&mut out, |_, &tile| glyph(tile)).unwrap();
+
+        assert_eq!("####\n#..+\n####", out);
+    }
+
+    #[test]
+    fn test_render_with_overlay() {
+        let player = (2, 1);
+        let mut out = String::new();
+        room()
+            .render_with(&mut out, |coord, &tile| {
+                if coord == player {
+                    '@'
+                } else {
+                    glyph(tile)
+                }
+            })
+            .unwrap();
+
+        assert_eq!("####\n#.@+\n####", out);
+
+        let mut multi = String::new();
+        labeled(2, 2).render_with(&mut multi, |_, cell| format!("[{}]", cell)).unwrap();
+        assert_eq!("[0][1]\n[2][3]", multi);
+    }
+
+    /// A grid with only the cell at `coord` set.
+    fn single(cols: usize, rows: usize, coord: Coord) -> Grid<bool> {
+        let mut grid = Grid::new(cols, rows, false);
+        grid.set(coord, true);
+
+        grid
+    }
+
+    #[test]
+    fn test_block_string_corners() {
+        assert_eq!("▀ \n  ", single(2, 4, (0, 0)).to_block_string());
+        assert_eq!(" ▀\n  ", single(2, 4, (1, 0)).to_block_string());
+        assert_eq!("  \n▄ ", single(2, 4, (0, 3)).to_block_string());
+        assert_eq!("  \n ▄", single(2, 4, (1, 3)).to_block_string());
+        assert_eq!("█", Grid::new(1, 2, true).to_block_string());
+    }
+
+    #[test]
+    fn test_block_string_odd_height() {
+        assert_eq!("██\n▀▀", Grid::new(2, 3, true).to_block_string());
+        assert_eq!(" \n \n▀", single(1, 5, (0, 4)).to_block_string());
+    }
+
+    #[test]
+    fn test_braille_string_corners() {
+        assert_eq!("⠁⠀", single(4, 4, (0, 0)).to_braille_string());
+        assert_eq!("⠀⠈", single(4, 4, (3, 0)).to_braille_string());
+        assert_eq!("⡀⠀", single(4, 4, (0, 3)).to_braille_string());
+        assert_eq!("⠀⢀", single(4, 4, (3, 3)).to_braille_string());
+        assert_eq!("⣿", Grid::new(2, 4, true).to_braille_string());
+    }
+
+    #[test]
+    fn test_braille_string_odd_dims() {
+        assert_eq!("⣿⡇\n⠛⠃", Grid::new(3, 6, true).to_braille_string());
+        assert_eq!("⠁", single(1, 1, (0, 0)).to_braille_string());
+    }
+
+    #[test]
+    fn test_packed_strings_empty() {
+        let empty: Grid<bool> = Grid::new(0, 0, false);
+
+        assert_eq!("", empty.to_block_string());
+        assert_eq!("", empty.to_braille_string());
+        assert_eq!("", Grid::new(0, 3, true).to_braille_string());
+    }
+
+    #[test]
+    fn test_aligned() {
+        let right = mixed().to_text(&RenderOptions::new().align(Alignment::Right));
+        assert_eq!("0   1   2\n3 250   5\n6   7 999", right);
+
+        let left = mixed().to_text(&RenderOptions::new().align(Alignment::Left));
+        assert_eq!("0 1   2\n3 250 5\n6 7   999", left);
+        assert_no_trailing_spaces(&left);
+    }
+
+    #[test]
+    fn test_headers() {
+        let opts = RenderOptions::new()
+            .align(Alignment::Right)
+            .headers(true)
+            .cell_separator(" | ");
+        let text = mixed().to_text(&opts);
+
+        assert_eq!(
+            "  | 0 |   1 |   2\n0 | 0 |   1 |   2\n1 | 3 | 250 |   5\n2 | 6 |   7 | 999",
+            text
+        );
+        assert_no_trailing_spaces(&text);
+
+        let unaligned = labeled(2, 1).to_text(&RenderOptions::new().headers(true));
+        assert_eq!(" 0 1\n0 0 1", unaligned);
+    }
+
+    #[test]
+    fn test_border() {
+        let text = mixed().to_text(&RenderOptions::new().border(true).row_separator("\r\n"));
+
+        assert_eq!(
+            "+-----------+\r\n\
+             | 0 1   2   |\r\n\
+             | 3 250 5   |\r\n\
+             | 6 7   999 |\r\n\
+             +-----------+",
+            text
+        );
+        assert_eq!("+--+\n+--+", Grid::new(0, 0, 0).to_text(&RenderOptions::new().border(true)));
+    }
+}
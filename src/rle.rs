@@ -0,0 +1,187 @@
+//! Run-length encoded grids.
+
+use crate::{Grid, GridError};
+use alloc::vec::Vec;
+
+/// A grid stored as runs of equal cells in row-major order.
+///
+/// Grids made mostly of long stretches of one value, such as tile maps,
+/// shrink to a handful of runs.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RleGrid<T> {
+    col_count: usize,
+    row_count: usize,
+    runs: Vec<(usize, T)>,
+}
+
+impl<T> RleGrid<T> {
+    /// Get the number of columns of the encoded grid.
+    pub fn col_count(&self) -> usize {
+        self.col_count
+    }
+
+    /// Get the number of rows of the encoded grid.
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// Get the `(count, value)` runs, in row-major order.
+    pub fn runs(&self) -> &[(usize, T)] {
+        &self.runs
+    }
+}
+
+impl<T> RleGrid<T>
+where
+    T: Clone,
+{
+    /// Expand the runs back into a grid.
+    ///
+    /// # Errors
+    ///
+    /// Fails with `GridError::LengthMismatch` if the run lengths do not sum
+    /// to exactly the number of cells, as can happen with a corrupt
+    /// deserialized value. Runs are checked before they are expanded, so
+    /// an oversized run fails without allocating for it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Grid;
+    ///
+    /// let mut grid = Grid::new(4, 3, '.');
+    /// grid.set((2, 1), '#');
+    ///
+    /// let rle = grid.rle_encode();
+    /// assert_eq!(&[(6, '.'), (1, '#'), (5, '.')], rle.runs());
+    /// assert_eq!(Ok(grid), rle.decode());
+    /// ```
+    pub fn decode(&self) -> Result<Grid<T>, GridError> {
+        let cell_count = self.col_count.checked_mul(self.row_count);
+        let mismatch = |found| GridError::LengthMismatch {
+            expected: cell_count.unwrap_or(usize::MAX),
+            found,
+        };
+        let cell_count = cell_count.ok_or_else(|| mismatch(0))?;
+
+        let mut total: usize = 0;
+        for &(count, _) in &self.runs {
+            total = total
+                .checked_add(count)
+                .filter(|&total| total <= cell_count)
+                .ok_or_else(|| mismatch(total.saturating_add(count)))?;
+        }
+        if total != cell_count {
+            return Err(mismatch(total));
+        }
+
+        let mut data = Vec::with_capacity(cell_count);
+        for (count, value) in &self.runs {
+            data.extend(core::iter::repeat_n(value, *count).cloned());
+        }
+
+        Ok(Grid {
+            col_count: self.col_count,
+            row_count: self.row_count,
+            data,
+        })
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Clone + PartialEq,
+{
+    /// Encode the grid as runs of equal cells in row-major order.
+    ///
+    /// Runs continue across row boundaries.
+    pub fn rle_encode(&self) -> RleGrid<T> {
+        let mut runs: Vec<(usize, T)> = Vec::new();
+
+        for cell in &self.data {
+            match runs.last_mut() {
+                Some((count, value)) if value == cell => *count += 1,
+                _ => runs.push((1, cell.clone())),
+            }
+        }
+
+        RleGrid {
+            col_count: self.col_count,
+            row_count: self.row_count,
+            runs,
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::labeled;
+
+    #[test]
+    fn test_mostly_uniform() {
+        let mut grid = Grid::new(100, 80, 0u8);
+        grid.set((10, 10), 1);
+        grid.set((11, 10), 1);
+        grid.set((99, 79), 2);
+
+        let rle = grid.rle_encode();
+        assert_eq!(&[(1010, 0), (2, 1), (6987, 0), (1, 2)], rle.runs());
+        assert_eq!(Ok(grid), rle.decode());
+    }
+
+    #[test]
+    fn test_alternating() {
+        let grid = labeled(7, 5).map(|&cell| cell % 2 == 0);
+        let rle = grid.rle_encode();
+
+        assert_eq!(35, rle.runs().len());
+        assert!(rle.runs().iter().all(|&(count, _)| count == 1));
+        assert_eq!(Ok(grid), rle.decode());
+    }
+
+    #[test]
+    fn test_empty() {
+        let grid: Grid<u8> = Grid::new(0, 5, 0);
+        let rle = grid.rle_encode();
+
+        assert!(rle.runs().is_empty());
+        assert_eq!((0, 5), (rle.col_count(), rle.row_count()));
+        assert_eq!(Ok(grid), rle.decode());
+    }
+
+    #[test]
+    fn test_corrupt_totals() {
+        let corrupt = |runs| RleGrid {
+            col_count: 3,
+            row_count: 2,
+            runs,
+        };
+        let mismatch = |found| {
+            Err(GridError::LengthMismatch {
+                expected: 6,
+                found,
+            })
+        };
+
+        assert_eq!(mismatch(5), corrupt(vec![(2, 'a'), (3, 'b')]).decode());
+        assert_eq!(mismatch(7), corrupt(vec![(2, 'a'), (5, 'b')]).decode());
+        assert_eq!(mismatch(usize::MAX), corrupt(vec![(2, 'a'), (usize::MAX, 'b')]).decode());
+        assert_eq!(mismatch(0), corrupt(Vec::new()).decode());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let rle = Grid::new(3, 2, 'x').rle_encode();
+        let json = serde_json::to_string(&rle).unwrap();
+
+        assert_eq!(r#"{"col_count":3,"row_count":2,"runs":[[6,"x"]]}"#, json);
+        assert_eq!(rle, serde_json::from_str(&json).unwrap());
+    }
+}
@@ -0,0 +1,90 @@
+//! Serde support, behind the `serde` feature.
+//!
+//! `Grid` serializes as its column count, row count, and flat row-major
+//! cells. Deserializing goes through `RawGrid` so the cell count is checked
+//! against the dimensions before a grid is built.
+
+use crate::{Grid, GridError};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use serde::Deserialize;
+
+/// An unchecked grid, as read from a serialized document.
+#[derive(Deserialize)]
+#[serde(rename = "Grid")]
+pub(crate) struct RawGrid<T> {
+    col_count: usize,
+    row_count: usize,
+    data: Vec<T>,
+}
+
+impl<T> TryFrom<RawGrid<T>> for Grid<T> {
+    type Error = GridError;
+
+    fn try_from(raw: RawGrid<T>) -> Result<Self, Self::Error> {
+        Grid::from_vec(raw.col_count, raw.row_count, raw.data)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::labeled;
+    use crate::{Offset, Rect};
+
+    #[test]
+    fn test_json_round_trip() {
+        let grid = labeled(3, 2);
+        let json = serde_json::to_string(&grid).unwrap();
+
+        assert_eq!(r#"{"col_count":3,"row_count":2,"data":[0,1,2,3,4,5]}"#, json);
+        assert_eq!(grid, serde_json::from_str::<Grid<_>>(&json).unwrap());
+    }
+
+    #[test]
+    fn test_bincode_round_trip() {
+        let grid = labeled(4, 3).map(|&cell| cell as u16 * 300);
+        let bytes = bincode::serialize(&grid).unwrap();
+
+        assert_eq!(grid, bincode::deserialize::<Grid<u16>>(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_option_string_cells() {
+        let mut grid = Grid::new(2, 2, None);
+        grid.set((1, 0), Some(String::from("door")));
+        grid.set((0, 1), Some(String::new()));
+
+        let json = serde_json::to_string(&grid).unwrap();
+        assert_eq!(grid, serde_json::from_str::<Grid<_>>(&json).unwrap());
+
+        let bytes = bincode::serialize(&grid).unwrap();
+        assert_eq!(grid, bincode::deserialize::<Grid<Option<String>>>(&bytes).unwrap());
+    }
+
+    #[test]
+    fn test_rejects_bad_length() {
+        let short = r#"{"col_count":3,"row_count":2,"data":[0,1,2,3,4]}"#;
+        let err = serde_json::from_str::<Grid<u8>>(short).unwrap_err();
+        assert_eq!("expected 6 cells, found 5", err.to_string());
+
+        let huge = format!(r#"{{"col_count":{},"row_count":2,"data":[]}}"#, usize::MAX);
+        assert!(serde_json::from_str::<Grid<u8>>(&huge).is_err());
+    }
+
+    #[test]
+    fn test_offset_and_rect() {
+        let offset = Offset::from((-2, 5));
+        let json = serde_json::to_string(&offset).unwrap();
+        assert_eq!(r#"{"col_offset":-2,"row_offset":5}"#, json);
+        assert_eq!(offset, serde_json::from_str(&json).unwrap());
+
+        let rect = Rect::new((1, 2), 3, 4);
+        let json = serde_json::to_string(&rect).unwrap();
+        assert_eq!(rect, serde_json::from_str(&json).unwrap());
+    }
+}
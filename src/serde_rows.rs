@@ -0,0 +1,151 @@
+//! Serialize a `Grid` as an array of row arrays, for use with
+//! `#[serde(with = "gridd::serde_rows")]`.
+//!
+//! Nested rows are easier to read and write by hand than the flat default,
+//! which suits level layouts and other grids kept in config files. A grid
+//! with no rows has no way to record its column count, so it always
+//! deserializes as 0x0.
+//!
+//! # Examples
+//!
+//! ```
+//! use gridd::Grid;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! struct Level {
+//!     #[serde(with = "gridd::serde_rows")]
+//!     tiles: Grid<u8>,
+//! }
+//!
+//! let level: Level = serde_json::from_str(r#"{"tiles": [[1, 0], [0, 1]]}"#).unwrap();
+//!
+//! assert_eq!(Some(&1), level.tiles.get((1, 1)));
+//! assert_eq!(r#"{"tiles":[[1,0],[0,1]]}"#, serde_json::to_string(&level).unwrap());
+//! ```
+
+use crate::Grid;
+use alloc::vec::Vec;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serialize `grid` as a sequence of rows.
+pub fn serialize<T, S>(grid: &Grid<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    serializer.collect_seq((0..grid.row_count).map(|row| &grid.data[grid.row_range(row)]))
+}
+
+/// Deserialize a grid from a sequence of rows, rejecting rows whose
+/// lengths differ from the first row's.
+///
+/// Like `GridError::RaggedLine`, the error names the ragged row counting
+/// from 1.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Grid<T>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    let rows = Vec::<Vec<T>>::deserialize(deserializer)?;
+    let col_count = rows.first().map_or(0, Vec::len);
+    let row_count = rows.len();
+    let mut data = Vec::with_capacity(col_count * row_count);
+
+    for (row, cells) in rows.into_iter().enumerate() {
+        if cells.len() != col_count {
+            return Err(D::Error::custom(format_args!(
+                "row {} has {} cells, expected {}",
+                row + 1,
+                cells.len(),
+                col_count
+            )));
+        }
+
+        data.extend(cells);
+    }
+
+    Ok(Grid {
+        col_count,
+        row_count,
+        data,
+    })
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::labeled;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Rows {
+        #[serde(with = "crate::serde_rows")]
+        grid: Grid<usize>,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Flat {
+        grid: Grid<usize>,
+    }
+
+    #[test]
+    fn test_hand_written_rows() {
+        let doc = r#"
+            {
+                "grid": [
+                    [1, 2, 3],
+                    [4, 5, 6]
+                ]
+            }
+        "#;
+        let rows: Rows = serde_json::from_str(doc).unwrap();
+
+        assert_eq!((3, 2), (rows.grid.col_count(), rows.grid.row_count()));
+        assert_eq!(labeled(3, 2).map(|&cell| cell + 1), rows.grid);
+    }
+
+    #[test]
+    fn test_serialized_rows() {
+        let rows = Rows {
+            grid: labeled(2, 3),
+        };
+
+        assert_eq!(r#"{"grid":[[0,1],[2,3],[4,5]]}"#, serde_json::to_string(&rows).unwrap());
+
+        let empty = Rows {
+            grid: Grid::new(0, 0, 0),
+        };
+        assert_eq!(r#"{"grid":[]}"#, serde_json::to_string(&empty).unwrap());
+        assert_eq!(empty, serde_json::from_str(r#"{"grid":[]}"#).unwrap());
+    }
+
+    #[test]
+    fn test_ragged_rows() {
+        let err = serde_json::from_str::<Rows>(r#"{"grid":[[1,2],[3,4],[5]]}"#).unwrap_err();
+
+        assert!(err.to_string().starts_with("row 3 has 1 cells, expected 2"), "{}", err);
+    }
+
+    #[test]
+    fn test_flat_and_rows_agree() {
+        let flat = Flat {
+            grid: labeled(4, 3),
+        };
+        let flat_json = serde_json::to_string(&flat).unwrap();
+        let from_flat: Flat = serde_json::from_str(&flat_json).unwrap();
+
+        let rows_json = serde_json::to_string(&Rows {
+            grid: from_flat.grid,
+        })
+        .unwrap();
+        let from_rows: Rows = serde_json::from_str(&rows_json).unwrap();
+
+        assert_eq!(flat.grid, from_rows.grid);
+        assert_eq!(flat_json, serde_json::to_string(&Flat { grid: from_rows.grid }).unwrap());
+    }
+}
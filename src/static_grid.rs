@@ -0,0 +1,390 @@
+//! Fixed-size grids stored inline, without a heap allocation.
+
+use crate::{Coord, Grid, GridError, Offset};
+use alloc::vec::Vec;
+use core::array;
+use core::convert::TryFrom;
+
+//////////////////////////////////////////////////////////////////////////////
+// Static Grids
+//////////////////////////////////////////////////////////////////////////////
+
+/// A grid with `C` columns and `R` rows, stored inline as an array of rows.
+///
+/// Suited to small boards whose size is known up front, where allocating
+/// a `Grid` for each one would be wasteful. The API mirrors `Grid`'s, and
+/// coordinates are `(column, row)` as usual.
+///
+/// # Examples
+///
+/// ```
+/// use gridd::{Offset, StaticGrid};
+///
+/// let mut board: StaticGrid<char, 3, 3> = StaticGrid::new('.');
+/// board.set((1, 1), 'X');
+///
+/// assert_eq!(Some(&'X'), board.rget((0, 0), Offset::from((1, 1))));
+/// assert_eq!(None, board.get((3, 0)));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct StaticGrid<T, const C: usize, const R: usize> {
+    cells: [[T; C]; R],
+}
+
+impl<T, const C: usize, const R: usize> StaticGrid<T, C, R> {
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Create a grid with every cell holding `val`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::StaticGrid;
+    ///
+    /// const EMPTY: StaticGrid<u8, 4, 4> = StaticGrid::new(0);
+    ///
+    /// assert_eq!(Some(&0), EMPTY.get((3, 3)));
+    /// ```
+    pub const fn new(val: T) -> Self
+    where
+        T: Copy,
+    {
+        Self {
+            cells: [[val; C]; R],
+        }
+    }
+
+    /// Create a grid from an array of rows.
+    pub const fn from_rows(cells: [[T; C]; R]) -> Self {
+        Self { cells }
+    }
+
+    /// Create a grid by calling `f` with each cell's coordinates, in
+    /// row-major order.
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(Coord) -> T,
+    {
+        Self {
+            cells: array::from_fn(|row| array::from_fn(|col| f((col, row)))),
+        }
+    }
+
+    /// Get the grid's rows.
+    pub const fn as_rows(&self) -> &[[T; C]; R] {
+        &self.cells
+    }
+
+    /// Convert the grid into its rows.
+    pub fn into_rows(self) -> [[T; C]; R] {
+        self.cells
+    }
+
+    //////////////////////////////////
+    // Shape
+    //////////////////////////////////
+
+    /// Get the column count.
+    pub const fn col_count(&self) -> usize {
+        C
+    }
+
+    /// Get the row count.
+    pub const fn row_count(&self) -> usize {
+        R
+    }
+
+    /// Determine if a coordinate is within the grid.
+    pub const fn contains(&self, (col, row): Coord) -> bool {
+        col < C && row < R
+    }
+
+    //////////////////////////////////
+    // Access
+    //////////////////////////////////
+
+    /// Get an immutable reference to some cell.
+    pub const fn get(&self, (col, row): Coord) -> Option<&T> {
+        if self.contains((col, row)) {
+            Some(&self.cells[row][col])
+        } else {
+            None
+        }
+    }
+
+    /// Get a mutable reference to some cell.
+    pub fn get_mut(&mut self, (col, row): Coord) -> Option<&mut T> {
+        if self.contains((col, row)) {
+            Some(&mut self.cells[row][col])
+        } else {
+            None
+        }
+    }
+
+    /// Get an immutable reference to the cell with the given positional
+    /// relationship to the provided coordinate.
+    pub fn rget(&self, anchor: Coord, vec: Offset) -> Option<&T> {
+        vec.rcoord(anchor).and_then(|coord| self.get(coord))
+    }
+
+    /// Get a mutable reference to the cell with the given positional
+    /// relationship to the provided coordinate.
+    pub fn rget_mut(&mut self, anchor: Coord, vec: Offset) -> Option<&mut T> {
+        match vec.rcoord(anchor) {
+            Some(coord) => self.get_mut(coord),
+            _ => None,
+        }
+    }
+
+    /// Set a cell's value. Coordinates out of bounds are ignored.
+    pub fn set(&mut self, coord: Coord, new_val: T) {
+        if let Some(val) = self.get_mut(coord) {
+            *val = new_val;
+        }
+    }
+
+    /// Set the value of a cell with the given positional relationship to
+    /// the provided coordinate.
+    pub fn rset(&mut self, coord: Coord, vec: Offset, new_val: T) {
+        if let Some(rcoord) = vec.rcoord(coord) {
+            self.set(rcoord, new_val);
+        }
+    }
+
+    //////////////////////////////////
+    // Iteration
+    //////////////////////////////////
+
+    /// Iterate over the grid's cells in row-major order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + '_ {
+        self.cells.iter().flatten()
+    }
+
+    /// Iterate mutably over the grid's cells in row-major order.
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> + '_ {
+        self.cells.iter_mut().flatten()
+    }
+
+    /// Iterate over the grid's cells in row-major order, along with their
+    /// coordinates.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Coord, &T)> + '_ {
+        self.cells.iter().enumerate().flat_map(|(row, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .map(move |(col, cell)| ((col, row), cell))
+        })
+    }
+
+    //////////////////////////////////
+    // Transformation
+    //////////////////////////////////
+
+    /// Create a grid with this grid's rows as its columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::StaticGrid;
+    ///
+    /// let wide = StaticGrid::from_rows([[1, 2, 3], [4, 5, 6]]);
+    /// let tall = wide.transpose();
+    ///
+    /// assert_eq!(&[[1, 4], [2, 5], [3, 6]], tall.as_rows());
+    /// ```
+    pub fn transpose(&self) -> StaticGrid<T, R, C>
+    where
+        T: Clone,
+    {
+        StaticGrid::from_fn(|(col, row)| self.cells[col][row].clone())
+    }
+}
+
+impl<T, const C: usize, const R: usize> Default for StaticGrid<T, C, R>
+where
+    T: Default,
+{
+    fn default() -> Self {
+        Self::from_fn(|_| T::default())
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Conversions
+//////////////////////////////////////////////////////////////////////////////
+
+impl<T, const C: usize, const R: usize> From<StaticGrid<T, C, R>> for Grid<T> {
+    fn from(grid: StaticGrid<T, C, R>) -> Self {
+        let data: Vec<T> = IntoIterator::into_iter(grid.cells)
+            .flat_map(IntoIterator::into_iter)
+            .collect();
+
+        Grid {
+            col_count: C,
+            row_count: R,
+            data,
+        }
+    }
+}
+
+/// Fails with `DimensionMismatch` unless the grid has exactly `C` columns
+/// and `R` rows.
+///
+/// # Examples
+///
+/// ```
+/// use gridd::{Grid, GridError, StaticGrid};
+/// use std::convert::TryFrom;
+///
+/// let board = StaticGrid::<u8, 8, 8>::try_from(Grid::new(8, 8, 0)).unwrap();
+/// assert_eq!(Grid::new(8, 8, 0), Grid::from(board));
+///
+/// assert_eq!(
+///     Err(GridError::DimensionMismatch { expected: (8, 8), found: (8, 7) }),
+///     StaticGrid::<u8, 8, 8>::try_from(Grid::new(8, 7, 0))
+/// );
+/// ```
+impl<T, const C: usize, const R: usize> TryFrom<Grid<T>> for StaticGrid<T, C, R> {
+    type Error = GridError;
+
+    fn try_from(grid: Grid<T>) -> Result<Self, GridError> {
+        let found = (grid.col_count, grid.row_count);
+        if found != (C, R) {
+            return Err(GridError::DimensionMismatch {
+                expected: (C, R),
+                found,
+            });
+        }
+
+        let mut cells = grid.data.into_iter();
+
+        Ok(Self::from_fn(|_| cells.next().expect("the grid has C * R cells")))
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::labeled;
+    use core::mem;
+
+    // A static grid is exactly its cells, with no length or capacity.
+    const _: () = assert!(mem::size_of::<StaticGrid<u8, 3, 3>>() == 9);
+    const _: () = assert!(mem::size_of::<StaticGrid<u32, 8, 8>>() == 8 * 8 * 4);
+    const _: () = assert!(mem::size_of::<StaticGrid<u64, 0, 5>>() == 0);
+
+    const CORNER: Option<&u8> = StaticGrid::<u8, 4, 4>::new(7).get((3, 3));
+
+    fn labeled_static<const C: usize, const R: usize>() -> StaticGrid<usize, C, R> {
+        StaticGrid::from_fn(|(col, row)| col + row * C)
+    }
+
+    #[test]
+    fn test_new_and_shape() {
+        let grid: StaticGrid<i32, 4, 2> = StaticGrid::new(5);
+
+        assert_eq!((4, 2), (grid.col_count(), grid.row_count()));
+        assert_eq!(&[[5; 4]; 2], grid.as_rows());
+        assert_eq!(Some(&7), CORNER);
+        assert_eq!(StaticGrid::new(0), StaticGrid::<i32, 4, 2>::default());
+    }
+
+    #[test]
+    fn test_get_set() {
+        let mut grid = labeled_static::<3, 2>();
+
+        assert_eq!(Some(&0), grid.get((0, 0)));
+        assert_eq!(Some(&5), grid.get((2, 1)));
+        assert_eq!(None, grid.get((3, 0)));
+        assert_eq!(None, grid.get((0, 2)));
+
+        grid.set((1, 1), 40);
+        grid.set((9, 9), 50);
+        *grid.get_mut((0, 1)).unwrap() += 30;
+        assert_eq!(&[[0, 1, 2], [33, 40, 5]], grid.as_rows());
+        assert_eq!(None, grid.get_mut((3, 1)));
+    }
+
+    #[test]
+    fn test_contains() {
+        let grid: StaticGrid<(), 2, 3> = StaticGrid::new(());
+
+        assert!(grid.contains((1, 2)));
+        assert!(!grid.contains((2, 2)));
+        assert!(!grid.contains((1, 3)));
+        assert!(!StaticGrid::<(), 0, 3>::new(()).contains((0, 0)));
+    }
+
+    #[test]
+    fn test_relative_access() {
+        let mut grid = labeled_static::<3, 3>();
+
+        assert_eq!(Some(&4), grid.rget((0, 0), Offset::from((1, 1))));
+        assert_eq!(None, grid.rget((0, 0), Offset::from((-1, 0))));
+        assert_eq!(None, grid.rget((2, 2), Offset::from((1, 0))));
+
+        *grid.rget_mut((1, 1), Offset::from((0, -1))).unwrap() = 10;
+        grid.rset((1, 1), Offset::from((1, 1)), 20);
+        grid.rset((0, 0), Offset::from((-1, -1)), 30);
+        assert_eq!(&[[0, 10, 2], [3, 4, 5], [6, 7, 20]], grid.as_rows());
+    }
+
+    #[test]
+    fn test_iteration() {
+        let mut grid = labeled_static::<4, 3>();
+
+        assert_eq!((0..12).collect::<Vec<_>>(), grid.iter().copied().collect::<Vec<_>>());
+        assert_eq!(Some(&11), grid.iter().next_back());
+        for ((col, row), &cell) in grid.indexed_iter() {
+            assert_eq!(col + row * 4, cell);
+        }
+        assert_eq!(12, grid.indexed_iter().count());
+
+        grid.iter_mut().for_each(|cell| *cell *= 2);
+        assert_eq!(Some(&22), grid.get((3, 2)));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let grid = labeled_static::<4, 3>();
+        let transposed: StaticGrid<usize, 3, 4> = grid.transpose();
+
+        assert_eq!(Grid::from(grid).transpose(), Grid::from(transposed));
+        assert_eq!(grid, transposed.transpose());
+    }
+
+    #[test]
+    fn test_copy() {
+        let a: StaticGrid<u8, 2, 2> = StaticGrid::new(1);
+        let mut b = a;
+        b.set((0, 0), 9);
+
+        assert_eq!(Some(&1), a.get((0, 0)));
+        assert_eq!(Some(&9), b.get((0, 0)));
+    }
+
+    #[test]
+    fn test_conversions() {
+        let grid = labeled(5, 3);
+        let fixed = StaticGrid::<usize, 5, 3>::try_from(grid.clone()).unwrap();
+
+        assert_eq!(labeled_static::<5, 3>(), fixed);
+        assert_eq!(grid, Grid::from(fixed));
+        assert_eq!(
+            Err(GridError::DimensionMismatch {
+                expected: (3, 5),
+                found: (5, 3),
+            }),
+            StaticGrid::<usize, 3, 5>::try_from(grid)
+        );
+
+        let empty = StaticGrid::<u8, 0, 4>::try_from(Grid::new(0, 4, 0)).unwrap();
+        assert_eq!((0, 4), (Grid::from(empty).col_count(), Grid::from(empty).row_count()));
+    }
+}
@@ -0,0 +1,179 @@
+//! Fast neighborhood passes over whole grids.
+
+use crate::{Grid, PadMode};
+use alloc::vec;
+use alloc::vec::Vec;
+
+//////////////////////////////////////////////////////////////////////////////
+// Five-Point Stencils
+//////////////////////////////////////////////////////////////////////////////
+
+impl<T> Grid<T>
+where
+    T: Clone,
+{
+    /// Create a grid of the same shape where each cell is `f` applied to
+    /// the corresponding cell and its four orthogonal neighbors, in the
+    /// order center, north, east, south, west.
+    ///
+    /// Neighbors beyond the grid's edges are resolved by `edge`, as if the
+    /// grid had been padded by one cell with `Grid::pad`. The pass slides
+    /// three rows down the grid at once, so the interior is read as
+    /// contiguous slices and only the border cells need edge handling.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Grid, PadMode};
+    ///
+    /// let mut heat = Grid::new(3, 3, 0.0);
+    /// heat.set((1, 1), 1.0);
+    ///
+    /// let laplacian = heat.stencil5(PadMode::Constant(0.0), |c, n, e, s, w| {
+    ///     n + e + s + w - 4.0 * c
+    /// });
+    /// assert_eq!(Some(&-4.0), laplacian.get((1, 1)));
+    /// assert_eq!(Some(&1.0), laplacian.get((1, 0)));
+    /// assert_eq!(Some(&0.0), laplacian.get((0, 0)));
+    /// ```
+    pub fn stencil5<U, F>(&self, edge: PadMode<T>, mut f: F) -> Grid<U>
+    where
+        F: FnMut(&T, &T, &T, &T, &T) -> U,
+    {
+        let (cols, rows) = (self.col_count, self.row_count);
+        let mut data = Vec::with_capacity(cols * rows);
+
+        if cols > 0 {
+            let fill = match &edge {
+                PadMode::Constant(fill) => Some(fill),
+                _ => None,
+            };
+            let fill_row = match fill {
+                Some(fill) => vec![fill.clone(); cols],
+                None => Vec::new(),
+            };
+            let row_at = |row: isize| match edge.source_index(row, rows) {
+                Some(row) => &self.data[self.row_range(row)],
+                None => &fill_row[..],
+            };
+            let west = edge.source_index(-1, cols);
+            let east = edge.source_index(cols as isize, cols);
+
+            for row in 0..rows as isize {
+                let (north, center, south) = (row_at(row - 1), row_at(row), row_at(row + 1));
+                let side = |src: Option<usize>| match src {
+                    Some(col) => &center[col],
+                    None => fill.expect("only constant edges leave neighbors unmapped"),
+                };
+
+                let first_east = if cols > 1 { &center[1] } else { side(east) };
+                data.push(f(&center[0], &north[0], first_east, &south[0], side(west)));
+
+                for ((cells, n), s) in center.windows(3).zip(&north[1..]).zip(&south[1..]) {
+                    data.push(f(&cells[1], n, &cells[2], s, &cells[0]));
+                }
+
+                if cols > 1 {
+                    let last = cols - 1;
+                    data.push(f(
+                        &center[last],
+                        &north[last],
+                        side(east),
+                        &south[last],
+                        &center[last - 1],
+                    ));
+                }
+            }
+        }
+
+        Grid {
+            col_count: cols,
+            row_count: rows,
+            data,
+        }
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::labeled;
+    use proptest::prelude::*;
+
+    /// Apply a five-point stencil one cell at a time over a padded copy.
+    fn naive_stencil5<F>(grid: &Grid<i64>, edge: PadMode<i64>, f: F) -> Grid<i64>
+    where
+        F: Fn(&i64, &i64, &i64, &i64, &i64) -> i64,
+    {
+        let padded = grid.pad(1, 1, 1, 1, edge).unwrap();
+        let at = |col, row| padded.get((col, row)).unwrap();
+        let mut out = Grid::new(grid.col_count(), grid.row_count(), 0);
+
+        for row in 0..grid.row_count() {
+            for col in 0..grid.col_count() {
+                let (c, r) = (col + 1, row + 1);
+                out.set(
+                    (col, row),
+                    f(at(c, r), at(c, r - 1), at(c + 1, r), at(c, r + 1), at(c - 1, r)),
+                );
+            }
+        }
+
+        out
+    }
+
+    /// A stencil that tells every neighbor apart.
+    fn weigh(c: &i64, n: &i64, e: &i64, s: &i64, w: &i64) -> i64 {
+        c + 10 * n + 100 * e + 1000 * s + 10000 * w
+    }
+
+    #[test]
+    fn test_stencil5_neighbors() {
+        let grid = labeled(3, 3).map(|&cell| cell as i64);
+        let out = grid.stencil5(PadMode::Constant(0), weigh);
+
+        assert_eq!(Some(&(4 + 10 + 500 + 7000 + 30000)), out.get((1, 1)));
+        assert_eq!(Some(&(100 + 3000)), out.get((0, 0)));
+        assert_eq!(Some(&(8 + 50 + 70000)), out.get((2, 2)));
+    }
+
+    #[test]
+    fn test_stencil5_thin_and_empty() {
+        let edges = [PadMode::Constant(-1), PadMode::Edge, PadMode::Reflect, PadMode::Wrap];
+
+        for &edge in &edges {
+            for &(cols, rows) in &[(1, 1), (1, 4), (4, 1), (2, 2)] {
+                let grid = labeled(cols, rows).map(|&cell| cell as i64);
+
+                assert_eq!(naive_stencil5(&grid, edge, weigh), grid.stencil5(edge, weigh));
+            }
+        }
+
+        let empty: Grid<i64> = Grid::new(0, 3, 0);
+        assert_eq!(Grid::new(0, 3, 0), empty.stencil5(PadMode::Wrap, weigh));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_stencil5_matches_naive(
+            cols in 1usize..10,
+            rows in 1usize..10,
+            mode in 0usize..4,
+            seed in any::<i64>(),
+        ) {
+            let grid = labeled(cols, rows).map(|&cell| (cell as i64).wrapping_mul(seed) % 97);
+            let edge = match mode {
+                0 => PadMode::Constant(seed % 13),
+                1 => PadMode::Edge,
+                2 => PadMode::Reflect,
+                _ => PadMode::Wrap,
+            };
+
+            prop_assert_eq!(naive_stencil5(&grid, edge, weigh), grid.stencil5(edge, weigh));
+        }
+    }
+}
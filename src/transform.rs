@@ -0,0 +1,531 @@
+//! Symbolic orientations from the symmetry group of the square.
+
+use crate::{Coord, Grid};
+use core::cmp::Ordering;
+
+//////////////////////////////////////////////////////////////////////////////
+// Dihedral Transforms
+//////////////////////////////////////////////////////////////////////////////
+
+/// One of the eight rotations and reflections of a rectangle.
+///
+/// A `Transform` describes an orientation symbolically, so it can be stored,
+/// composed, and inverted without touching any grid data. Rotations are
+/// clockwise, matching `Grid::rotate_cw`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Transform {
+    /// Leave every cell where it is.
+    Identity,
+    /// Rotate a quarter-turn clockwise, as `Grid::rotate_cw`.
+    Rot90,
+    /// Rotate a half-turn, as `Grid::rotate_180`.
+    Rot180,
+    /// Rotate three quarter-turns clockwise, as `Grid::rotate_ccw`.
+    Rot270,
+    /// Mirror across the vertical axis, as `Grid::flip_horizontal`.
+    FlipH,
+    /// Mirror across the horizontal axis, as `Grid::flip_vertical`.
+    FlipV,
+    /// Mirror across the main diagonal, as `Grid::transpose`.
+    FlipDiagonal,
+    /// Mirror across the anti-diagonal.
+    FlipAntiDiagonal,
+}
+
+impl Transform {
+    //////////////////////////////////
+    // Constants
+    //////////////////////////////////
+
+    /// Every element of the group.
+    pub const ALL: [Transform; 8] = [
+        Transform::Identity,
+        Transform::Rot90,
+        Transform::Rot180,
+        Transform::Rot270,
+        Transform::FlipH,
+        Transform::FlipV,
+        Transform::FlipDiagonal,
+        Transform::FlipAntiDiagonal,
+    ];
+
+    //////////////////////////////////
+    // Utilities
+    //////////////////////////////////
+
+    /// Decompose into (swap axes, then mirror columns, then mirror rows).
+    fn parts(self) -> (bool, bool, bool) {
+        match self {
+            Transform::Identity => (false, false, false),
+            Transform::FlipH => (false, true, false),
+            Transform::FlipV => (false, false, true),
+            Transform::Rot180 => (false, true, true),
+            Transform::FlipDiagonal => (true, false, false),
+            Transform::Rot90 => (true, true, false),
+            Transform::Rot270 => (true, false, true),
+            Transform::FlipAntiDiagonal => (true, true, true),
+        }
+    }
+
+    /// Rebuild a transform from the pieces produced by `parts`.
+    fn from_parts(parts: (bool, bool, bool)) -> Self {
+        match parts {
+            (false, false, false) => Transform::Identity,
+            (false, true, false) => Transform::FlipH,
+            (false, false, true) => Transform::FlipV,
+            (false, true, true) => Transform::Rot180,
+            (true, false, false) => Transform::FlipDiagonal,
+            (true, true, false) => Transform::Rot90,
+            (true, false, true) => Transform::Rot270,
+            (true, true, true) => Transform::FlipAntiDiagonal,
+        }
+    }
+
+    //////////////////////////////////
+    // Group Operations
+    //////////////////////////////////
+
+    /// Get the transform equivalent to applying `self` and then `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Transform;
+    ///
+    /// assert_eq!(Transform::Rot180, Transform::Rot90.compose(Transform::Rot90));
+    /// assert_eq!(Transform::Rot90, Transform::FlipDiagonal.compose(Transform::FlipH));
+    /// ```
+    pub fn compose(self, other: Transform) -> Transform {
+        let (swap1, col1, row1) = self.parts();
+        let (swap2, col2, row2) = other.parts();
+
+        // Swapping axes in the second step exchanges which axis the first
+        // step's mirroring applies to.
+        let (col1, row1) = if swap2 { (row1, col1) } else { (col1, row1) };
+
+        Self::from_parts((swap1 ^ swap2, col1 ^ col2, row1 ^ row2))
+    }
+
+    /// Get the transform that undoes this one.
+    pub fn inverse(self) -> Transform {
+        match self {
+            Transform::Rot90 => Transform::Rot270,
+            Transform::Rot270 => Transform::Rot90,
+            other => other,
+        }
+    }
+
+    /// Determine if the transform swaps the column and row counts.
+    pub fn swaps_axes(self) -> bool {
+        self.parts().0
+    }
+
+    /// Get the (column, row) dimensions of a grid after transformation.
+    pub fn apply_dims(self, (col_count, row_count): (usize, usize)) -> (usize, usize) {
+        if self.swaps_axes() {
+            (row_count, col_count)
+        } else {
+            (col_count, row_count)
+        }
+    }
+
+    /// Get the coordinate a cell moves to when a grid with the given
+    /// (column, row) dimensions is transformed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::Transform;
+    ///
+    /// // The top-left corner of a 2x3 grid becomes the top-right corner.
+    /// assert_eq!((2, 0), Transform::Rot90.apply_coord((0, 0), (2, 3)));
+    /// ```
+    pub fn apply_coord(self, (col, row): Coord, dims: (usize, usize)) -> Coord {
+        let (swap, mirror_col, mirror_row) = self.parts();
+        let (col_count, row_count) = self.apply_dims(dims);
+        let (col, row) = if swap { (row, col) } else { (col, row) };
+
+        let col = if mirror_col { col_count - 1 - col } else { col };
+        let row = if mirror_row { row_count - 1 - row } else { row };
+
+        (col, row)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Grid Integration
+//////////////////////////////////////////////////////////////////////////////
+
+impl<T> Grid<T>
+where
+    T: Clone,
+{
+    /// Create a copy of the grid reoriented by a `Transform`.
+    ///
+    /// Cell `c` of this grid ends up at `t.apply_coord(c, dims)` in the
+    /// result.
+    pub fn apply_transform(&self, t: Transform) -> Self {
+        match t {
+            Transform::Identity => self.clone(),
+            Transform::Rot90 => self.rotate_cw(),
+            Transform::Rot180 => self.rotate_180(),
+            Transform::Rot270 => self.rotate_ccw(),
+            Transform::FlipH => self.flip_horizontal(),
+            Transform::FlipV => self.flip_vertical(),
+            Transform::FlipDiagonal => self.transpose(),
+            Transform::FlipAntiDiagonal => {
+                let mut grid = self.transpose();
+                grid.rotate_180_in_place();
+
+                grid
+            }
+        }
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: PartialEq,
+{
+    //////////////////////////////////
+    // Symmetry Queries
+    //////////////////////////////////
+
+    /// Determine if the grid is unchanged by a `Transform`.
+    ///
+    /// Cells are compared in place against their images, so nothing is
+    /// allocated. Transforms that would swap the dimensions of a
+    /// rectangular grid never leave it unchanged.
+    pub fn is_symmetric_under(&self, t: Transform) -> bool {
+        let dims = (self.col_count(), self.row_count());
+
+        if t.apply_dims(dims) != dims {
+            return false;
+        }
+
+        (0..dims.1).all(|row| {
+            (0..dims.0).all(|col| {
+                let image = t.apply_coord((col, row), dims);
+                self.get((col, row)) == self.get(image)
+            })
+        })
+    }
+
+    /// Determine if every row reads the same forwards and backwards, i.e.
+    /// the grid is unchanged by `flip_horizontal`.
+    pub fn is_symmetric_horizontal(&self) -> bool {
+        self.is_symmetric_under(Transform::FlipH)
+    }
+
+    /// Determine if every column reads the same forwards and backwards,
+    /// i.e. the grid is unchanged by `flip_vertical`.
+    pub fn is_symmetric_vertical(&self) -> bool {
+        self.is_symmetric_under(Transform::FlipV)
+    }
+
+    /// Determine if the grid is unchanged by a half-turn.
+    pub fn is_symmetric_rot180(&self) -> bool {
+        self.is_symmetric_under(Transform::Rot180)
+    }
+
+    /// Determine if the grid is unchanged by a quarter-turn.
+    ///
+    /// Always `false` for non-square grids.
+    pub fn is_symmetric_rot90(&self) -> bool {
+        self.is_symmetric_under(Transform::Rot90)
+    }
+
+    /// Determine if the grid is unchanged by mirroring across its main
+    /// diagonal, i.e. by `transpose`.
+    ///
+    /// Always `false` for non-square grids.
+    pub fn is_symmetric_diagonal(&self) -> bool {
+        self.is_symmetric_under(Transform::FlipDiagonal)
+    }
+
+    /// Get the transforms which keep the grid's shape: all eight for square
+    /// grids, and the four that don't swap axes otherwise.
+    fn shape_preserving_transforms(&self) -> impl Iterator<Item = Transform> {
+        let dims = (self.col_count(), self.row_count());
+
+        Transform::ALL
+            .iter()
+            .copied()
+            .filter(move |t| t.apply_dims(dims) == dims)
+    }
+
+    /// Get the cell at `coord` of this grid's image under `t`, without
+    /// materializing the image.
+    fn image_cell(&self, t: Transform, coord: Coord) -> Option<&T> {
+        let dims = t.apply_dims((self.col_count(), self.row_count()));
+
+        self.get(t.inverse().apply_coord(coord, dims))
+    }
+
+    /// Determine if `other` is one of this grid's symmetry images.
+    ///
+    /// Only shape-preserving transforms are considered (see
+    /// `canonical_form`). Each candidate is compared in place and abandoned
+    /// at its first mismatch, so nothing is allocated.
+    pub fn eq_up_to_symmetry(&self, other: &Grid<T>) -> bool {
+        let dims = (other.col_count(), other.row_count());
+
+        self.shape_preserving_transforms()
+            .filter(|t| t.apply_dims((self.col_count(), self.row_count())) == dims)
+            .any(|t| {
+                (0..dims.1).all(|row| {
+                    (0..dims.0).all(|col| {
+                        self.image_cell(t, (col, row)) == other.get((col, row))
+                    })
+                })
+            })
+    }
+}
+
+impl<T> Grid<T>
+where
+    T: Ord + Clone,
+{
+    /// Get the smallest of this grid's symmetry images, along with the
+    /// transform producing it.
+    ///
+    /// Two grids are equal up to symmetry exactly when their canonical
+    /// forms are equal. Square grids consider all eight transforms; other
+    /// grids consider only the four that keep their shape, so a rectangle
+    /// and its quarter-turn are treated as different shapes.
+    ///
+    /// Images are ordered by comparing cells in row-major order. When
+    /// several transforms produce the smallest image, the one listed first
+    /// in `Transform::ALL` is returned, so a fully symmetric grid yields
+    /// itself and `Transform::Identity`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Grid, Transform};
+    ///
+    /// let mut grid = Grid::square(2, 1);
+    /// grid.set((0, 0), 0);
+    ///
+    /// let rotated = grid.rotate_cw();
+    /// assert_eq!(grid.canonical_form().0, rotated.canonical_form().0);
+    /// assert_eq!(Transform::Identity, grid.canonical_form().1);
+    /// ```
+    pub fn canonical_form(&self) -> (Grid<T>, Transform) {
+        let dims = (self.col_count(), self.row_count());
+        let mut best = Transform::Identity;
+
+        for t in self.shape_preserving_transforms() {
+            let ordering = (0..dims.1)
+                .flat_map(|row| (0..dims.0).map(move |col| (col, row)))
+                .map(|coord| self.image_cell(t, coord).cmp(&self.image_cell(best, coord)))
+                .find(|&ordering| ordering != Ordering::Equal);
+
+            if ordering == Some(Ordering::Less) {
+                best = t;
+            }
+        }
+
+        (self.apply_transform(best), best)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::labeled;
+    use proptest::prelude::*;
+
+    fn any_transform() -> impl Strategy<Value = Transform> {
+        prop::sample::select(Transform::ALL.to_vec())
+    }
+
+    #[test]
+    fn test_apply_transform_matches_methods() {
+        let grid = labeled(2, 3);
+
+        assert_eq!(grid, grid.apply_transform(Transform::Identity));
+        assert_eq!(grid.rotate_cw(), grid.apply_transform(Transform::Rot90));
+        assert_eq!(grid.rotate_ccw(), grid.apply_transform(Transform::Rot270));
+        assert_eq!(
+            grid.rotate_cw().flip_vertical(),
+            grid.apply_transform(Transform::FlipAntiDiagonal)
+        );
+    }
+
+    #[test]
+    fn test_rotations_compose() {
+        let mut t = Transform::Identity;
+
+        for _ in 0..4 {
+            t = t.compose(Transform::Rot90);
+        }
+
+        assert_eq!(Transform::Identity, t);
+        assert_eq!(Transform::Rot270, Transform::Rot180.compose(Transform::Rot90));
+    }
+
+    /// Build a grid from rows of characters.
+    fn chars(rows: &[&str]) -> Grid<char> {
+        let mut grid = Grid::new(rows[0].len(), rows.len(), ' ');
+
+        for (row, line) in rows.iter().enumerate() {
+            for (col, ch) in line.chars().enumerate() {
+                grid.set((col, row), ch);
+            }
+        }
+
+        grid
+    }
+
+    #[test]
+    fn test_symmetric_horizontal() {
+        assert!(chars(&["abcba", "xyzyx"]).is_symmetric_horizontal());
+        assert!(chars(&["abba"]).is_symmetric_horizontal());
+        assert!(!chars(&["abca", "xyzx"]).is_symmetric_horizontal());
+        assert!(!chars(&["abcba", "xyzxy"]).is_symmetric_horizontal());
+    }
+
+    #[test]
+    fn test_symmetric_vertical() {
+        assert!(chars(&["ab", "cd", "ab"]).is_symmetric_vertical());
+        assert!(chars(&["ab", "ab"]).is_symmetric_vertical());
+        assert!(!chars(&["ab", "cd", "ba"]).is_symmetric_vertical());
+    }
+
+    #[test]
+    fn test_symmetric_rot180() {
+        assert!(chars(&["abc", "dxd", "cba"]).is_symmetric_rot180());
+        assert!(chars(&["ab", "ba"]).is_symmetric_rot180());
+        assert!(chars(&["abcd", "dcba"]).is_symmetric_rot180());
+        assert!(!chars(&["abc", "dxe", "cba"]).is_symmetric_rot180());
+    }
+
+    #[test]
+    fn test_symmetric_rot90() {
+        assert!(chars(&["aba", "bxb", "aba"]).is_symmetric_rot90());
+        assert!(!chars(&["ab", "ba"]).is_symmetric_rot90());
+        assert!(!chars(&["aba", "bxc", "aba"]).is_symmetric_rot90());
+        assert!(!chars(&["aa", "aa", "aa"]).is_symmetric_rot90());
+    }
+
+    #[test]
+    fn test_symmetric_diagonal() {
+        assert!(chars(&["abc", "bxd", "cde"]).is_symmetric_diagonal());
+        assert!(!chars(&["abc", "bxd", "cfe"]).is_symmetric_diagonal());
+        assert!(!chars(&["aa", "aa", "aa"]).is_symmetric_diagonal());
+    }
+
+    #[test]
+    fn test_canonical_form_of_images() {
+        let grid = chars(&["abc", "def", "ghi"]);
+        let (canonical, _) = grid.canonical_form();
+
+        for &t in Transform::ALL.iter() {
+            let image = grid.apply_transform(t);
+            let (image_canonical, image_t) = image.canonical_form();
+
+            assert_eq!(canonical, image_canonical);
+            assert_eq!(image_canonical, image.apply_transform(image_t));
+            assert!(grid.eq_up_to_symmetry(&image));
+        }
+
+        assert_eq!(chars(&["abc", "def", "ghi"]), canonical);
+    }
+
+    #[test]
+    fn test_canonical_form_picks_smallest() {
+        let grid = chars(&["ih", "gf"]);
+        let (canonical, t) = grid.canonical_form();
+
+        assert_eq!(chars(&["fg", "hi"]), canonical);
+        assert_eq!(Transform::Rot180, t);
+    }
+
+    #[test]
+    fn test_canonical_form_symmetric() {
+        let grid = chars(&["aba", "bcb", "aba"]);
+
+        assert_eq!((grid.clone(), Transform::Identity), grid.canonical_form());
+    }
+
+    #[test]
+    fn test_canonical_form_non_square() {
+        let grid = chars(&["cab", "fed"]);
+        let (canonical, t) = grid.canonical_form();
+
+        assert_eq!(chars(&["bac", "def"]), canonical);
+        assert_eq!(Transform::FlipH, t);
+
+        for &t in Transform::ALL.iter() {
+            let image = grid.apply_transform(t);
+            assert_eq!(!t.swaps_axes(), grid.eq_up_to_symmetry(&image));
+        }
+    }
+
+    #[test]
+    fn test_eq_up_to_symmetry_mismatch() {
+        let grid = chars(&["ab", "cd"]);
+
+        assert!(!grid.eq_up_to_symmetry(&chars(&["ab", "dc"])));
+        assert!(!grid.eq_up_to_symmetry(&chars(&["abc", "def"])));
+    }
+
+    proptest! {
+        #[test]
+        fn prop_compose_then_apply(
+            a in any_transform(),
+            b in any_transform(),
+            cols in 0usize..6,
+            rows in 0usize..6,
+        ) {
+            let grid = labeled(cols, rows);
+
+            prop_assert_eq!(
+                grid.apply_transform(a).apply_transform(b),
+                grid.apply_transform(a.compose(b))
+            );
+        }
+
+        #[test]
+        fn prop_compose_with_inverse(t in any_transform()) {
+            prop_assert_eq!(Transform::Identity, t.compose(t.inverse()));
+            prop_assert_eq!(Transform::Identity, t.inverse().compose(t));
+        }
+
+        #[test]
+        fn prop_compose_associative(
+            a in any_transform(),
+            b in any_transform(),
+            c in any_transform(),
+        ) {
+            prop_assert_eq!(a.compose(b).compose(c), a.compose(b.compose(c)));
+        }
+
+        #[test]
+        fn prop_apply_matches_coords(
+            t in any_transform(),
+            cols in 1usize..6,
+            rows in 1usize..6,
+        ) {
+            let grid = labeled(cols, rows);
+            let transformed = grid.apply_transform(t);
+
+            prop_assert_eq!(
+                t.apply_dims((cols, rows)),
+                (transformed.col_count(), transformed.row_count())
+            );
+
+            for row in 0..rows {
+                for col in 0..cols {
+                    let moved = t.apply_coord((col, row), (cols, rows));
+                    prop_assert_eq!(grid.get((col, row)), transformed.get(moved));
+                }
+            }
+        }
+    }
+}
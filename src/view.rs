@@ -0,0 +1,1115 @@
+//! Borrowed windows onto part of a `Grid`.
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::iter::StepBy;
+use core::marker::PhantomData;
+use core::slice;
+
+use crate::{ColumnIter, Coord, Grid, Offset, Rect};
+
+//////////////////////////////////////////////////////////////////////////////
+// Read-Only Views
+//////////////////////////////////////////////////////////////////////////////
+
+/// A borrowed, read-only rectangular region of a `Grid`.
+///
+/// Created by `Grid::view`. Coordinates passed to a view are local to it:
+/// (0, 0) is the region's top-left cell.
+#[derive(Debug)]
+pub struct GridView<'a, T> {
+    /// The full parent rows the view spans.
+    cells: &'a [T],
+    /// The parent's column count.
+    stride: usize,
+    /// The parent column of the view's first column.
+    col_start: usize,
+    col_count: usize,
+    row_count: usize,
+}
+
+impl<'a, T> Clone for GridView<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for GridView<'a, T> {}
+
+impl<'a, T> GridView<'a, T> {
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Create a view of a region already known to lie within `grid`.
+    pub(crate) fn new(
+        grid: &'a Grid<T>,
+        origin: Coord,
+        col_count: usize,
+        row_count: usize,
+    ) -> Self {
+        debug_assert!(origin.0 + col_count <= grid.col_count());
+        debug_assert!(origin.1 + row_count <= grid.row_count());
+
+        let stride = grid.col_count();
+        let cells = &grid.data[stride * origin.1..stride * (origin.1 + row_count)];
+
+        Self::from_rows(cells, stride, origin.0, col_count, row_count)
+    }
+
+    /// Create a view of columns `col_start..col_start + col_count` of some
+    /// full parent rows.
+    fn from_rows(
+        cells: &'a [T],
+        stride: usize,
+        col_start: usize,
+        col_count: usize,
+        row_count: usize,
+    ) -> Self {
+        debug_assert_eq!(stride * row_count, cells.len());
+        debug_assert!(col_start + col_count <= stride);
+
+        Self {
+            cells,
+            stride,
+            col_start,
+            col_count,
+            row_count,
+        }
+    }
+
+    //////////////////////////////////
+    // Get & Set
+    //////////////////////////////////
+
+    /// Get the view's column count.
+    pub fn col_count(&self) -> usize {
+        self.col_count
+    }
+
+    /// Get the view's row count.
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// Get an immutable reference to some cell of the view.
+    pub fn get(&self, (col, row): Coord) -> Option<&'a T> {
+        if self.contains((col, row)) {
+            Some(&self.cells[self.stride * row + self.col_start + col])
+        } else {
+            None
+        }
+    }
+
+    /// Get an immutable reference to the cell with the given positional
+    /// relationship to the provided coordinate.
+    ///
+    /// Like `get`, this only reaches cells inside the view.
+    pub fn rget(&self, anchor: Coord, vec: Offset) -> Option<&'a T> {
+        vec.rcoord(anchor).and_then(|coord| self.get(coord))
+    }
+
+    /// Get a row of the view as a slice.
+    pub fn row(&self, row: usize) -> Option<&'a [T]> {
+        if row < self.row_count {
+            let start = self.stride * row + self.col_start;
+
+            Some(&self.cells[start..start + self.col_count])
+        } else {
+            None
+        }
+    }
+
+    //////////////////////////////////
+    // Iteration
+    //////////////////////////////////
+
+    /// Iterate over the view's rows, top to bottom, as slices.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + 'a {
+        let view = *self;
+
+        (0..self.row_count).filter_map(move |row| view.row(row))
+    }
+
+    /// Iterate over the view's cells in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        self.rows().flatten()
+    }
+
+    /// Iterate over the view's cells in row-major order, along with their
+    /// local coordinates.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Coord, &'a T)> + 'a {
+        self.rows().enumerate().flat_map(|(row, cells)| {
+            cells
+                .iter()
+                .enumerate()
+                .map(move |(col, cell)| ((col, row), cell))
+        })
+    }
+
+    //////////////////////////////////
+    // Nesting & Copying
+    //////////////////////////////////
+
+    /// Borrow a rectangular region of the view, in local coordinates.
+    ///
+    /// Returns `None` unless the rectangle lies entirely within this view,
+    /// even where it would still fit within the parent grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use gridd::{Grid, Rect};
+    ///
+    /// let mut grid = Grid::new(4, 4, 0);
+    /// grid.set((2, 2), 1);
+    ///
+    /// let outer = grid.view(Rect::new((1, 1), 3, 3)).unwrap();
+    /// let inner = outer.view(Rect::new((1, 1), 2, 2)).unwrap();
+    /// assert_eq!(Some(&1), inner.get((0, 0)));
+    /// assert!(outer.view(Rect::new((2, 2), 2, 2)).is_none());
+    /// ```
+    pub fn view(&self, rect: impl Into<Rect>) -> Option<GridView<'a, T>> {
+        let rect = rect.into();
+
+        if local_bounds(self.col_count, self.row_count).contains_rect(rect) {
+            let row_start = self.stride * rect.origin.1;
+            let cells = &self.cells[row_start..row_start + self.stride * rect.row_count];
+
+            Some(GridView::from_rows(
+                cells,
+                self.stride,
+                self.col_start + rect.origin.0,
+                rect.col_count,
+                rect.row_count,
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Copy the viewed region into a new grid.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        let mut data = Vec::with_capacity(self.col_count * self.row_count);
+
+        for row in self.rows() {
+            data.extend_from_slice(row);
+        }
+
+        Grid {
+            col_count: self.col_count,
+            row_count: self.row_count,
+            data,
+        }
+    }
+
+    //////////////////////////////////
+    // Boolean Operations
+    //////////////////////////////////
+
+    /// Determine if a local coordinate is within the view.
+    pub fn contains(&self, (col, row): Coord) -> bool {
+        col < self.col_count && row < self.row_count
+    }
+}
+
+/// Get the rectangle covering a view in its own local coordinates.
+fn local_bounds(col_count: usize, row_count: usize) -> Rect {
+    Rect::new((0, 0), col_count, row_count)
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Transposed Views
+//////////////////////////////////////////////////////////////////////////////
+
+/// A borrowed, read-only view of a `Grid` with its axes swapped.
+///
+/// Created by `Grid::transposed`. Cell (col, row) of the view is cell
+/// (row, col) of the grid, so the view's rows are the grid's columns.
+#[derive(Debug)]
+pub struct TransposedView<'a, T> {
+    grid: &'a Grid<T>,
+}
+
+impl<'a, T> Clone for TransposedView<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for TransposedView<'a, T> {}
+
+impl<'a, T> TransposedView<'a, T> {
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Create a transposed view of a whole grid.
+    pub(crate) fn new(grid: &'a Grid<T>) -> Self {
+        Self { grid }
+    }
+
+    //////////////////////////////////
+    // Get & Set
+    //////////////////////////////////
+
+    /// Get the view's column count, which is the grid's row count.
+    pub fn col_count(&self) -> usize {
+        self.grid.row_count()
+    }
+
+    /// Get the view's row count, which is the grid's column count.
+    pub fn row_count(&self) -> usize {
+        self.grid.col_count()
+    }
+
+    /// Get an immutable reference to some cell of the view.
+    pub fn get(&self, (col, row): Coord) -> Option<&'a T> {
+        self.grid.get((row, col))
+    }
+
+    /// Get an immutable reference to the cell with the given positional
+    /// relationship to the provided coordinate.
+    pub fn rget(&self, anchor: Coord, vec: Offset) -> Option<&'a T> {
+        vec.rcoord(anchor).and_then(|coord| self.get(coord))
+    }
+
+    //////////////////////////////////
+    // Iteration
+    //////////////////////////////////
+
+    /// Iterate over the view's rows, top to bottom.
+    ///
+    /// Each row is one of the grid's columns, which are not contiguous in
+    /// memory, so rows are yielded as iterators rather than slices.
+    pub fn rows(&self) -> impl Iterator<Item = ColumnIter<'a, T>> + 'a {
+        let grid = self.grid;
+
+        (0..grid.col_count()).map(move |col| ColumnIter::new(&grid.data, col, grid.col_count()))
+    }
+
+    /// Iterate over the view's cells in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        self.rows().flatten()
+    }
+
+    /// Iterate over the view's cells in row-major order, along with their
+    /// coordinates in the view.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Coord, &'a T)> + 'a {
+        self.rows().enumerate().flat_map(|(row, cells)| {
+            cells
+                .enumerate()
+                .map(move |(col, cell)| ((col, row), cell))
+        })
+    }
+
+    //////////////////////////////////
+    // Boolean Operations
+    //////////////////////////////////
+
+    /// Determine if a coordinate is within the view.
+    pub fn contains(&self, (col, row): Coord) -> bool {
+        self.grid.contains((row, col))
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Mapped Views
+//////////////////////////////////////////////////////////////////////////////
+
+/// A borrowed, read-only view of a `Grid` that passes each cell through a
+/// function as it is read.
+///
+/// Created by `Grid::map_view`. Nothing is computed up front: the function
+/// runs on every access, so reading the same cell twice calls it twice.
+pub struct MapView<'a, T, U, F> {
+    grid: &'a Grid<T>,
+    f: F,
+    mapped: PhantomData<fn() -> U>,
+}
+
+impl<'a, T, U, F> Clone for MapView<'a, T, U, F>
+where
+    F: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            grid: self.grid,
+            f: self.f.clone(),
+            mapped: PhantomData,
+        }
+    }
+}
+
+impl<'a, T, U, F> fmt::Debug for MapView<'a, T, U, F>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MapView")
+            .field("grid", self.grid)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, T, U, F> MapView<'a, T, U, F>
+where
+    F: Fn(&'a T) -> U,
+{
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Create a mapped view of a whole grid.
+    pub(crate) fn new(grid: &'a Grid<T>, f: F) -> Self {
+        Self {
+            grid,
+            f,
+            mapped: PhantomData,
+        }
+    }
+
+    //////////////////////////////////
+    // Get & Set
+    //////////////////////////////////
+
+    /// Get the view's column count.
+    pub fn col_count(&self) -> usize {
+        self.grid.col_count()
+    }
+
+    /// Get the view's row count.
+    pub fn row_count(&self) -> usize {
+        self.grid.row_count()
+    }
+
+    /// Get the mapped value of some cell.
+    pub fn get(&self, coord: Coord) -> Option<U> {
+        self.grid.get(coord).map(&self.f)
+    }
+
+    /// Get the mapped value of the cell with the given positional
+    /// relationship to the provided coordinate.
+    pub fn rget(&self, anchor: Coord, vec: Offset) -> Option<U> {
+        self.grid.rget(anchor, vec).map(&self.f)
+    }
+
+    //////////////////////////////////
+    // Iteration
+    //////////////////////////////////
+
+    /// Iterate over the mapped values in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = U> + use<'_, 'a, T, U, F> {
+        self.grid.iter().map(&self.f)
+    }
+
+    /// Iterate over the mapped values in row-major order, along with their
+    /// coordinates.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Coord, U)> + use<'_, 'a, T, U, F> {
+        self.grid
+            .indexed_iter()
+            .map(move |(coord, cell)| (coord, (self.f)(cell)))
+    }
+
+    //////////////////////////////////
+    // Boolean Operations
+    //////////////////////////////////
+
+    /// Determine if a coordinate is within the view.
+    pub fn contains(&self, coord: Coord) -> bool {
+        self.grid.contains(coord)
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Strided Views
+//////////////////////////////////////////////////////////////////////////////
+
+/// A borrowed, read-only view of every nth column and row of a `Grid`.
+///
+/// Created by `Grid::strided` or `Grid::strided_from`. Cell (col, row) of
+/// the view is cell `(origin.0 + col * col_step, origin.1 + row * row_step)`
+/// of the grid.
+#[derive(Debug)]
+pub struct StridedView<'a, T> {
+    grid: &'a Grid<T>,
+    origin: Coord,
+    col_step: usize,
+    row_step: usize,
+    col_count: usize,
+    row_count: usize,
+}
+
+impl<'a, T> Clone for StridedView<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for StridedView<'a, T> {}
+
+impl<'a, T> StridedView<'a, T> {
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Create a strided view starting at `origin`, which may lie outside
+    /// the grid, in which case the view is empty.
+    pub(crate) fn new(grid: &'a Grid<T>, origin: Coord, col_step: usize, row_step: usize) -> Self {
+        assert!(col_step > 0 && row_step > 0, "strides must be non-zero");
+
+        let sampled = |len: usize, start: usize, step: usize| {
+            len.saturating_sub(start).div_ceil(step)
+        };
+
+        Self {
+            grid,
+            origin,
+            col_step,
+            row_step,
+            col_count: sampled(grid.col_count(), origin.0, col_step),
+            row_count: sampled(grid.row_count(), origin.1, row_step),
+        }
+    }
+
+    //////////////////////////////////
+    // Get & Set
+    //////////////////////////////////
+
+    /// Get the view's column count.
+    pub fn col_count(&self) -> usize {
+        self.col_count
+    }
+
+    /// Get the view's row count.
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// Get an immutable reference to some cell of the view.
+    pub fn get(&self, coord: Coord) -> Option<&'a T> {
+        if self.contains(coord) {
+            self.grid.get((
+                self.origin.0 + coord.0 * self.col_step,
+                self.origin.1 + coord.1 * self.row_step,
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Get an immutable reference to the cell with the given positional
+    /// relationship to the provided coordinate, in view coordinates.
+    pub fn rget(&self, anchor: Coord, vec: Offset) -> Option<&'a T> {
+        vec.rcoord(anchor).and_then(|coord| self.get(coord))
+    }
+
+    //////////////////////////////////
+    // Iteration
+    //////////////////////////////////
+
+    /// Iterate over the view's rows, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = StepBy<slice::Iter<'a, T>>> + 'a {
+        let view = *self;
+
+        (0..self.row_count).map(move |row| {
+            let start = view.grid.flat_index((view.origin.0, view.origin.1 + row * view.row_step));
+            let end = start + view.col_count.saturating_sub(1) * view.col_step + 1;
+            let cells = if view.col_count == 0 { &[] } else { &view.grid.data[start..end] };
+
+            cells.iter().step_by(view.col_step)
+        })
+    }
+
+    /// Iterate over the view's cells in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + 'a {
+        self.rows().flatten()
+    }
+
+    /// Iterate over the view's cells in row-major order, along with their
+    /// coordinates in the view.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = (Coord, &'a T)> + 'a {
+        self.rows().enumerate().flat_map(|(row, cells)| {
+            cells
+                .enumerate()
+                .map(move |(col, cell)| ((col, row), cell))
+        })
+    }
+
+    //////////////////////////////////
+    // Boolean Operations
+    //////////////////////////////////
+
+    /// Determine if a coordinate is within the view.
+    pub fn contains(&self, (col, row): Coord) -> bool {
+        col < self.col_count && row < self.row_count
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Mutable Views
+//////////////////////////////////////////////////////////////////////////////
+
+/// A borrowed, mutable rectangular region of a `Grid`.
+///
+/// Created by `Grid::view_mut`. Coordinates passed to a view are local to
+/// it, and no method can reach cells outside the region.
+#[derive(Debug)]
+pub struct GridViewMut<'a, T> {
+    /// The full parent rows the view spans.
+    cells: &'a mut [T],
+    /// The parent's column count.
+    stride: usize,
+    /// The parent column of the view's first column.
+    col_start: usize,
+    col_count: usize,
+    row_count: usize,
+}
+
+impl<'a, T> GridViewMut<'a, T> {
+    //////////////////////////////////
+    // Instantiation
+    //////////////////////////////////
+
+    /// Create a view of a region already known to lie within `grid`.
+    pub(crate) fn new(
+        grid: &'a mut Grid<T>,
+        origin: Coord,
+        col_count: usize,
+        row_count: usize,
+    ) -> Self {
+        debug_assert!(origin.0 + col_count <= grid.col_count());
+        debug_assert!(origin.1 + row_count <= grid.row_count());
+
+        let stride = grid.col_count();
+        let cells = &mut grid.data[stride * origin.1..stride * (origin.1 + row_count)];
+
+        Self::from_rows(cells, stride, origin.0, col_count, row_count)
+    }
+
+    /// Create a view of columns `col_start..col_start + col_count` of some
+    /// full parent rows.
+    pub(crate) fn from_rows(
+        cells: &'a mut [T],
+        stride: usize,
+        col_start: usize,
+        col_count: usize,
+        row_count: usize,
+    ) -> Self {
+        debug_assert_eq!(stride * row_count, cells.len());
+        debug_assert!(col_start + col_count <= stride);
+
+        Self {
+            cells,
+            stride,
+            col_start,
+            col_count,
+            row_count,
+        }
+    }
+
+    //////////////////////////////////
+    // Get & Set
+    //////////////////////////////////
+
+    /// Get the view's column count.
+    pub fn col_count(&self) -> usize {
+        self.col_count
+    }
+
+    /// Get the view's row count.
+    pub fn row_count(&self) -> usize {
+        self.row_count
+    }
+
+    /// Get the index into `cells` of a local coordinate within the view.
+    fn cell_index(&self, (col, row): Coord) -> Option<usize> {
+        if self.contains((col, row)) {
+            Some(self.stride * row + self.col_start + col)
+        } else {
+            None
+        }
+    }
+
+    /// Get an immutable reference to some cell of the view.
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.cell_index(coord).map(|index| &self.cells[index])
+    }
+
+    /// Get a mutable reference to some cell of the view.
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        match self.cell_index(coord) {
+            Some(index) => Some(&mut self.cells[index]),
+            None => None,
+        }
+    }
+
+    /// Set a cell's value. Coordinates outside the view are ignored.
+    pub fn set(&mut self, coord: Coord, new_val: T) {
+        if let Some(val) = self.get_mut(coord) {
+            *val = new_val;
+        }
+    }
+
+    /// Set every cell of the view to a value.
+    pub fn fill(&mut self, val: T)
+    where
+        T: Clone,
+    {
+        for row in self.rows_mut() {
+            row.fill(val.clone());
+        }
+    }
+
+    //////////////////////////////////
+    // Iteration
+    //////////////////////////////////
+
+    /// Iterate over the view's rows, top to bottom, as mutable slices.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> + '_ {
+        let stride = self.stride;
+        let cols = self.col_start..self.col_start + self.col_count;
+        let mut rest = &mut *self.cells;
+
+        // Each view row is a sub-slice of one parent row, so peel parent
+        // rows off the front of the buffer one at a time.
+        (0..self.row_count).map(move |_| {
+            let (row, tail) = core::mem::take(&mut rest).split_at_mut(stride);
+            rest = tail;
+
+            &mut row[cols.clone()]
+        })
+    }
+
+    /// Iterate mutably over the view's cells in row-major order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        self.rows_mut().flatten()
+    }
+
+    /// Iterate mutably over the view's cells in row-major order, along with
+    /// their local coordinates.
+    pub fn indexed_iter_mut(&mut self) -> impl Iterator<Item = (Coord, &mut T)> + '_ {
+        self.rows_mut().enumerate().flat_map(|(row, cells)| {
+            cells
+                .iter_mut()
+                .enumerate()
+                .map(move |(col, cell)| ((col, row), cell))
+        })
+    }
+
+    //////////////////////////////////
+    // Nesting & Copying
+    //////////////////////////////////
+
+    /// Reborrow the whole view as a read-only `GridView`.
+    fn as_view(&self) -> GridView<'_, T> {
+        GridView::from_rows(
+            &*self.cells,
+            self.stride,
+            self.col_start,
+            self.col_count,
+            self.row_count,
+        )
+    }
+
+    /// Immutably borrow a rectangular region of the view, in local
+    /// coordinates.
+    ///
+    /// Returns `None` unless the rectangle lies entirely within this view.
+    pub fn view(&self, rect: impl Into<Rect>) -> Option<GridView<'_, T>> {
+        self.as_view().view(rect)
+    }
+
+    /// Mutably borrow a rectangular region of the view, in local
+    /// coordinates.
+    ///
+    /// Returns `None` unless the rectangle lies entirely within this view.
+    /// The returned view borrows this one, so the two cannot be used at
+    /// the same time.
+    pub fn view_mut(&mut self, rect: impl Into<Rect>) -> Option<GridViewMut<'_, T>> {
+        let rect = rect.into();
+
+        if local_bounds(self.col_count, self.row_count).contains_rect(rect) {
+            let row_start = self.stride * rect.origin.1;
+            let cells = &mut self.cells[row_start..row_start + self.stride * rect.row_count];
+
+            Some(GridViewMut::from_rows(
+                cells,
+                self.stride,
+                self.col_start + rect.origin.0,
+                rect.col_count,
+                rect.row_count,
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Copy the viewed region into a new grid.
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        self.as_view().to_grid()
+    }
+
+    //////////////////////////////////
+    // Boolean Operations
+    //////////////////////////////////
+
+    /// Determine if a local coordinate is within the view.
+    pub fn contains(&self, (col, row): Coord) -> bool {
+        col < self.col_count && row < self.row_count
+    }
+}
+
+//////////////////////////////////////////////////////////////////////////////
+// Unit Tests
+//////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::labeled;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_view_corners() {
+        let grid = labeled(5, 4);
+        let view = grid.view(Rect::new((1, 2), 3, 2)).unwrap();
+
+        assert_eq!((3, 2), (view.col_count(), view.row_count()));
+        assert_eq!(Some(&11), view.get((0, 0)));
+        assert_eq!(Some(&13), view.get((2, 0)));
+        assert_eq!(Some(&16), view.get((0, 1)));
+        assert_eq!(Some(&18), view.get((2, 1)));
+        assert_eq!(None, view.get((3, 0)));
+        assert_eq!(None, view.get((0, 2)));
+    }
+
+    #[test]
+    fn test_view_rget() {
+        let grid = labeled(5, 4);
+        let view = grid.view(Rect::new((1, 1), 3, 3)).unwrap();
+
+        assert_eq!(Some(&7), view.rget((1, 1), Offset::NORTH));
+        assert_eq!(Some(&13), view.rget((1, 1), Offset::EAST));
+        assert_eq!(None, view.rget((0, 0), Offset::WEST));
+        assert_eq!(None, view.rget((2, 2), Offset::SOUTH));
+    }
+
+    #[test]
+    fn test_view_iteration() {
+        let grid = labeled(4, 3);
+        let view = grid.view(((1, 1), (2, 2))).unwrap();
+
+        assert_eq!(vec![5, 6, 9, 10], view.iter().copied().collect::<Vec<_>>());
+        assert_eq!(vec![&[5, 6][..], &[9, 10][..]], view.rows().collect::<Vec<_>>());
+        for ((col, row), &cell) in view.indexed_iter() {
+            assert_eq!(cell, 1 + col + 4 * (1 + row));
+        }
+        assert_eq!(4, view.indexed_iter().count());
+    }
+
+    #[test]
+    fn test_view_whole_and_empty() {
+        let grid = labeled(3, 2);
+        let whole = grid.view(Rect::new((0, 0), 3, 2)).unwrap();
+
+        assert!(whole.iter().eq(grid.iter()));
+
+        let empty = grid.view(Rect::new((3, 2), 0, 0)).unwrap();
+        assert_eq!(0, empty.iter().count());
+        assert_eq!(None, empty.get((0, 0)));
+
+        assert!(grid.view(Rect::new((2, 0), 2, 1)).is_none());
+    }
+
+    #[test]
+    fn test_view_mut_stays_inside() {
+        let mut grid = labeled(5, 4);
+        let rect = Rect::new((1, 1), 3, 2);
+        let mut view = grid.view_mut(rect).unwrap();
+
+        for (_, cell) in view.indexed_iter_mut() {
+            *cell += 100;
+        }
+        *view.get_mut((2, 1)).unwrap() = 0;
+
+        for ((col, row), &cell) in grid.indexed_iter() {
+            let label = col + 5 * row;
+
+            if (col, row) == (3, 2) {
+                assert_eq!(0, cell);
+            } else if rect.contains((col, row)) {
+                assert_eq!(label + 100, cell);
+            } else {
+                assert_eq!(label, cell);
+            }
+        }
+    }
+
+    #[test]
+    fn test_view_mut_set_outside() {
+        let mut grid = labeled(4, 4);
+        let mut view = grid.view_mut(Rect::new((0, 0), 2, 2)).unwrap();
+
+        view.set((2, 0), 99);
+        view.set((0, 2), 99);
+        assert_eq!(None, view.get_mut((2, 2)));
+        view.set((1, 1), 99);
+
+        assert_eq!(Some(&99), grid.get((1, 1)));
+        assert_eq!(1, grid.iter().filter(|&&cell| cell == 99).count());
+    }
+
+    #[test]
+    fn test_view_mut_rows_and_fill() {
+        let mut grid = labeled(4, 3);
+        let mut view = grid.view_mut(Rect::new((2, 1), 2, 2)).unwrap();
+
+        let rows: Vec<Vec<usize>> = view.rows_mut().map(|row| row.to_vec()).collect();
+        assert_eq!(vec![vec![6, 7], vec![10, 11]], rows);
+
+        view.fill(0);
+        assert_eq!(4, view.iter_mut().count());
+
+        let cells: Vec<usize> = grid.iter().copied().collect();
+        assert_eq!(vec![0, 1, 2, 3, 4, 5, 0, 0, 8, 9, 0, 0], cells);
+    }
+
+    #[test]
+    fn test_view_mut_zero_width() {
+        let mut grid: Grid<usize> = Grid::new(0, 3, 0);
+        let mut view = grid.view_mut(Rect::new((0, 0), 0, 3)).unwrap();
+
+        assert_eq!(3, view.rows_mut().count());
+        assert_eq!(0, view.iter_mut().count());
+    }
+
+    #[test]
+    fn test_nested_view() {
+        let grid = labeled(6, 5);
+        let outer = grid.view(Rect::new((1, 1), 4, 3)).unwrap();
+        let inner = outer.view(Rect::new((2, 1), 2, 2)).unwrap();
+
+        assert_eq!(Some(&15), inner.get((0, 0)));
+        assert_eq!(Some(&22), inner.get((1, 1)));
+        assert_eq!(None, inner.get((2, 0)));
+
+        // Fits in the parent grid, but not in the outer view.
+        assert!(outer.view(Rect::new((3, 0), 2, 1)).is_none());
+        assert!(outer.view(Rect::new((0, 0), 4, 3)).is_some());
+    }
+
+    #[test]
+    fn test_nested_view_mut() {
+        let mut grid = labeled(6, 5);
+        let mut outer = grid.view_mut(Rect::new((1, 1), 4, 3)).unwrap();
+
+        assert!(outer.view_mut(Rect::new((0, 2), 1, 2)).is_none());
+        outer.view_mut(Rect::new((2, 1), 2, 2)).unwrap().set((0, 0), 0);
+        assert_eq!(Some(&0), outer.view(Rect::new((2, 1), 1, 1)).unwrap().get((0, 0)));
+
+        assert_eq!(Some(&0), grid.get((3, 2)));
+    }
+
+    #[test]
+    fn test_to_grid() {
+        let mut grid = labeled(4, 3);
+        let rect = Rect::new((1, 1), 2, 2);
+
+        let copy = grid.view(rect).unwrap().to_grid();
+        assert_eq!(grid.subgrid(rect), Some(copy.clone()));
+        assert_eq!(vec![5, 6, 9, 10], copy.iter().copied().collect::<Vec<_>>());
+
+        assert_eq!(copy, grid.view_mut(rect).unwrap().to_grid());
+        assert_eq!(grid, grid.view(grid.bounds()).unwrap().to_grid());
+    }
+
+    #[test]
+    fn test_split_at_row_mut() {
+        let mut grid = labeled(3, 4);
+        let (mut top, mut bottom) = grid.split_at_row_mut(1);
+
+        assert_eq!((3, 1), (top.col_count(), top.row_count()));
+        assert_eq!((3, 3), (bottom.col_count(), bottom.row_count()));
+
+        // The boundary row is the first row of the bottom half only.
+        assert_eq!(None, top.get((0, 1)));
+        assert_eq!(Some(&3), bottom.get((0, 0)));
+
+        top.fill(100);
+        bottom.set((1, 0), 200);
+        bottom.set((1, 3), 300);
+
+        let mut expected = labeled(3, 4);
+        expected.view_mut(Rect::new((0, 0), 3, 1)).unwrap().fill(100);
+        expected.set((1, 1), 200);
+        assert_eq!(expected, grid);
+    }
+
+    #[test]
+    fn test_split_at_row_mut_ends() {
+        let mut grid = labeled(2, 3);
+
+        let (top, bottom) = grid.split_at_row_mut(0);
+        assert_eq!((0, 3), (top.row_count(), bottom.row_count()));
+
+        let (top, bottom) = grid.split_at_row_mut(3);
+        assert_eq!((3, 0), (top.row_count(), bottom.row_count()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_split_at_row_mut_past_end() {
+        labeled(2, 3).split_at_row_mut(4);
+    }
+
+    #[test]
+    fn test_split_at_row_mut_threads() {
+        let mut grid = Grid::new(5, 6, 0);
+        let (mut top, mut bottom) = grid.split_at_row_mut(2);
+
+        std::thread::scope(|scope| {
+            scope.spawn(move || top.iter_mut().for_each(|cell| *cell += 1));
+            scope.spawn(move || bottom.iter_mut().for_each(|cell| *cell += 2));
+        });
+
+        for ((_, row), &cell) in grid.indexed_iter() {
+            assert_eq!(if row < 2 { 1 } else { 2 }, cell);
+        }
+    }
+
+    #[test]
+    fn test_transposed_dimensions() {
+        let grid = labeled(4, 2);
+        let view = grid.transposed();
+
+        assert_eq!((2, 4), (view.col_count(), view.row_count()));
+        assert!(view.contains((1, 3)));
+        assert!(!view.contains((3, 1)));
+    }
+
+    #[test]
+    fn test_transposed_iteration() {
+        let grid = labeled(3, 2);
+        let view = grid.transposed();
+        let expected = grid.transpose();
+
+        assert!(view.iter().eq(expected.iter()));
+        assert!(view.indexed_iter().eq(expected.indexed_iter()));
+
+        let rows: Vec<Vec<usize>> = view.rows().map(|row| row.copied().collect()).collect();
+        assert_eq!(vec![vec![0, 3], vec![1, 4], vec![2, 5]], rows);
+    }
+
+    #[test]
+    fn test_map_view_matches_map() {
+        let grid = labeled(4, 3);
+        let view = grid.map_view(|&cell| cell * 10 + 1);
+        let eager = grid.map(|&cell| cell * 10 + 1);
+
+        assert_eq!((4, 3), (view.col_count(), view.row_count()));
+        assert!(view.iter().eq(eager.iter().copied()));
+        for (coord, value) in view.indexed_iter() {
+            assert_eq!(eager.get(coord), Some(&value));
+        }
+        assert_eq!(Some(51), view.rget((1, 0), Offset::SOUTH));
+        assert_eq!(None, view.get((4, 0)));
+    }
+
+    #[test]
+    fn test_map_view_is_lazy() {
+        use std::cell::Cell;
+
+        let grid = labeled(100, 100);
+        let calls = Cell::new(0);
+        let view = grid.map_view(|&cell| {
+            calls.set(calls.get() + 1);
+            cell + 1
+        });
+        assert_eq!(0, calls.get());
+
+        assert_eq!(Some(6), view.get((5, 0)));
+        assert_eq!(Some(6), view.get((5, 0)));
+        assert_eq!(None, view.get((100, 0)));
+        assert_eq!(2, calls.get());
+
+        assert_eq!(3, view.iter().take(3).count());
+        assert_eq!(5, calls.get());
+    }
+
+    #[test]
+    fn test_strided_dimensions() {
+        let grid = labeled(7, 4);
+
+        let view = grid.strided(3, 2);
+        assert_eq!((3, 2), (view.col_count(), view.row_count()));
+
+        let view = grid.strided(7, 5);
+        assert_eq!((1, 1), (view.col_count(), view.row_count()));
+
+        let view = grid.strided_from((5, 3), 2, 2);
+        assert_eq!((1, 1), (view.col_count(), view.row_count()));
+
+        let view = grid.strided_from((7, 0), 1, 1);
+        assert_eq!((0, 4), (view.col_count(), view.row_count()));
+        assert_eq!(0, view.iter().count());
+    }
+
+    #[test]
+    fn test_strided_values() {
+        let grid = labeled(7, 4);
+        let view = grid.strided(3, 2);
+
+        let cells: Vec<usize> = view.iter().copied().collect();
+        assert_eq!(vec![0, 3, 6, 14, 17, 20], cells);
+        for ((col, row), &cell) in view.indexed_iter() {
+            assert_eq!(Some(&cell), view.get((col, row)));
+            assert_eq!(3 * col + 7 * 2 * row, cell);
+        }
+        assert_eq!(Some(&17), view.rget((0, 1), Offset::EAST));
+        assert_eq!(None, view.get((3, 0)));
+    }
+
+    #[test]
+    fn test_strided_phase() {
+        let grid = labeled(6, 6);
+        let view = grid.strided_from((1, 2), 2, 3);
+
+        assert_eq!((3, 2), (view.col_count(), view.row_count()));
+        let cells: Vec<usize> = view.iter().copied().collect();
+        assert_eq!(vec![13, 15, 17, 31, 33, 35], cells);
+    }
+
+    #[test]
+    fn test_strided_identity() {
+        let grid = labeled(4, 3);
+
+        assert!(grid.strided(1, 1).iter().eq(grid.iter()));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_strided_zero_step() {
+        labeled(4, 3).strided(0, 1);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_transposed_get(
+            cols in 0usize..8,
+            rows in 0usize..8,
+            col in 0usize..10,
+            row in 0usize..10,
+        ) {
+            let grid = labeled(cols, rows);
+
+            prop_assert_eq!(grid.get((col, row)), grid.transposed().get((row, col)));
+        }
+    }
+}